@@ -0,0 +1,96 @@
+use criterion::{
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+use rand::{
+    rngs::StdRng,
+    seq::SliceRandom,
+    SeedableRng,
+};
+use rodash::{
+    difference,
+    difference_all,
+    difference_all_hashed,
+    difference_hashed,
+    difference_sorted,
+};
+use std::hint::black_box;
+
+/// The linear-search implementation of `difference` that the sorted and hashed lookups replaced.
+fn linear_difference(array: &[u64], other: &[u64]) -> Vec<u64> {
+    array
+        .iter()
+        .filter(|item| !other.contains(item))
+        .copied()
+        .collect()
+}
+
+/// Returns shuffled arrays of `len` values that have half of their values in common, followed by
+/// the same arrays sorted.
+fn inputs(len: u64) -> [Vec<u64>; 4] {
+    let mut rng = StdRng::seed_from_u64(len);
+    let mut array = (0..len).map(|n| n * 2).collect::<Vec<_>>();
+    let mut other = (0..len).map(|n| n * 2 + len).collect::<Vec<_>>();
+    let (sorted_array, sorted_other) = (array.clone(), other.clone());
+
+    array.shuffle(&mut rng);
+    other.shuffle(&mut rng);
+
+    [array, other, sorted_array, sorted_other]
+}
+
+fn bench_difference(c: &mut Criterion) {
+    let mut group = c.benchmark_group("difference");
+
+    for len in [100, 10_000, 1_000_000] {
+        let [array, other, sorted_array, sorted_other] = inputs(len);
+
+        if len <= 10_000 {
+            group.bench_with_input(BenchmarkId::new("linear", len), &len, |b, _| {
+                b.iter(|| linear_difference(black_box(&array), black_box(&other)))
+            });
+        }
+
+        group.bench_with_input(BenchmarkId::new("difference", len), &len, |b, _| {
+            b.iter(|| difference(black_box(&array), black_box(&other), None))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("difference_is_sorted", len),
+            &len,
+            |b, _| b.iter(|| difference(black_box(&array), black_box(&sorted_other), Some(true))),
+        );
+        group.bench_with_input(BenchmarkId::new("difference_hashed", len), &len, |b, _| {
+            b.iter(|| difference_hashed(black_box(&array), black_box(&other)))
+        });
+        group.bench_with_input(BenchmarkId::new("difference_sorted", len), &len, |b, _| {
+            b.iter(|| difference_sorted(black_box(&sorted_array), black_box(&sorted_other)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_difference_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("difference_all");
+
+    for len in [100, 10_000, 1_000_000] {
+        let [array, other, ..] = inputs(len);
+        let (first, second) = other.split_at(other.len() / 2);
+
+        group.bench_with_input(BenchmarkId::new("difference_all", len), &len, |b, _| {
+            b.iter(|| difference_all(black_box(&array), vec![first, second], None))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("difference_all_hashed", len),
+            &len,
+            |b, _| b.iter(|| difference_all_hashed(black_box(&array), vec![first, second])),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_difference, bench_difference_all);
+criterion_main!(benches);
@@ -0,0 +1,145 @@
+use criterion::{
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+use once_cell::sync::Lazy;
+use regex::{
+    Captures,
+    Regex,
+};
+use rodash::{
+    escape,
+    escape_cow,
+    escape_to,
+    unescape,
+    unescape_cow,
+    EscapeContext,
+    UnescapeMode,
+};
+use std::hint::black_box;
+
+/// The regex-based implementation of `escape` that the byte-scanning core replaced.
+fn regex_escape(value: &str) -> String {
+    static UNESCAPED_HTML: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"[&<>"']"#).expect("unable to build the pattern"));
+
+    if UNESCAPED_HTML.is_match(value) {
+        UNESCAPED_HTML
+            .replace_all(value, |cap: &Captures| {
+                match &cap[0] {
+                    "&" => "&amp;",
+                    "<" => "&lt;",
+                    ">" => "&gt;",
+                    r#"""# => "&quot;",
+                    _ => "&#39;",
+                }
+                .to_owned()
+            })
+            .to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// The regex-based implementation of `unescape` that the byte-scanning core replaced.
+fn regex_unescape(value: &str) -> String {
+    static ESCAPED_HTML: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r#"&(?:amp|lt|gt|quot|#(0+)?39);"#).expect("unable to build the pattern")
+    });
+
+    if ESCAPED_HTML.is_match(value) {
+        ESCAPED_HTML
+            .replace_all(value, |cap: &Captures| {
+                match &cap[0] {
+                    "&amp;" => "&",
+                    "&lt;" => "<",
+                    "&gt;" => ">",
+                    "&quot;" => r#"""#,
+                    _ => "'",
+                }
+                .to_owned()
+            })
+            .to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Short and long inputs, with and without characters to escape, and a long input whose only
+/// characters to escape are frequent quotes.
+fn inputs() -> [(&'static str, String); 5] {
+    [
+        ("short_clean", "fred, barney and pebbles".to_string()),
+        (
+            "short_dirty",
+            r#"<a href="/">fred & 'barney'</a>"#.to_string(),
+        ),
+        ("long_clean", "fred, barney and pebbles. ".repeat(400)),
+        (
+            "long_dirty",
+            r#"<a href="/">fred & 'barney'</a> "#.repeat(400),
+        ),
+        (
+            "long_quoted",
+            r#"fred said "hi" to 'barney'. "#.repeat(4000),
+        ),
+    ]
+}
+
+fn bench_escape(c: &mut Criterion) {
+    let mut group = c.benchmark_group("escape");
+
+    for (name, input) in inputs() {
+        group.bench_with_input(BenchmarkId::new("regex", name), &input, |b, input| {
+            b.iter(|| regex_escape(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("escape", name), &input, |b, input| {
+            b.iter(|| escape(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("escape_cow", name), &input, |b, input| {
+            b.iter(|| escape_cow(black_box(input), EscapeContext::Text).len())
+        });
+        group.bench_with_input(BenchmarkId::new("escape_to", name), &input, |b, input| {
+            let mut out = String::with_capacity(input.len() * 2);
+
+            b.iter(|| {
+                out.clear();
+                escape_to(black_box(input), EscapeContext::Text, &mut out)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_unescape(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unescape");
+
+    for (name, input) in inputs() {
+        let input = escape(input);
+
+        group.bench_with_input(BenchmarkId::new("regex", name), &input, |b, input| {
+            b.iter(|| regex_unescape(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("unescape", name), &input, |b, input| {
+            b.iter(|| unescape(black_box(input)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("unescape_cow", name),
+            &input,
+            |b, input| b.iter(|| unescape_cow(black_box(input), UnescapeMode::Lodash).len()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("unescape_html5", name),
+            &input,
+            |b, input| b.iter(|| unescape_cow(black_box(input), UnescapeMode::Html5).len()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_escape, bench_unescape);
+criterion_main!(benches);
@@ -0,0 +1,168 @@
+use crate::case::{
+    self,
+    WordCase,
+};
+
+/// How the case conversions that capitalize words, such as [camel_case], convert acronyms.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AcronymMode {
+    /// Acronyms are converted like any other word, so that "HTTP" becomes "Http".
+    #[default]
+    Normalize,
+    /// Acronyms, which are words without lowercase letters such as "HTTP", are kept uppercase,
+    /// except as the first word of camel case.
+    Preserve,
+}
+
+/// Converts `value` to [camel case](https://en.wikipedia.org/wiki/Camel_case), like the lodash
+/// `_.camelCase` function. The first of the [words] of `value` is lowercased, and the others are
+/// capitalized and joined without separators.
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::camel_case;
+///
+/// assert_eq!(camel_case("Foo Bar"), "fooBar".to_string());
+/// assert_eq!(camel_case("--foo-bar--"), "fooBar".to_string());
+/// assert_eq!(camel_case("__FOO_BAR__"), "fooBar".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn camel_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    camel_case_with(value, AcronymMode::Normalize)
+}
+
+/// This method is like [camel_case] except that acronyms are converted according to `acronyms`.
+///
+/// * `value` - The string to convert.
+/// * `acronyms` - How acronyms such as "HTTP" are converted.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     camel_case_with,
+///     AcronymMode,
+/// };
+///
+/// assert_eq!(
+///     camel_case_with("get HTTP response", AcronymMode::Preserve),
+///     "getHTTPResponse".to_string()
+/// );
+/// ```
+pub fn camel_case_with<S>(value: S, acronyms: AcronymMode) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Lower,
+        WordCase::Capitalized,
+        "",
+        acronyms,
+    )
+}
+
+/// A trait that implements the [CamelCase::camel_case] method on strings.
+pub trait CamelCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to camel case, like the lodash `_.camelCase` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::CamelCase;
+    ///
+    /// assert_eq!("foo_bar".camel_case(), "fooBar".to_string());
+    /// ```
+    fn camel_case(&self) -> String;
+
+    /// This method is like [CamelCase::camel_case] except that acronyms are converted according to
+    /// `acronyms`.
+    ///
+    /// * `acronyms` - How acronyms such as "HTTP" are converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     AcronymMode,
+    ///     CamelCase,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "XMLHttpRequest".camel_case_with(AcronymMode::Preserve),
+    ///     "xmlHttpRequest".to_string()
+    /// );
+    /// ```
+    fn camel_case_with(&self, acronyms: AcronymMode) -> String;
+}
+
+impl CamelCase<String> for String {
+    fn camel_case(&self) -> String {
+        camel_case(self)
+    }
+
+    fn camel_case_with(&self, acronyms: AcronymMode) -> String {
+        camel_case_with(self, acronyms)
+    }
+}
+
+impl CamelCase<str> for str {
+    fn camel_case(&self) -> String {
+        camel_case(self)
+    }
+
+    fn camel_case_with(&self, acronyms: AcronymMode) -> String {
+        camel_case_with(self, acronyms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_camel_case() {
+        assert_eq!(camel_case("fooBar"), "fooBar".to_string());
+        assert_eq!("XMLHttpRequest".camel_case(), "xmlHttpRequest".to_string());
+        assert_eq!(
+            "version2Update".to_string().camel_case(),
+            "version2Update".to_string()
+        );
+        assert_eq!(camel_case("don't stop"), "dontStop".to_string());
+        assert_eq!("  ".camel_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().camel_case(),
+            "élanVital".to_string()
+        );
+    }
+
+    #[test]
+    fn can_preserve_acronyms() {
+        assert_eq!(
+            camel_case_with("getHTTPResponse", AcronymMode::Normalize),
+            "getHttpResponse".to_string()
+        );
+        assert_eq!(
+            camel_case_with("getHTTPResponse", AcronymMode::Preserve),
+            "getHTTPResponse".to_string()
+        );
+        assert_eq!(
+            camel_case_with("XMLHttpRequest", AcronymMode::Normalize),
+            "xmlHttpRequest".to_string()
+        );
+        assert_eq!(
+            "XMLHttpRequest".camel_case_with(AcronymMode::Preserve),
+            "xmlHttpRequest".to_string()
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    words,
+    AcronymMode,
+};
+use std::borrow::Cow;
+
+/// The case of the letters of a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WordCase {
+    /// Every letter is lowercase.
+    Lower,
+    /// Every letter is uppercase.
+    Upper,
+    /// The first letter is uppercase and the others are lowercase.
+    Capitalized,
+}
+
+/// Returns `true` if `word` has an uppercase letter and no lowercase letter, such as "HTTP".
+fn is_acronym(word: &str) -> bool {
+    word.chars().any(char::is_uppercase) && !word.chars().any(char::is_lowercase)
+}
+
+/// Writes `word` into `out` in the given `case`, leaving out its apostrophes.
+fn push_word(word: &str, case: WordCase, out: &mut String) {
+    let word = if word.contains(['\'', '\u{2019}']) {
+        Cow::Owned(word.replace(['\'', '\u{2019}'], ""))
+    } else {
+        Cow::Borrowed(word)
+    };
+
+    match case {
+        WordCase::Lower => out.push_str(&word.to_lowercase()),
+        WordCase::Upper => out.push_str(&word.to_uppercase()),
+        WordCase::Capitalized => {
+            let mut chars = word.chars();
+
+            if let Some(first) = chars.next() {
+                out.extend(first.to_uppercase());
+                out.push_str(&chars.as_str().to_lowercase());
+            }
+        }
+    }
+}
+
+/// Converts the [words] of `value` to `first` case for the first word and `rest` case for the
+/// others, and joins them with `separator`. With [AcronymMode::Preserve], acronyms are kept as
+/// they are, except for a lowercase first word, as in camel case.
+pub(crate) fn convert(
+    value: &str,
+    first: WordCase,
+    rest: WordCase,
+    separator: &str,
+    acronyms: AcronymMode,
+) -> String {
+    let mut converted = String::with_capacity(value.len());
+
+    for (index, word) in words(value).into_iter().enumerate() {
+        let case = if index == 0 { first } else { rest };
+
+        if index > 0 {
+            converted.push_str(separator);
+        }
+
+        if acronyms == AcronymMode::Preserve
+            && !(index == 0 && case == WordCase::Lower)
+            && is_acronym(word)
+        {
+            push_word(word, WordCase::Upper, &mut converted);
+        } else {
+            push_word(word, case, &mut converted);
+        }
+    }
+
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        camel_case,
+        camel_case_with,
+        constant_case,
+        dot_case,
+        kebab_case,
+        lower_case,
+        pascal_case,
+        pascal_case_with,
+        path_case,
+        sentence_case,
+        snake_case,
+        start_case,
+        upper_case,
+        AcronymMode,
+    };
+
+    /// The same identifiers in several cases.
+    const IDENTIFIERS: [&str; 10] = [
+        "userIdHttpResponse",
+        "UserIdHttpResponse",
+        "user_id_http_response",
+        "user-id-http-response",
+        "USER_ID_HTTP_RESPONSE",
+        "User Id Http Response",
+        "user id http response",
+        "USER ID HTTP RESPONSE",
+        "user.id.http.response",
+        "user/id/http/response",
+    ];
+
+    /// Returns the conversions to every case.
+    fn conversions() -> [fn(&str) -> String; 11] {
+        [
+            |value| camel_case(value),
+            |value| pascal_case(value),
+            |value| snake_case(value),
+            |value| kebab_case(value),
+            |value| constant_case(value),
+            |value| start_case(value),
+            |value| lower_case(value),
+            |value| upper_case(value),
+            |value| dot_case(value),
+            |value| path_case(value),
+            |value| sentence_case(value),
+        ]
+    }
+
+    #[test]
+    fn can_convert_between_cases() {
+        for convert in conversions() {
+            let expected = convert(IDENTIFIERS[0]);
+
+            for identifier in IDENTIFIERS {
+                assert_eq!(convert(identifier), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn can_round_trip_through_every_case() {
+        for identifier in IDENTIFIERS {
+            for from in conversions() {
+                for to in conversions() {
+                    assert_eq!(to(&from(identifier)), to(identifier));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_round_trip_preserved_acronyms() {
+        for identifier in ["getHTTPResponse", "XMLHttpRequest", "parseURL2ID"] {
+            let pascal = pascal_case_with(identifier, AcronymMode::Preserve);
+            let snake = snake_case(identifier);
+
+            assert_eq!(
+                pascal_case_with(&snake, AcronymMode::Normalize),
+                pascal_case(identifier)
+            );
+            assert_eq!(snake_case(&pascal), snake);
+            assert_eq!(
+                camel_case_with(&pascal, AcronymMode::Preserve),
+                camel_case_with(identifier, AcronymMode::Preserve)
+            );
+        }
+    }
+
+    #[test]
+    fn can_convert_unicode_words() {
+        assert_eq!(snake_case("ΣΊΣΥΦΟΣ Straße"), "σίσυφος_straße".to_string());
+        assert_eq!(constant_case("straße"), "STRASSE".to_string());
+        assert_eq!(camel_case("élan vital"), "élanVital".to_string());
+        assert_eq!(kebab_case("don't stop"), "dont-stop".to_string());
+    }
+}
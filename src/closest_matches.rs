@@ -0,0 +1,259 @@
+use crate::levenshtein::edit_distance;
+
+/// Finds the at most `n` candidates closest to `query` that are within `max_distance`, ranked by
+/// [damerau_levenshtein](crate::damerau_levenshtein) distance and then by their order in
+/// `candidates`.
+fn find_closest<'a, S>(
+    query: &str,
+    candidates: &'a [S],
+    n: usize,
+    max_distance: usize,
+) -> Vec<&'a str>
+where
+    S: AsRef<str>,
+{
+    let query = query.chars().collect::<Vec<_>>();
+    let mut candidate_chars = Vec::new();
+    // The distances and indices of the closest candidates so far, sorted by distance.
+    let mut closest: Vec<(usize, usize)> = Vec::with_capacity(n.saturating_add(1).min(64));
+
+    for (index, candidate) in candidates.iter().enumerate() {
+        // Once `n` candidates are found, later candidates must be strictly closer to rank.
+        let bound = match closest.last() {
+            Some(&(distance, _)) if closest.len() >= n => match distance.checked_sub(1) {
+                Some(bound) => bound,
+                None => break,
+            },
+            _ => max_distance,
+        };
+
+        candidate_chars.clear();
+        candidate_chars.extend(candidate.as_ref().chars());
+
+        if let Some(distance) = edit_distance(&query, &candidate_chars, bound, true) {
+            let position = closest.partition_point(|&(other, _)| other <= distance);
+            closest.insert(position, (distance, index));
+            closest.truncate(n);
+        }
+    }
+
+    closest
+        .into_iter()
+        .map(|(_, index)| candidates[index].as_ref())
+        .collect()
+}
+
+/// Finds the `n` candidates closest to `query`, such as "did you mean" suggestions for a mistyped
+/// command. Candidates are ranked by their [damerau_levenshtein](crate::damerau_levenshtein)
+/// distance to `query`, and candidates at the same distance keep their order in `candidates`.
+/// Characters are compared as they are, so queries and candidates may need to be lowercased
+/// first.
+///
+/// * `query` - The string to find matches for.
+/// * `candidates` - The strings to search.
+/// * `n` - The maximum number of matches.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::closest_matches;
+///
+/// let commands = ["build", "bench", "check", "clean", "clippy"];
+/// assert_eq!(closest_matches("biuld", &commands, 1), ["build"]);
+/// assert_eq!(closest_matches("clen", &commands, 2), ["clean", "check"]);
+/// ```
+pub fn closest_matches<'a, S>(query: &str, candidates: &'a [S], n: usize) -> Vec<&'a str>
+where
+    S: AsRef<str>,
+{
+    find_closest(query, candidates, n, usize::MAX)
+}
+
+/// This method is like [closest_matches] except that candidates farther than `max_distance` from
+/// `query` are left out. Distance computations stop as soon as a candidate is known to be too
+/// far, and the bound tightens once `n` candidates are found, which makes this method much faster
+/// on large candidate lists.
+///
+/// * `query` - The string to find matches for.
+/// * `candidates` - The strings to search.
+/// * `n` - The maximum number of matches.
+/// * `max_distance` - The maximum distance of a match.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::closest_matches_within;
+///
+/// let commands = ["build", "bench", "check", "clean", "clippy"];
+/// assert_eq!(closest_matches_within("clena", &commands, 3, 1), ["clean"]);
+/// assert!(closest_matches_within("deploy", &commands, 3, 2).is_empty());
+/// ```
+pub fn closest_matches_within<'a, S>(
+    query: &str,
+    candidates: &'a [S],
+    n: usize,
+    max_distance: usize,
+) -> Vec<&'a str>
+where
+    S: AsRef<str>,
+{
+    find_closest(query, candidates, n, max_distance)
+}
+
+/// A trait that implements the [ClosestMatches::closest_matches] method on strings.
+pub trait ClosestMatches<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Finds the `n` candidates closest to this string, ranked by Damerau-Levenshtein distance.
+    ///
+    /// * `candidates` - The strings to search.
+    /// * `n` - The maximum number of matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::ClosestMatches;
+    ///
+    /// assert_eq!(
+    ///     "aple".closest_matches(&["apple", "maple", "pear"], 2),
+    ///     ["apple", "maple"]
+    /// );
+    /// ```
+    fn closest_matches<'a, C>(&self, candidates: &'a [C], n: usize) -> Vec<&'a str>
+    where
+        C: AsRef<str>;
+
+    /// This method is like [ClosestMatches::closest_matches] except that candidates farther than
+    /// `max_distance` from this string are left out.
+    ///
+    /// * `candidates` - The strings to search.
+    /// * `n` - The maximum number of matches.
+    /// * `max_distance` - The maximum distance of a match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::ClosestMatches;
+    ///
+    /// assert_eq!(
+    ///     "pera".closest_matches_within(&["apple", "pear"], 2, 1),
+    ///     ["pear"]
+    /// );
+    /// ```
+    fn closest_matches_within<'a, C>(
+        &self,
+        candidates: &'a [C],
+        n: usize,
+        max_distance: usize,
+    ) -> Vec<&'a str>
+    where
+        C: AsRef<str>;
+}
+
+impl ClosestMatches<String> for String {
+    fn closest_matches<'a, C>(&self, candidates: &'a [C], n: usize) -> Vec<&'a str>
+    where
+        C: AsRef<str>,
+    {
+        closest_matches(self, candidates, n)
+    }
+
+    fn closest_matches_within<'a, C>(
+        &self,
+        candidates: &'a [C],
+        n: usize,
+        max_distance: usize,
+    ) -> Vec<&'a str>
+    where
+        C: AsRef<str>,
+    {
+        closest_matches_within(self, candidates, n, max_distance)
+    }
+}
+
+impl ClosestMatches<str> for str {
+    fn closest_matches<'a, C>(&self, candidates: &'a [C], n: usize) -> Vec<&'a str>
+    where
+        C: AsRef<str>,
+    {
+        closest_matches(self, candidates, n)
+    }
+
+    fn closest_matches_within<'a, C>(
+        &self,
+        candidates: &'a [C],
+        n: usize,
+        max_distance: usize,
+    ) -> Vec<&'a str>
+    where
+        C: AsRef<str>,
+    {
+        closest_matches_within(self, candidates, n, max_distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::damerau_levenshtein;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_rank_matches() {
+        let words = ["receive", "deceive", "recipe", "relieve", "receiver"];
+
+        assert_eq!(
+            closest_matches("recieve", &words, 3),
+            ["receive", "relieve", "deceive"]
+        );
+        assert_eq!(closest_matches("recieve", &words, 0), Vec::<&str>::new());
+        assert_eq!(closest_matches("recieve", &words, 10).len(), 5);
+        assert_eq!(
+            closest_matches("x", &Vec::<String>::new(), 3),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn can_limit_match_distances() {
+        let words = vec!["receive".to_string(), "recipe".to_string()];
+
+        assert_eq!("recieve".closest_matches_within(&words, 5, 1), ["receive"]);
+        assert_eq!(
+            "recieve".to_string().closest_matches_within(&words, 5, 3),
+            ["receive", "recipe"]
+        );
+        assert_eq!(closest_matches_within("receive", &words, 1, 0), ["receive"]);
+    }
+
+    #[test]
+    fn can_match_unicode_candidates() {
+        assert_eq!(
+            "Zurich".closest_matches(&["Zürich", "Munich", "Zug"], 2),
+            ["Zürich", "Munich"]
+        );
+    }
+
+    quickcheck! {
+        fn closest_matches_are_ranked_like_sorted_distances(
+            query: String,
+            candidates: Vec<String>,
+            n: u8,
+            max: u8
+        ) -> bool {
+            let (n, max) = (usize::from(n % 5), usize::from(max % 6));
+            let chars = |value: &str| value.chars().collect::<Vec<_>>();
+            let mut expected = candidates
+                .iter()
+                .map(|candidate| (damerau_levenshtein(&chars(&query), &chars(candidate)), candidate))
+                .filter(|(distance, _)| *distance <= max)
+                .collect::<Vec<_>>();
+            expected.sort_by_key(|(distance, _)| *distance);
+            expected.truncate(n);
+
+            closest_matches_within(&query, &candidates, n, max)
+                == expected.into_iter().map(|(_, candidate)| candidate.as_str()).collect::<Vec<_>>()
+        }
+    }
+}
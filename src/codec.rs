@@ -0,0 +1,297 @@
+//! A byte-scanning core shared by the escaping and unescaping functions.
+//!
+//! Encoders scan for the bytes that may start a character they replace, and decoders scan for the
+//! byte that starts a reference. Untouched runs of the input are copied as whole slices, so that
+//! values without anything to replace are returned as borrowed [Cow] values without allocating.
+
+use memchr::memchr;
+use std::{
+    borrow::Cow,
+    fmt,
+    io,
+};
+
+/// A set of bytes, used by an [Encoder] to find the characters it may replace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct ByteSet([bool; 256]);
+
+impl ByteSet {
+    /// Creates a set containing `bytes`.
+    pub(crate) const fn new(bytes: &[u8]) -> Self {
+        Self([false; 256]).with(bytes)
+    }
+
+    /// Creates a set containing every ASCII character that is not alphanumeric.
+    pub(crate) const fn non_alphanumeric_ascii() -> Self {
+        let mut set = [false; 256];
+        let mut byte = 0;
+
+        while byte < 0x80 {
+            set[byte] = !(byte as u8).is_ascii_alphanumeric();
+            byte += 1;
+        }
+
+        Self(set)
+    }
+
+    /// Adds `bytes` to this set.
+    pub(crate) const fn with(mut self, bytes: &[u8]) -> Self {
+        let mut index = 0;
+
+        while index < bytes.len() {
+            self.0[bytes[index] as usize] = true;
+            index += 1;
+        }
+
+        self
+    }
+
+    /// Adds the ASCII bytes of `bytes` to this set, ignoring the others.
+    pub(crate) const fn with_ascii(mut self, bytes: &[u8]) -> Self {
+        let mut index = 0;
+
+        while index < bytes.len() {
+            if bytes[index].is_ascii() {
+                self.0[bytes[index] as usize] = true;
+            }
+
+            index += 1;
+        }
+
+        self
+    }
+
+    /// Adds every byte that starts a multi-byte UTF-8 sequence to this set.
+    pub(crate) const fn with_non_ascii(mut self) -> Self {
+        let mut byte = 0xc0;
+
+        while byte < 0x100 {
+            self.0[byte] = true;
+            byte += 1;
+        }
+
+        self
+    }
+
+    /// Removes `bytes` from this set.
+    pub(crate) const fn without(mut self, bytes: &[u8]) -> Self {
+        let mut index = 0;
+
+        while index < bytes.len() {
+            self.0[bytes[index] as usize] = false;
+            index += 1;
+        }
+
+        self
+    }
+
+    /// Returns `true` if this set contains `byte`.
+    #[inline]
+    pub(crate) const fn contains(&self, byte: u8) -> bool {
+        self.0[byte as usize]
+    }
+}
+
+/// A set of rules that replace individual characters.
+pub(crate) trait Encoder {
+    /// Returns the set of bytes that may start a character replaced by [Encoder::encode]. The set
+    /// must not contain UTF-8 continuation bytes.
+    fn special_bytes(&self) -> &ByteSet;
+
+    /// Finds the index of the first byte of `bytes` contained in [Encoder::special_bytes].
+    /// Encoders with few special bytes may override this with a faster search.
+    #[inline]
+    fn find_special(&self, bytes: &[u8]) -> Option<usize> {
+        let set = self.special_bytes();
+
+        bytes.iter().position(|byte| set.contains(*byte))
+    }
+
+    /// Writes the replacement of `c` into `out`, or `c` itself if it is kept as is.
+    fn encode<W>(&self, c: char, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write;
+}
+
+/// The characters a reference is decoded to.
+pub(crate) enum Decoded {
+    /// A single character.
+    Char(char),
+    /// A sequence of characters.
+    Str(&'static str),
+}
+
+/// A set of rules that replace references starting with a marker byte.
+pub(crate) trait Decoder {
+    /// The byte that starts every reference, such as "&".
+    const MARKER: u8;
+
+    /// Decodes the reference at the start of `value`, which begins with [Decoder::MARKER].
+    /// Returns the decoded characters and the length of the reference in bytes, or `None` if
+    /// `value` does not start with a reference.
+    fn decode(&self, value: &str) -> Option<(Decoded, usize)>;
+}
+
+/// Finds the index of the first byte of `value`, starting at `from`, that may start a character
+/// replaced by `encoder`.
+#[inline]
+fn find_special<E>(encoder: &E, value: &str, from: usize) -> Option<usize>
+where
+    E: Encoder,
+{
+    encoder
+        .find_special(&value.as_bytes()[from..])
+        .map(|index| from + index)
+}
+
+/// Writes `value` into `out`, replacing characters with `encoder`.
+pub(crate) fn encode_to<E, W>(encoder: &E, value: &str, out: &mut W) -> fmt::Result
+where
+    E: Encoder,
+    W: fmt::Write,
+{
+    let mut start = 0;
+
+    while let Some(index) = find_special(encoder, value, start) {
+        let Some(c) = value[index..].chars().next() else {
+            break;
+        };
+
+        out.write_str(&value[start..index])?;
+        encoder.encode(c, out)?;
+        start = index + c.len_utf8();
+    }
+
+    out.write_str(&value[start..])
+}
+
+/// Replaces characters of `value` with `encoder`, borrowing `value` if none may be replaced.
+pub(crate) fn encode_cow<'a, E>(encoder: &E, value: &'a str) -> Cow<'a, str>
+where
+    E: Encoder,
+{
+    match find_special(encoder, value, 0) {
+        None => Cow::Borrowed(value),
+        Some(index) => {
+            let mut encoded = String::with_capacity(value.len() + value.len() / 4 + 8);
+            encoded.push_str(&value[..index]);
+            // Writing into a `String` never fails.
+            let _ = encode_to(encoder, &value[index..], &mut encoded);
+
+            Cow::Owned(encoded)
+        }
+    }
+}
+
+/// Finds the next reference of `value`, starting at `from`, returning its index, decoded
+/// characters and length.
+#[inline]
+fn find_reference<D>(decoder: &D, value: &str, mut from: usize) -> Option<(usize, Decoded, usize)>
+where
+    D: Decoder,
+{
+    while let Some(index) = memchr(D::MARKER, &value.as_bytes()[from..]).map(|index| from + index) {
+        if let Some((decoded, length)) = decoder.decode(&value[index..]) {
+            return Some((index, decoded, length));
+        }
+
+        from = index + 1;
+    }
+
+    None
+}
+
+/// Writes `value` into `out`, replacing references with `decoder`.
+pub(crate) fn decode_to<D, W>(decoder: &D, value: &str, out: &mut W) -> fmt::Result
+where
+    D: Decoder,
+    W: fmt::Write,
+{
+    let mut start = 0;
+
+    while let Some((index, decoded, length)) = find_reference(decoder, value, start) {
+        out.write_str(&value[start..index])?;
+
+        match decoded {
+            Decoded::Char(c) => out.write_char(c)?,
+            Decoded::Str(characters) => out.write_str(characters)?,
+        }
+
+        start = index + length;
+    }
+
+    out.write_str(&value[start..])
+}
+
+/// Replaces references of `value` with `decoder`, borrowing `value` if it has none.
+pub(crate) fn decode_cow<'a, D>(decoder: &D, value: &'a str) -> Cow<'a, str>
+where
+    D: Decoder,
+{
+    match find_reference(decoder, value, 0) {
+        None => Cow::Borrowed(value),
+        Some((index, decoded, length)) => {
+            let mut decoded_value = String::with_capacity(value.len());
+            decoded_value.push_str(&value[..index]);
+
+            match decoded {
+                Decoded::Char(c) => decoded_value.push(c),
+                Decoded::Str(characters) => decoded_value.push_str(characters),
+            }
+
+            // Writing into a `String` never fails.
+            let _ = decode_to(decoder, &value[index + length..], &mut decoded_value);
+
+            Cow::Owned(decoded_value)
+        }
+    }
+}
+
+/// Returns the length of the UTF-8 sequence started by `byte`, or `None` if `byte` cannot start
+/// a sequence.
+pub(crate) fn utf8_width(byte: u8) -> Option<usize> {
+    match byte {
+        0x00..=0x7f => Some(1),
+        0xc2..=0xdf => Some(2),
+        0xe0..=0xef => Some(3),
+        0xf0..=0xf4 => Some(4),
+        _ => None,
+    }
+}
+
+/// Adapts an [io::Write] to [fmt::Write], keeping the I/O error that made a write fail.
+pub(crate) struct IoAdapter<'a, W> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W> fmt::Write for IoAdapter<'_, W>
+where
+    W: io::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+/// Runs `write` against an [fmt::Write] adapter of `out`, surfacing the underlying I/O error.
+pub(crate) fn write_io<W, F>(out: &mut W, write: F) -> io::Result<()>
+where
+    W: io::Write,
+    F: FnOnce(&mut IoAdapter<'_, W>) -> fmt::Result,
+{
+    let mut adapter = IoAdapter {
+        inner: out,
+        error: None,
+    };
+
+    write(&mut adapter).map_err(|_| {
+        adapter
+            .error
+            .take()
+            .unwrap_or_else(|| io::Error::other("formatter error"))
+    })
+}
@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+
+/// Returns `true` if `value` has no leading or trailing whitespace, and no whitespace other than
+/// single spaces between words.
+fn is_collapsed(value: &str) -> bool {
+    let mut previous_is_space = true;
+
+    for c in value.chars() {
+        if c.is_whitespace() {
+            if previous_is_space || c != ' ' {
+                return false;
+            }
+
+            previous_is_space = true;
+        } else {
+            previous_is_space = false;
+        }
+    }
+
+    !previous_is_space || value.is_empty()
+}
+
+/// Removes the leading and trailing whitespace of `value`, and replaces every other run of
+/// whitespace, including line breaks, with a single space. Whitespace is any character with the
+/// Unicode `White_Space` property, as for [char::is_whitespace]. Returns `value` as is, without
+/// allocating, when there is no whitespace to remove or replace.
+///
+/// * `value` - The string to collapse.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::collapse_whitespace;
+///
+/// assert_eq!(
+///     collapse_whitespace("  Fred \t\r\n Flintstone "),
+///     "Fred Flintstone"
+/// );
+/// assert_eq!(collapse_whitespace("fred\u{a0}\u{a0}barney"), "fred barney");
+/// ```
+pub fn collapse_whitespace(value: &str) -> Cow<'_, str> {
+    if is_collapsed(value) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut collapsed = String::with_capacity(value.len());
+
+    for word in value.split_whitespace() {
+        if !collapsed.is_empty() {
+            collapsed.push(' ');
+        }
+
+        collapsed.push_str(word);
+    }
+
+    Cow::Owned(collapsed)
+}
+
+/// A trait that implements the [CollapseWhitespace::collapse_whitespace] method on strings.
+pub trait CollapseWhitespace<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Removes the leading and trailing whitespace of this string, and replaces every other run
+    /// of whitespace with a single space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::CollapseWhitespace;
+    ///
+    /// assert_eq!(" fred \n\n barney ".collapse_whitespace(), "fred barney");
+    /// ```
+    fn collapse_whitespace(&self) -> Cow<'_, str>;
+}
+
+impl CollapseWhitespace<String> for String {
+    fn collapse_whitespace(&self) -> Cow<'_, str> {
+        collapse_whitespace(self)
+    }
+}
+
+impl CollapseWhitespace<str> for str {
+    fn collapse_whitespace(&self) -> Cow<'_, str> {
+        collapse_whitespace(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_collapse_whitespace() {
+        assert_eq!(collapse_whitespace(""), "");
+        assert_eq!(collapse_whitespace(" \t\n "), "");
+        assert_eq!(collapse_whitespace("a\u{2028}b\u{3000} c"), "a b c");
+        assert_eq!("x\r\n".to_string().collapse_whitespace(), "x");
+    }
+
+    #[test]
+    fn can_borrow_collapsed_strings() {
+        assert!(matches!(collapse_whitespace(""), Cow::Borrowed(_)));
+        assert!(matches!(
+            collapse_whitespace("fred barney"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(collapse_whitespace("fred  barney"), Cow::Owned(_)));
+        assert!(matches!(collapse_whitespace("fred\tbarney"), Cow::Owned(_)));
+        assert!(matches!(collapse_whitespace(" fred"), Cow::Owned(_)));
+        assert!(matches!("fred ".collapse_whitespace(), Cow::Owned(_)));
+    }
+
+    quickcheck! {
+        fn borrows_exactly_the_collapsed_strings(value: String) -> bool {
+            let collapsed = collapse_whitespace(&value);
+
+            collapsed == value.split_whitespace().collect::<Vec<_>>().join(" ")
+                && matches!(collapsed, Cow::Borrowed(_)) == (collapsed == value)
+        }
+    }
+}
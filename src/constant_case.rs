@@ -0,0 +1,89 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to constant case, also known as screaming snake case. The [words] of `value`
+/// are uppercased and joined with "_".
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::constant_case;
+///
+/// assert_eq!(constant_case("Foo Bar"), "FOO_BAR".to_string());
+/// assert_eq!(constant_case("fooBar"), "FOO_BAR".to_string());
+/// assert_eq!(constant_case("--foo-bar--"), "FOO_BAR".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn constant_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Upper,
+        WordCase::Upper,
+        "_",
+        AcronymMode::Normalize,
+    )
+}
+
+/// A trait that implements the [ConstantCase::constant_case] method on strings.
+pub trait ConstantCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to constant case, also known as screaming snake case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::ConstantCase;
+    ///
+    /// assert_eq!("fooBar".constant_case(), "FOO_BAR".to_string());
+    /// ```
+    fn constant_case(&self) -> String;
+}
+
+impl ConstantCase<String> for String {
+    fn constant_case(&self) -> String {
+        constant_case(self)
+    }
+}
+
+impl ConstantCase<str> for str {
+    fn constant_case(&self) -> String {
+        constant_case(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_constant_case() {
+        assert_eq!(constant_case("fooBar"), "FOO_BAR".to_string());
+        assert_eq!(
+            "XMLHttpRequest".constant_case(),
+            "XML_HTTP_REQUEST".to_string()
+        );
+        assert_eq!(
+            "version2Update".to_string().constant_case(),
+            "VERSION_2_UPDATE".to_string()
+        );
+        assert_eq!(constant_case("don't stop"), "DONT_STOP".to_string());
+        assert_eq!("  ".constant_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().constant_case(),
+            "ÉLAN_VITAL".to_string()
+        );
+    }
+}
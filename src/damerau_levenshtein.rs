@@ -0,0 +1,162 @@
+use crate::levenshtein::edit_distance;
+
+/// Computes the Damerau-Levenshtein distance between the `a` and `b` arrays, which is like the
+/// [Levenshtein distance](crate::levenshtein) except that swapping two adjacent elements is a
+/// single edit, as in the common typo "teh". This is the [optimal string alignment distance], in
+/// which no element is edited more than once, so that the distance between "ca" and "abc" is 3.
+///
+/// * `a` - The first array to compare.
+/// * `b` - The second array to compare.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::damerau_levenshtein;
+///
+/// assert_eq!(damerau_levenshtein(b"teh", b"the"), 1);
+/// assert_eq!(damerau_levenshtein(b"kitten", b"sitting"), 3);
+/// assert_eq!(damerau_levenshtein(b"ca", b"abc"), 3);
+/// ```
+///
+/// [optimal string alignment distance]: https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance#Optimal_string_alignment_distance
+pub fn damerau_levenshtein<A>(a: &[A], b: &[A]) -> usize
+where
+    A: PartialEq,
+{
+    edit_distance(a, b, usize::MAX, true).unwrap_or(usize::MAX)
+}
+
+/// This method is like [damerau_levenshtein] except that it returns `None` as soon as the
+/// distance is known to exceed `max_distance`, which is much faster than computing large
+/// distances.
+///
+/// * `a` - The first array to compare.
+/// * `b` - The second array to compare.
+/// * `max_distance` - The maximum distance of interest.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::damerau_levenshtein_within;
+///
+/// assert_eq!(
+///     damerau_levenshtein_within(b"recieve", b"receive", 1),
+///     Some(1)
+/// );
+/// assert_eq!(damerau_levenshtein_within(b"receive", b"deceiver", 1), None);
+/// ```
+pub fn damerau_levenshtein_within<A>(a: &[A], b: &[A], max_distance: usize) -> Option<usize>
+where
+    A: PartialEq,
+{
+    edit_distance(a, b, max_distance, true)
+}
+
+/// A trait that implements the [DamerauLevenshtein::damerau_levenshtein] method on strings.
+pub trait DamerauLevenshtein<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Computes the Damerau-Levenshtein distance, as the optimal string alignment distance,
+    /// between the characters of this string and `other`.
+    ///
+    /// * `other` - The string to compare with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DamerauLevenshtein;
+    ///
+    /// assert_eq!("teh".damerau_levenshtein("the"), 1);
+    /// ```
+    fn damerau_levenshtein(&self, other: &str) -> usize;
+
+    /// This method is like [DamerauLevenshtein::damerau_levenshtein] except that it returns
+    /// `None` as soon as the distance is known to exceed `max_distance`.
+    ///
+    /// * `other` - The string to compare with.
+    /// * `max_distance` - The maximum distance of interest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DamerauLevenshtein;
+    ///
+    /// assert_eq!("teh".damerau_levenshtein_within("then", 1), None);
+    /// ```
+    fn damerau_levenshtein_within(&self, other: &str, max_distance: usize) -> Option<usize>;
+}
+
+impl DamerauLevenshtein<String> for String {
+    fn damerau_levenshtein(&self, other: &str) -> usize {
+        self.as_str().damerau_levenshtein(other)
+    }
+
+    fn damerau_levenshtein_within(&self, other: &str, max_distance: usize) -> Option<usize> {
+        self.as_str()
+            .damerau_levenshtein_within(other, max_distance)
+    }
+}
+
+impl DamerauLevenshtein<str> for str {
+    fn damerau_levenshtein(&self, other: &str) -> usize {
+        damerau_levenshtein(
+            &self.chars().collect::<Vec<_>>(),
+            &other.chars().collect::<Vec<_>>(),
+        )
+    }
+
+    fn damerau_levenshtein_within(&self, other: &str, max_distance: usize) -> Option<usize> {
+        damerau_levenshtein_within(
+            &self.chars().collect::<Vec<_>>(),
+            &other.chars().collect::<Vec<_>>(),
+            max_distance,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein::tests::{
+        naive_edit_distance,
+        small_alphabet,
+    };
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_compute_damerau_levenshtein_distances() {
+        assert_eq!(damerau_levenshtein(b"", b"ab"), 2);
+        assert_eq!(damerau_levenshtein(b"ab", b"ba"), 1);
+        assert_eq!(damerau_levenshtein(b"abcdef", b"badcfe"), 3);
+        assert_eq!(damerau_levenshtein(&[1, 2, 3], &[1, 3, 2]), 1);
+        assert_eq!("Ünïcödé".damerau_levenshtein("Üïnöcdé"), 2);
+        assert_eq!("form".to_string().damerau_levenshtein("from"), 1);
+    }
+
+    #[test]
+    fn can_stop_at_the_maximum_distance() {
+        assert_eq!(damerau_levenshtein_within(b"abcd", b"badc", 2), Some(2));
+        assert_eq!(damerau_levenshtein_within(b"abcd", b"badc", 1), None);
+        assert_eq!("form".damerau_levenshtein_within("from", 1), Some(1));
+        assert_eq!(
+            "form".to_string().damerau_levenshtein_within("farm", 0),
+            None
+        );
+    }
+
+    quickcheck! {
+        fn damerau_levenshtein_matches_the_full_matrix(a: Vec<u8>, b: Vec<u8>) -> bool {
+            let (a, b) = (small_alphabet(a), small_alphabet(b));
+
+            damerau_levenshtein(&a, &b) == naive_edit_distance(&a, &b, true)
+        }
+
+        fn bounded_damerau_levenshtein_matches_unbounded(a: Vec<u8>, b: Vec<u8>, max: u8) -> bool {
+            let (a, b, max) = (small_alphabet(a), small_alphabet(b), usize::from(max % 8));
+            let distance = damerau_levenshtein(&a, &b);
+
+            damerau_levenshtein_within(&a, &b, max) == (distance <= max).then_some(distance)
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    codec::{
+        self,
+        ByteSet,
+        Encoder,
+    },
+    transliterations::{
+        self,
+        DEBURRED_LETTERS,
+    },
+};
+use std::{
+    borrow::Cow,
+    fmt,
+};
+
+/// The bytes that start non-ASCII characters, which are the only characters [deburr] and
+/// [transliterate](crate::transliterate) may replace.
+pub(crate) static NON_ASCII_BYTES: ByteSet = ByteSet::new(b"").with_non_ascii();
+
+/// Returns `true` if `c` is one of the combining diacritical marks removed by [deburr].
+pub(crate) fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36f}' | '\u{20d0}'..='\u{20ff}' | '\u{fe20}'..='\u{fe2f}'
+    )
+}
+
+/// The rules applied by [deburr].
+struct DeburrEncoder;
+
+impl Encoder for DeburrEncoder {
+    fn special_bytes(&self) -> &ByteSet {
+        &NON_ASCII_BYTES
+    }
+
+    fn encode<W>(&self, c: char, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        if is_combining_mark(c) {
+            return Ok(());
+        }
+
+        match transliterations::lookup(&DEBURRED_LETTERS, c) {
+            Some(replacement) => out.write_str(replacement),
+            None => out.write_char(c),
+        }
+    }
+}
+
+/// Deburrs `value` like the lodash `_.deburr` function, by converting the letters of the Latin-1
+/// Supplement and Latin Extended-A blocks to basic Latin letters and removing combining
+/// diacritical marks. Other characters are kept as they are; see
+/// [transliterate](crate::transliterate) for a fuller conversion to ASCII.
+///
+/// * `value` - The string to deburr.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::deburr;
+///
+/// assert_eq!(deburr("déjà vu"), "deja vu".to_string());
+/// assert_eq!(deburr("Crème Brûlée"), "Creme Brulee".to_string());
+/// assert_eq!(deburr("Łódź"), "Lodz".to_string());
+/// ```
+pub fn deburr<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    deburr_cow(value.as_ref()).into_owned()
+}
+
+/// This method is like [deburr] except that it returns `value` as is, without allocating, when
+/// it only contains ASCII characters.
+///
+/// * `value` - The string to deburr.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::deburr_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(deburr_cow("Æsir"), "Aesir");
+/// assert!(matches!(deburr_cow("fred"), Cow::Borrowed(_)));
+/// ```
+pub fn deburr_cow(value: &str) -> Cow<'_, str> {
+    codec::encode_cow(&DeburrEncoder, value)
+}
+
+/// A trait that implements the [Deburr::deburr] method on strings.
+pub trait Deburr<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Deburrs this string like the lodash `_.deburr` function, by converting Latin-1 Supplement
+    /// and Latin Extended-A letters to basic Latin letters and removing combining diacritical
+    /// marks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Deburr;
+    ///
+    /// assert_eq!("déjà vu".deburr(), "deja vu".to_string());
+    /// ```
+    fn deburr(&self) -> String;
+
+    /// This method is like [Deburr::deburr] except that it returns this string as is, without
+    /// allocating, when it only contains ASCII characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Deburr;
+    ///
+    /// assert_eq!("Straße".deburr_cow(), "Strasse");
+    /// ```
+    fn deburr_cow(&self) -> Cow<'_, str>;
+}
+
+impl Deburr<String> for String {
+    fn deburr(&self) -> String {
+        deburr(self)
+    }
+
+    fn deburr_cow(&self) -> Cow<'_, str> {
+        deburr_cow(self)
+    }
+}
+
+impl Deburr<str> for str {
+    fn deburr(&self) -> String {
+        deburr(self)
+    }
+
+    fn deburr_cow(&self) -> Cow<'_, str> {
+        deburr_cow(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_deburr_latin_letters() {
+        assert_eq!(
+            deburr("ÀÁÂÃÄÅàáâãäåÇçÐðÈÉÊËèéêëÌÍÎÏìíîïÑñÒÓÔÕÖØòóôõöøÙÚÛÜùúûüÝýÿ"),
+            "AAAAAAaaaaaaCcDdEEEEeeeeIIIIiiiiNnOOOOOOooooooUUUUuuuuYyy".to_string()
+        );
+        assert_eq!("ÆæÞþßĲĳŒœŉſ".deburr(), "AeaeThthssIJijOeoe'ns".to_string());
+        assert_eq!(
+            "Ōkubo Žižek Øresund".to_string().deburr(),
+            "Okubo Zizek Oresund".to_string()
+        );
+    }
+
+    #[test]
+    fn can_remove_combining_marks() {
+        assert_eq!(deburr("e\u{301}le\u{300}ve"), "eleve".to_string());
+        assert_eq!(deburr("a\u{20dd}b\u{fe20}"), "ab".to_string());
+    }
+
+    #[test]
+    fn can_keep_other_characters() {
+        assert_eq!(deburr("×÷ Ǎ Σ я 日本 🦀"), "×÷ Ǎ Σ я 日本 🦀".to_string());
+        assert_eq!(deburr(""), "".to_string());
+    }
+
+    #[test]
+    fn can_borrow_ascii_strings() {
+        assert!(matches!(deburr_cow("fred & barney"), Cow::Borrowed(_)));
+        assert!(matches!("café".deburr_cow(), Cow::Owned(_)));
+    }
+}
@@ -0,0 +1,420 @@
+use crate::codec::utf8_width;
+use memchr::{
+    memchr,
+    memchr2,
+};
+use std::{
+    error::Error,
+    fmt,
+    str,
+};
+
+/// The reserved characters that [decode_uri] keeps percent-encoded, as `decodeURI` does.
+const RESERVED: &[u8] = b";,/?:@&=+$#";
+
+/// The error returned when decoding a malformed percent-encoded string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeUriError {
+    /// A "%" character that is not followed by two hexadecimal digits.
+    InvalidEscape {
+        /// The byte index of the "%" character in the value.
+        index: usize,
+    },
+    /// A sequence of percent-encoded bytes that is not valid UTF-8.
+    InvalidUtf8 {
+        /// The byte index of the "%" character that starts the sequence in the value.
+        index: usize,
+    },
+}
+
+impl fmt::Display for DecodeUriError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEscape { index } => {
+                write!(f, "invalid percent-encoded byte at index {index}")
+            }
+            Self::InvalidUtf8 { index } => {
+                write!(f, "invalid percent-encoded UTF-8 sequence at index {index}")
+            }
+        }
+    }
+}
+
+impl Error for DecodeUriError {}
+
+/// Decodes the two hexadecimal digits following the "%" character at `index` of `bytes`.
+fn decode_byte(bytes: &[u8], index: usize) -> Result<u8, DecodeUriError> {
+    let digit = |offset: usize| {
+        bytes
+            .get(index + offset)
+            .and_then(|byte| char::from(*byte).to_digit(16))
+            .ok_or(DecodeUriError::InvalidEscape { index })
+    };
+
+    Ok((digit(1)? * 16 + digit(2)?) as u8)
+}
+
+/// Decodes the percent-encoded bytes of `value`.
+///
+/// * `value` - The string to decode.
+/// * `preserved` - The ASCII characters that are kept percent-encoded.
+/// * `plus_as_space` - Whether "+" characters are decoded as spaces.
+fn decode(value: &str, preserved: &[u8], plus_as_space: bool) -> Result<String, DecodeUriError> {
+    let bytes = value.as_bytes();
+    let find = |from: usize| {
+        if plus_as_space {
+            memchr2(b'%', b'+', &bytes[from..])
+        } else {
+            memchr(b'%', &bytes[from..])
+        }
+        .map(|index| from + index)
+    };
+    let mut decoded = String::with_capacity(value.len());
+    let mut start = 0;
+
+    while let Some(index) = find(start) {
+        decoded.push_str(&value[start..index]);
+
+        if bytes[index] == b'+' {
+            decoded.push(' ');
+            start = index + 1;
+            continue;
+        }
+
+        let mut sequence = [decode_byte(bytes, index)?, 0, 0, 0];
+        let width = utf8_width(sequence[0]).ok_or(DecodeUriError::InvalidUtf8 { index })?;
+
+        for (offset, byte) in sequence.iter_mut().enumerate().take(width).skip(1) {
+            let position = index + offset * 3;
+
+            if bytes.get(position) != Some(&b'%') {
+                return Err(DecodeUriError::InvalidUtf8 { index });
+            }
+
+            *byte = decode_byte(bytes, position)?;
+        }
+
+        let c = str::from_utf8(&sequence[..width])
+            .map_err(|_| DecodeUriError::InvalidUtf8 { index })?;
+        let end = index + width * 3;
+
+        if preserved.contains(&sequence[0]) {
+            decoded.push_str(&value[index..end]);
+        } else {
+            decoded.push_str(c);
+        }
+
+        start = end;
+    }
+
+    decoded.push_str(&value[start..]);
+
+    Ok(decoded)
+}
+
+/// The inverse of [encode_uri]. This method decodes the percent-encoded bytes of `value`, like
+/// the JavaScript `decodeURI` function. Escape sequences of the characters that [encode_uri]
+/// keeps, such as "%2F" for "/", are left encoded so that the structure of the URI is unchanged.
+///
+/// * `value` - The URI to decode.
+///
+/// # Errors
+///
+/// Returns a [DecodeUriError] if `value` contains a "%" character that is not followed by two
+/// hexadecimal digits, or percent-encoded bytes that are not valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::decode_uri;
+///
+/// assert_eq!(
+///     decode_uri("https://example.com/fred%20%26%20barney%2Fpebbles?q=%C3%A9"),
+///     Ok("https://example.com/fred %26 barney%2Fpebbles?q=é".to_string())
+/// );
+/// assert!(decode_uri("100%").is_err());
+/// ```
+///
+/// [encode_uri]: crate::encode_uri
+pub fn decode_uri<S>(value: S) -> Result<String, DecodeUriError>
+where
+    S: AsRef<str>,
+{
+    decode(value.as_ref(), RESERVED, false)
+}
+
+/// The inverse of [encode_uri_component]. This method decodes all the percent-encoded bytes of
+/// `value`, like the JavaScript `decodeURIComponent` function.
+///
+/// * `value` - The URI component to decode.
+///
+/// # Errors
+///
+/// Returns a [DecodeUriError] if `value` contains a "%" character that is not followed by two
+/// hexadecimal digits, or percent-encoded bytes that are not valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     decode_uri_component,
+///     DecodeUriError,
+/// };
+///
+/// assert_eq!(
+///     decode_uri_component("fred%20%26%20barney%2F%C3%A9"),
+///     Ok("fred & barney/é".to_string())
+/// );
+/// assert_eq!(
+///     decode_uri_component("%C3%28"),
+///     Err(DecodeUriError::InvalidUtf8 { index: 0 })
+/// );
+/// ```
+///
+/// [encode_uri_component]: crate::encode_uri_component
+pub fn decode_uri_component<S>(value: S) -> Result<String, DecodeUriError>
+where
+    S: AsRef<str>,
+{
+    decode(value.as_ref(), &[], false)
+}
+
+/// The inverse of [encode_form_component]. This method decodes a name or value of an
+/// `application/x-www-form-urlencoded` form, in which "+" characters are decoded as spaces.
+///
+/// * `value` - The string to decode.
+///
+/// # Errors
+///
+/// Returns a [DecodeUriError] if `value` contains a "%" character that is not followed by two
+/// hexadecimal digits, or percent-encoded bytes that are not valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::decode_form_component;
+///
+/// assert_eq!(
+///     decode_form_component("fred+%26+barney%2B"),
+///     Ok("fred & barney+".to_string())
+/// );
+/// ```
+///
+/// [encode_form_component]: crate::encode_form_component
+pub fn decode_form_component<S>(value: S) -> Result<String, DecodeUriError>
+where
+    S: AsRef<str>,
+{
+    decode(value.as_ref(), &[], true)
+}
+
+/// A trait that implements the [DecodeUri::decode_uri] method on strings.
+pub trait DecodeUri<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// The inverse of [encode_uri]. This method decodes the percent-encoded bytes of this string,
+    /// like the JavaScript `decodeURI` function.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [DecodeUriError] if this string contains a "%" character that is not followed by
+    /// two hexadecimal digits, or percent-encoded bytes that are not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DecodeUri;
+    ///
+    /// assert_eq!(
+    ///     "/fred%20%26%20barney".decode_uri(),
+    ///     Ok("/fred %26 barney".to_string())
+    /// );
+    /// ```
+    ///
+    /// [encode_uri]: crate::EncodeUri::encode_uri
+    fn decode_uri(&self) -> Result<String, DecodeUriError>;
+
+    /// The inverse of [encode_uri_component]. This method decodes all the percent-encoded bytes
+    /// of this string, like the JavaScript `decodeURIComponent` function.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [DecodeUriError] if this string contains a "%" character that is not followed by
+    /// two hexadecimal digits, or percent-encoded bytes that are not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DecodeUri;
+    ///
+    /// assert_eq!(
+    ///     "fred%20%26%20barney".decode_uri_component(),
+    ///     Ok("fred & barney".to_string())
+    /// );
+    /// ```
+    ///
+    /// [encode_uri_component]: crate::EncodeUri::encode_uri_component
+    fn decode_uri_component(&self) -> Result<String, DecodeUriError>;
+
+    /// The inverse of [encode_form_component]. This method decodes a name or value of an
+    /// `application/x-www-form-urlencoded` form.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [DecodeUriError] if this string contains a "%" character that is not followed by
+    /// two hexadecimal digits, or percent-encoded bytes that are not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DecodeUri;
+    ///
+    /// assert_eq!(
+    ///     "fred+%26+barney".decode_form_component(),
+    ///     Ok("fred & barney".to_string())
+    /// );
+    /// ```
+    ///
+    /// [encode_form_component]: crate::EncodeUri::encode_form_component
+    fn decode_form_component(&self) -> Result<String, DecodeUriError>;
+}
+
+impl DecodeUri<String> for String {
+    fn decode_uri(&self) -> Result<String, DecodeUriError> {
+        decode_uri(self)
+    }
+
+    fn decode_uri_component(&self) -> Result<String, DecodeUriError> {
+        decode_uri_component(self)
+    }
+
+    fn decode_form_component(&self) -> Result<String, DecodeUriError> {
+        decode_form_component(self)
+    }
+}
+
+impl DecodeUri<str> for str {
+    fn decode_uri(&self) -> Result<String, DecodeUriError> {
+        decode_uri(self)
+    }
+
+    fn decode_uri_component(&self) -> Result<String, DecodeUriError> {
+        decode_uri_component(self)
+    }
+
+    fn decode_form_component(&self) -> Result<String, DecodeUriError> {
+        decode_form_component(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        encode_form_component,
+        encode_uri,
+        encode_uri_component,
+        EncodeUri,
+    };
+
+    /// A value with ASCII punctuation and multi-byte characters.
+    const VALUE: &str = r##" !"#$%&'()*+,-./:;<=>?@[\]^_`{|}~ é€😀"##;
+
+    #[test]
+    fn can_decode_uri_components() {
+        assert_eq!(
+            decode_uri_component("%F0%9F%98%80%e2%82%ac+%2b"),
+            Ok("😀€++".to_string())
+        );
+        assert_eq!(
+            decode_uri_component(encode_uri_component(VALUE)),
+            Ok(VALUE.to_string())
+        );
+        assert_eq!(
+            VALUE.encode_uri_component().decode_uri_component(),
+            Ok(VALUE.to_string())
+        );
+    }
+
+    #[test]
+    fn can_decode_uris() {
+        assert_eq!(
+            decode_uri("%3B%2F%3F%3A%40%26%3D%2B%24%2C%23%20%25%61%7E"),
+            Ok("%3B%2F%3F%3A%40%26%3D%2B%24%2C%23 %a~".to_string())
+        );
+        assert_eq!(decode_uri(encode_uri(VALUE)), Ok(VALUE.to_string()));
+        assert_eq!(VALUE.encode_uri().decode_uri(), Ok(VALUE.to_string()));
+    }
+
+    #[test]
+    fn can_decode_form_components() {
+        assert_eq!(
+            decode_form_component("a+b%2B%20c"),
+            Ok("a b+ c".to_string())
+        );
+        assert_eq!(
+            decode_form_component(encode_form_component(VALUE)),
+            Ok(VALUE.to_string())
+        );
+        assert_eq!(
+            VALUE.encode_form_component().decode_form_component(),
+            Ok(VALUE.to_string())
+        );
+    }
+
+    #[test]
+    fn can_report_malformed_escapes() {
+        assert_eq!(
+            decode_uri_component("ab%"),
+            Err(DecodeUriError::InvalidEscape { index: 2 })
+        );
+        assert_eq!(
+            decode_uri_component("ab%4"),
+            Err(DecodeUriError::InvalidEscape { index: 2 })
+        );
+        assert_eq!(
+            decode_uri("%zz"),
+            Err(DecodeUriError::InvalidEscape { index: 0 })
+        );
+        assert_eq!(
+            "a%E2%82%ZZ".decode_form_component(),
+            Err(DecodeUriError::InvalidEscape { index: 7 })
+        );
+        assert_eq!(
+            DecodeUriError::InvalidEscape { index: 2 }.to_string(),
+            "invalid percent-encoded byte at index 2"
+        );
+    }
+
+    #[test]
+    fn can_report_invalid_utf8() {
+        assert_eq!(
+            decode_uri_component("a%FF"),
+            Err(DecodeUriError::InvalidUtf8 { index: 1 })
+        );
+        assert_eq!(
+            decode_uri_component("a%E2%82"),
+            Err(DecodeUriError::InvalidUtf8 { index: 1 })
+        );
+        assert_eq!(
+            decode_uri_component("a%E2%82b"),
+            Err(DecodeUriError::InvalidUtf8 { index: 1 })
+        );
+        assert_eq!(
+            decode_uri_component("%ED%A0%80"),
+            Err(DecodeUriError::InvalidUtf8 { index: 0 })
+        );
+        assert_eq!(
+            DecodeUriError::InvalidUtf8 { index: 1 }.to_string(),
+            "invalid percent-encoded UTF-8 sequence at index 1"
+        );
+    }
+
+    #[test]
+    fn can_handle_strings_with_nothing_to_decode() {
+        assert_eq!(decode_uri("abc"), Ok("abc".to_string()));
+        assert_eq!(decode_uri_component("a+b"), Ok("a+b".to_string()));
+        assert_eq!("abc".decode_form_component(), Ok("abc".to_string()));
+    }
+}
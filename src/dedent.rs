@@ -0,0 +1,113 @@
+/// Removes the leading spaces and tabs that every line of `text` that is not blank starts with,
+/// such as the indentation of a string literal in indented code. Blank lines are ignored when
+/// finding the common indentation, and have their whitespace removed. Spaces and tabs are
+/// different characters, so a line indented with a tab has no indentation in common with a line
+/// indented with spaces.
+///
+/// * `text` - The text to dedent.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::dedent;
+///
+/// assert_eq!(
+///     dedent("    fn main() {\n        run();\n\n    }\n"),
+///     "fn main() {\n    run();\n\n}\n".to_string()
+/// );
+/// assert_eq!(dedent("  a\n b"), " a\nb".to_string());
+/// ```
+pub fn dedent<S>(text: S) -> String
+where
+    S: AsRef<str>,
+{
+    let text = text.as_ref();
+    let mut margin: Option<&str> = None;
+
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+
+        margin = Some(match margin {
+            None => indent,
+            Some(margin) => {
+                let common = margin
+                    .bytes()
+                    .zip(indent.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+
+                // The margin only contains ASCII characters, so any index is a character boundary.
+                &margin[..common]
+            }
+        });
+    }
+
+    let margin = margin.unwrap_or_default();
+    let mut dedented = String::with_capacity(text.len());
+
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            dedented.push_str(&line[line.trim_end_matches(['\r', '\n']).len()..]);
+        } else {
+            dedented.push_str(&line[margin.len()..]);
+        }
+    }
+
+    dedented
+}
+
+/// A trait that implements the [Dedent::dedent] method on strings.
+pub trait Dedent<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Removes the leading spaces and tabs that every line of this string that is not blank
+    /// starts with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Dedent;
+    ///
+    /// assert_eq!("  a\n    b".dedent(), "a\n  b".to_string());
+    /// ```
+    fn dedent(&self) -> String;
+}
+
+impl Dedent<String> for String {
+    fn dedent(&self) -> String {
+        dedent(self)
+    }
+}
+
+impl Dedent<str> for str {
+    fn dedent(&self) -> String {
+        dedent(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indent;
+
+    #[test]
+    fn can_dedent() {
+        assert_eq!(dedent(""), "".to_string());
+        assert_eq!(dedent("   \n\t\n"), "\n\n".to_string());
+        assert_eq!("a\n  b".dedent(), "a\n  b".to_string());
+        assert_eq!(
+            "\t\ta\r\n\t  \r\n\t\tb".to_string().dedent(),
+            "a\r\n\r\nb".to_string()
+        );
+        assert_eq!(dedent("\ta\n    b"), "\ta\n    b".to_string());
+        assert_eq!(dedent("  \u{a0}a\n  b"), "\u{a0}a\nb".to_string());
+    }
+
+    #[test]
+    fn can_undo_indent() {
+        let text = "fn main() {\n    run();\n\n}\n";
+
+        assert_eq!(dedent(indent(text, "\t  ")), text.to_string());
+    }
+}
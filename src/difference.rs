@@ -1,4 +1,53 @@
-use std::ops::Not;
+use std::{
+    collections::HashSet,
+    hash::Hash,
+};
+
+/// The length of the smaller input up to which values are looked up with a linear search, which
+/// is faster than building a lookup structure for a few values.
+pub(crate) const LINEAR_SEARCH_THRESHOLD: usize = 16;
+
+/// Creates a vector of `array` values not included in any of the `others` arrays. Binary search
+/// is used on each of `others` if `is_sorted` is set. Otherwise, a linear search is used for small
+/// inputs and a binary search on sorted references to the values of `others` for larger ones.
+pub(crate) fn difference_of<A>(array: &[A], others: &[&[A]], is_sorted: bool) -> Vec<A>
+where
+    A: Ord + Clone,
+{
+    if is_sorted {
+        return array
+            .iter()
+            .filter(|item| {
+                others
+                    .iter()
+                    .all(|other| other.binary_search(item).is_err())
+            })
+            .cloned()
+            .collect();
+    }
+
+    let others_len = others.iter().map(|other| other.len()).sum::<usize>();
+
+    if array.len().min(others_len) <= LINEAR_SEARCH_THRESHOLD {
+        return array
+            .iter()
+            .filter(|item| !others.iter().any(|other| other.contains(item)))
+            .cloned()
+            .collect();
+    }
+
+    let mut excluded = others
+        .iter()
+        .flat_map(|other| other.iter())
+        .collect::<Vec<_>>();
+    excluded.sort_unstable();
+
+    array
+        .iter()
+        .filter(|item| excluded.binary_search(item).is_err())
+        .cloned()
+        .collect()
+}
 
 /// Creates a vector of `array` values not included in the `other` array.
 ///
@@ -6,8 +55,16 @@ use std::ops::Not;
 /// * `other` - The values to exclude.
 /// * `is_sorted` - The sorted flag. Should be set to `Some(true)` if you are certain that `other`
 ///   is a sorted array. Internally, binary search is utilized when this flag is provided and is
-///   truthy, enabling searching in `O(log n)` time, compared to the `O(n)` time complexity of
-///   linear search otherwise.
+///   truthy, enabling searching in `O(log n)` time. Otherwise, references to the values of `other`
+///   are sorted first, in `O(n log n)` time, unless the arrays are so small that a linear search is
+///   faster. If `array` is sorted as well, [difference_sorted](crate::difference_sorted) merges
+///   both arrays in `O(n + m)` time instead.
+///
+/// This takes `O((n + m) log m)` time for large arrays. Use
+/// [difference_hashed](crate::difference_hashed) for `O(n + m)` time when the values are hashable,
+/// or [difference_sorted](crate::difference_sorted) when both arrays are sorted. The hashed
+/// strategy cannot be selected automatically for hashable values, as Rust has no specialization
+/// that would let this method detect a `Hash` implementation.
 ///
 /// # Examples
 ///
@@ -24,18 +81,74 @@ use std::ops::Not;
 pub fn difference<A>(array: &[A], other: &[A], is_sorted: Option<bool>) -> Vec<A>
 where
     A: PartialEq + Ord + Clone,
+{
+    difference_of(array, &[other], is_sorted.unwrap_or_default())
+}
+
+/// This method is like [difference] except that it accepts `iteratee` which is invoked for each
+/// value of `array` and `other` to generate the key by which they are compared, like the lodash
+/// `_.differenceBy` function. The keys of `other` are looked up in a hash set, so the values
+/// themselves only need to be cloneable.
+///
+/// * `array` - The array to inspect.
+/// * `other` - The values to exclude.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::difference_by;
+///
+/// let result = difference_by(&[2.1, 1.2], &[2.3, 3.4], |n: &f64| n.floor() as i64);
+/// assert_eq!(result, [1.2]);
+///
+/// let result = difference_by(&["Fred", "barney"], &["BARNEY"], |name| name.to_lowercase());
+/// assert_eq!(result, ["Fred"]);
+/// ```
+pub fn difference_by<A, K, F>(array: &[A], other: &[A], mut iteratee: F) -> Vec<A>
+where
+    A: Clone,
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    let excluded = other.iter().map(&mut iteratee).collect::<HashSet<_>>();
+
+    array
+        .iter()
+        .filter(|item| !excluded.contains(&iteratee(item)))
+        .cloned()
+        .collect()
+}
+
+/// This method is like [difference] except that it accepts `comparator` which is invoked with a
+/// value of `array` and a value of `other` to tell whether they are equal, like the lodash
+/// `_.differenceWith` function. Every value of `array` is compared with every value of `other`,
+/// in `O(n * m)` time, so [difference_by] should be preferred when values can be compared by a
+/// key.
+///
+/// * `array` - The array to inspect.
+/// * `other` - The values to exclude.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::difference_with;
+///
+/// let result = difference_with(&["Fred", "barney"], &["BARNEY"], |a, b| {
+///     a.eq_ignore_ascii_case(b)
+/// });
+/// assert_eq!(result, ["Fred"]);
+/// ```
+pub fn difference_with<A, F>(array: &[A], other: &[A], mut comparator: F) -> Vec<A>
+where
+    A: Clone,
+    F: FnMut(&A, &A) -> bool,
 {
     array
         .iter()
-        .filter_map(|item| {
-            if is_sorted.unwrap_or_default() {
-                other.binary_search(item).is_ok()
-            } else {
-                other.contains(item)
-            }
-            .not()
-            .then_some(item.to_owned())
-        })
+        .filter(|item| !other.iter().any(|value| comparator(item, value)))
+        .cloned()
         .collect()
 }
 
@@ -43,15 +156,18 @@ where
 pub trait Difference<A, I>
 where
     A: IntoIterator,
-    I: PartialEq + Ord + Clone,
+    I: Clone,
 {
     /// Creates a vector of values in this array that are not included in the `other` array.
     ///
     /// * `other` - The values to exclude.
     /// * `is_sorted` - The sorted flag. Should be set to `Some(true)` if you are certain that
     ///   `other` is a sorted array. Internally, binary search is utilized when this flag is
-    ///   provided and is truthy, enabling searching in `O(log n)` time, compared to the `O(n)` time
-    ///   complexity of linear search otherwise.
+    ///   provided and is truthy, enabling searching in `O(log n)` time. Otherwise, references to
+    ///   the values of `other` are sorted first, in `O(n log n)` time, unless the arrays are so
+    ///   small that a linear search is faster. If this array is sorted as well,
+    ///   [difference_sorted](crate::difference_sorted) merges both arrays in `O(n + m)` time
+    ///   instead.
     ///
     /// # Examples
     ///
@@ -63,30 +179,112 @@ where
     /// let sorted_values = [2, 3, 4, 5];
     /// assert_eq!([2, 1].difference(&sorted_values, Some(true)), [1]);
     /// ```
-    fn difference(&self, other: &[I], is_sorted: Option<bool>) -> Vec<I>;
+    fn difference(&self, other: &[I], is_sorted: Option<bool>) -> Vec<I>
+    where
+        I: PartialEq + Ord;
+
+    /// This method is like [Difference::difference] except that it accepts `iteratee` which is
+    /// invoked for each value of this array and `other` to generate the key by which they are
+    /// compared.
+    ///
+    /// * `other` - The values to exclude.
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Difference;
+    ///
+    /// assert_eq!(
+    ///     [2.1, 1.2].difference_by(&[2.3, 3.4], |n: &f64| n.floor() as i64),
+    ///     [1.2]
+    /// );
+    /// ```
+    fn difference_by<K, F>(&self, other: &[I], iteratee: F) -> Vec<I>
+    where
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [Difference::difference] except that it accepts `comparator` which is
+    /// invoked with a value of this array and a value of `other` to tell whether they are equal.
+    ///
+    /// * `other` - The values to exclude.
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Difference;
+    ///
+    /// assert_eq!(
+    ///     ["Fred", "barney"].difference_with(&["BARNEY"], |a, b| a.eq_ignore_ascii_case(b)),
+    ///     ["Fred"]
+    /// );
+    /// ```
+    fn difference_with<F>(&self, other: &[I], comparator: F) -> Vec<I>
+    where
+        F: FnMut(&I, &I) -> bool;
 }
 
 impl<A> Difference<Vec<A>, A> for Vec<A>
 where
-    A: PartialEq + Ord + Clone,
+    A: Clone,
 {
-    fn difference(&self, other: &[A], is_sorted: Option<bool>) -> Vec<A> {
+    fn difference(&self, other: &[A], is_sorted: Option<bool>) -> Vec<A>
+    where
+        A: PartialEq + Ord,
+    {
         difference(self, other, is_sorted)
     }
+
+    fn difference_by<K, F>(&self, other: &[A], iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        difference_by(self, other, iteratee)
+    }
+
+    fn difference_with<F>(&self, other: &[A], comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        difference_with(self, other, comparator)
+    }
 }
 
 impl<A, const N: usize> Difference<[A; N], A> for [A; N]
 where
-    A: PartialEq + Ord + Clone,
+    A: Clone,
 {
-    fn difference(&self, other: &[A], is_sorted: Option<bool>) -> Vec<A> {
+    fn difference(&self, other: &[A], is_sorted: Option<bool>) -> Vec<A>
+    where
+        A: PartialEq + Ord,
+    {
         difference(self, other, is_sorted)
     }
+
+    fn difference_by<K, F>(&self, other: &[A], iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        difference_by(self, other, iteratee)
+    }
+
+    fn difference_with<F>(&self, other: &[A], comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        difference_with(self, other, comparator)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::user;
+    use quickcheck::quickcheck;
 
     #[test]
     fn can_return_difference_of_two_arrays() {
@@ -107,4 +305,47 @@ mod tests {
 
         assert_eq!([2, 1].difference(&[2, 3, 4, 5], Some(true)), [1]);
     }
+
+    #[test]
+    fn can_return_difference_of_large_arrays() {
+        let array = (0..100).rev().collect::<Vec<_>>();
+        let other = (0..100).filter(|n| n % 3 != 0).collect::<Vec<_>>();
+        let expected = (0..100).rev().filter(|n| n % 3 == 0).collect::<Vec<_>>();
+
+        assert_eq!(difference(&array, &other, None), expected);
+        assert_eq!(difference(&array, &other, Some(true)), expected);
+        assert_eq!(difference(&[7, 7, 99], &other, None), [99]);
+    }
+
+    quickcheck! {
+        fn matches_linear_search(array: Vec<u8>, other: Vec<u8>) -> bool {
+            let expected = array
+                .iter()
+                .filter(|item| !other.contains(item))
+                .copied()
+                .collect::<Vec<_>>();
+            let mut sorted = other.clone();
+            sorted.sort_unstable();
+
+            difference(&array, &other, None) == expected
+                && difference(&array, &sorted, Some(true)) == expected
+        }
+    }
+
+    #[test]
+    fn can_keep_duplicates_not_matched_by_key_or_comparator() {
+        let users = [user(1, "fred"), user(2, "barney"), user(1, "Fred")];
+
+        assert_eq!(
+            difference_by(&users, &[user(2, "x")], |user| user.id),
+            [user(1, "fred"), user(1, "Fred")]
+        );
+        assert_eq!(
+            users.difference_with(&[user(3, "FRED")], |a, b| a
+                .name
+                .eq_ignore_ascii_case(b.name)),
+            [user(2, "barney")]
+        );
+        assert_eq!(vec![1, 2, 1].difference_by(&[], |n| *n), [1, 2, 1]);
+    }
 }
@@ -1,13 +1,21 @@
-use crate::difference;
+use crate::difference::difference_of;
+use std::{
+    collections::HashSet,
+    hash::Hash,
+};
 
 /// Creates a vector of `array` values not included in the `others` nested arrays.
 ///
 /// * `array` - The array to inspect.
 /// * `others` - The nested values to exclude.
-/// * `is_sorted` - The sorted flag. Should be set to `Some(true)` if you are certain that `other`
-///   is a sorted array. Internally, binary search is utilized when this flag is provided and is
-///   truthy, enabling searching in `O(log n)` time, compared to the `O(n)` time complexity of
-///   linear search otherwise.
+/// * `is_sorted` - The sorted flag. Should be set to `Some(true)` if you are certain that each of
+///   `others` is a sorted array. Internally, binary search is utilized when this flag is provided
+///   and is truthy, enabling searching in `O(log n)` time. Otherwise, references to the values of
+///   `others` are sorted first, in `O(n log n)` time, unless the arrays are so small that a linear
+///   search is faster.
+///
+/// Use [difference_all_hashed](crate::difference_all_hashed) for `O(n + m)` time when the values
+/// are hashable, as this method cannot detect a `Hash` implementation to select it automatically.
 ///
 /// # Examples
 ///
@@ -21,29 +29,94 @@ pub fn difference_all<A>(array: &[A], others: Vec<&[A]>, is_sorted: Option<bool>
 where
     A: PartialEq + Ord + Clone,
 {
-    let flattened = others
+    difference_of(array, &others, is_sorted.unwrap_or_default())
+}
+
+/// This method is like [difference_all] except that it accepts `iteratee` which is invoked for
+/// each value of `array` and `others` to generate the key by which they are compared. The keys of
+/// `others` are looked up in a hash set, so the values themselves only need to be cloneable.
+///
+/// * `array` - The array to inspect.
+/// * `others` - The nested values to exclude.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::difference_all_by;
+///
+/// let result = difference_all_by(&[1.5, 2.5, 3.5], vec![&[1.1], &[3.3]], |n: &f64| {
+///     n.floor() as i64
+/// });
+/// assert_eq!(result, [2.5]);
+/// ```
+pub fn difference_all_by<A, K, F>(array: &[A], others: Vec<&[A]>, mut iteratee: F) -> Vec<A>
+where
+    A: Clone,
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    let excluded = others
         .into_iter()
         .flatten()
-        .map(|item| item.to_owned())
-        .collect::<Vec<_>>();
+        .map(&mut iteratee)
+        .collect::<HashSet<_>>();
+
+    array
+        .iter()
+        .filter(|item| !excluded.contains(&iteratee(item)))
+        .cloned()
+        .collect()
+}
 
-    difference(array, &flattened, is_sorted)
+/// This method is like [difference_all] except that it accepts `comparator` which is invoked with
+/// a value of `array` and a value of `others` to tell whether they are equal.
+///
+/// * `array` - The array to inspect.
+/// * `others` - The nested values to exclude.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::difference_all_with;
+///
+/// let result = difference_all_with(&["a", "B", "c"], vec![&["b"], &["A"]], |a, b| {
+///     a.eq_ignore_ascii_case(b)
+/// });
+/// assert_eq!(result, ["c"]);
+/// ```
+pub fn difference_all_with<A, F>(array: &[A], others: Vec<&[A]>, mut comparator: F) -> Vec<A>
+where
+    A: Clone,
+    F: FnMut(&A, &A) -> bool,
+{
+    array
+        .iter()
+        .filter(|item| {
+            !others
+                .iter()
+                .any(|other| other.iter().any(|value| comparator(item, value)))
+        })
+        .cloned()
+        .collect()
 }
 
 /// A trait that implements the [DifferenceAll::difference_all] method on arrays.
 pub trait DifferenceAll<A, I>
 where
     A: IntoIterator,
-    I: PartialEq + Ord + Clone,
+    I: Clone,
 {
     /// Creates a vector of values in this array that are not included in the `others` nested
     /// arrays.
     ///
     /// * `others` - The nested values to exclude.
-    /// * `is_sorted` - The sorted flag. Should be set to `Some(true)` if you are certain that
-    ///   `other` is a sorted array. Internally, binary search is utilized when this flag is
-    ///   provided and is truthy, enabling searching in `O(log n)` time, compared to the `O(n)` time
-    ///   complexity of linear search otherwise.
+    /// * `is_sorted` - The sorted flag. Should be set to `Some(true)` if you are certain that each
+    ///   of `others` is a sorted array. Internally, binary search is utilized when this flag is
+    ///   provided and is truthy, enabling searching in `O(log n)` time. Otherwise, references to
+    ///   the values of `others` are sorted first, in `O(n log n)` time, unless the arrays are so
+    ///   small that a linear search is faster.
     ///
     /// # Examples
     ///
@@ -55,30 +128,111 @@ where
     ///     [1]
     /// );
     /// ```
-    fn difference_all(&self, others: Vec<&[I]>, is_sorted: Option<bool>) -> Vec<I>;
+    fn difference_all(&self, others: Vec<&[I]>, is_sorted: Option<bool>) -> Vec<I>
+    where
+        I: PartialEq + Ord;
+
+    /// This method is like [DifferenceAll::difference_all] except that it accepts `iteratee` which
+    /// is invoked for each value of this array and `others` to generate the key by which they are
+    /// compared.
+    ///
+    /// * `others` - The nested values to exclude.
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DifferenceAll;
+    ///
+    /// assert_eq!(
+    ///     [1.5, 2.5, 3.5].difference_all_by(vec![&[1.1], &[3.3]], |n: &f64| n.floor() as i64),
+    ///     [2.5]
+    /// );
+    /// ```
+    fn difference_all_by<K, F>(&self, others: Vec<&[I]>, iteratee: F) -> Vec<I>
+    where
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [DifferenceAll::difference_all] except that it accepts `comparator`
+    /// which is invoked with a value of this array and a value of `others` to tell whether they
+    /// are equal.
+    ///
+    /// * `others` - The nested values to exclude.
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DifferenceAll;
+    ///
+    /// let result =
+    ///     ["a", "B", "c"].difference_all_with(vec![&["b"], &["A"]], |a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(result, ["c"]);
+    /// ```
+    fn difference_all_with<F>(&self, others: Vec<&[I]>, comparator: F) -> Vec<I>
+    where
+        F: FnMut(&I, &I) -> bool;
 }
 
 impl<A> DifferenceAll<Vec<A>, A> for Vec<A>
 where
-    A: PartialEq + Ord + Clone,
+    A: Clone,
 {
-    fn difference_all(&self, others: Vec<&[A]>, is_sorted: Option<bool>) -> Vec<A> {
+    fn difference_all(&self, others: Vec<&[A]>, is_sorted: Option<bool>) -> Vec<A>
+    where
+        A: PartialEq + Ord,
+    {
         difference_all(self, others, is_sorted)
     }
+
+    fn difference_all_by<K, F>(&self, others: Vec<&[A]>, iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        difference_all_by(self, others, iteratee)
+    }
+
+    fn difference_all_with<F>(&self, others: Vec<&[A]>, comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        difference_all_with(self, others, comparator)
+    }
 }
 
 impl<A, const N: usize> DifferenceAll<[A; N], A> for [A; N]
 where
-    A: PartialEq + Ord + Clone,
+    A: Clone,
 {
-    fn difference_all(&self, others: Vec<&[A]>, is_sorted: Option<bool>) -> Vec<A> {
+    fn difference_all(&self, others: Vec<&[A]>, is_sorted: Option<bool>) -> Vec<A>
+    where
+        A: PartialEq + Ord,
+    {
         difference_all(self, others, is_sorted)
     }
+
+    fn difference_all_by<K, F>(&self, others: Vec<&[A]>, iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        difference_all_by(self, others, iteratee)
+    }
+
+    fn difference_all_with<F>(&self, others: Vec<&[A]>, comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        difference_all_with(self, others, comparator)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::user;
 
     #[test]
     fn can_return_difference_of_multiple_arrays() {
@@ -94,6 +248,8 @@ mod tests {
             [2, 1, 2, 3].difference_all(vec![&[3, 4], &[3, 2]], None),
             [1]
         );
+
+        assert_eq!(difference_all(&[1, 2, 3], vec![&[1], &[2, 4]], None), [3]);
     }
 
     #[test]
@@ -111,4 +267,47 @@ mod tests {
             [1]
         );
     }
+
+    #[test]
+    fn can_search_each_sorted_array() {
+        let others: Vec<&[i32]> = vec![&[4, 5, 6], &[1, 2, 3]];
+
+        assert_eq!(difference_all(&[0, 1, 2, 6, 7], others, Some(true)), [0, 7]);
+    }
+
+    #[test]
+    fn can_return_difference_of_multiple_large_arrays() {
+        let array = (0..100).collect::<Vec<_>>();
+        let evens = (0..100).step_by(2).collect::<Vec<_>>();
+        let threes = (0..100).step_by(3).collect::<Vec<_>>();
+        let expected = (0..100)
+            .filter(|n| n % 2 != 0 && n % 3 != 0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            difference_all(&array, vec![&evens, &threes], None),
+            expected
+        );
+        assert_eq!(
+            difference_all(&array, vec![&evens, &threes], Some(true)),
+            expected
+        );
+    }
+
+    #[test]
+    fn can_exclude_matches_of_any_nested_array_by_key_or_comparator() {
+        let users = [user(1, "fred"), user(2, "barney"), user(3, "pebbles")];
+        let (first, second) = ([user(1, "x")], [user(3, "BARNEY"), user(4, "y")]);
+
+        assert_eq!(
+            difference_all_by(&users, vec![&first, &second], |user| user.id),
+            [user(2, "barney")]
+        );
+        assert_eq!(
+            users.difference_all_with(vec![&first, &second], |a, b| a
+                .name
+                .eq_ignore_ascii_case(b.name)),
+            [user(1, "fred"), user(3, "pebbles")]
+        );
+    }
 }
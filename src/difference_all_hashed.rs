@@ -0,0 +1,98 @@
+use crate::difference_hashed::difference_hashed_of;
+use std::hash::Hash;
+
+/// Creates a vector of `array` values not included in the `others` nested arrays, like
+/// [difference_all] except that values are looked up in a hash set of the values of `others`.
+/// This takes `O(n + m)` time, where `m` is the total length of `others`, and only requires the
+/// values to be hashable rather than ordered. A linear search is used instead when the arrays are
+/// so small that it is faster.
+///
+/// * `array` - The array to inspect.
+/// * `others` - The nested values to exclude.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::difference_all_hashed;
+///
+/// let result = difference_all_hashed(&[2, 1, 2, 3], vec![&[3, 4], &[3, 2]]);
+/// assert_eq!(result, [1]);
+/// ```
+///
+/// [difference_all]: crate::difference_all
+pub fn difference_all_hashed<A>(array: &[A], others: Vec<&[A]>) -> Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    difference_hashed_of(array, &others)
+}
+
+/// A trait that implements the [DifferenceAllHashed::difference_all_hashed] method on arrays.
+pub trait DifferenceAllHashed<A, I>
+where
+    A: IntoIterator,
+    I: Hash + Eq + Clone,
+{
+    /// Creates a vector of values in this array that are not included in the `others` nested
+    /// arrays, looking values up in a hash set of the values of `others`.
+    ///
+    /// * `others` - The nested values to exclude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DifferenceAllHashed;
+    ///
+    /// assert_eq!(
+    ///     [2, 1, 2, 3].difference_all_hashed(vec![&[3, 4], &[3, 2]]),
+    ///     [1]
+    /// );
+    /// ```
+    fn difference_all_hashed(&self, others: Vec<&[I]>) -> Vec<I>;
+}
+
+impl<A> DifferenceAllHashed<Vec<A>, A> for Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    fn difference_all_hashed(&self, others: Vec<&[A]>) -> Vec<A> {
+        difference_all_hashed(self, others)
+    }
+}
+
+impl<A, const N: usize> DifferenceAllHashed<[A; N], A> for [A; N]
+where
+    A: Hash + Eq + Clone,
+{
+    fn difference_all_hashed(&self, others: Vec<&[A]>) -> Vec<A> {
+        difference_all_hashed(self, others)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::difference_all;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_return_difference_of_multiple_arrays() {
+        let result = difference_all_hashed(&[2, 1, 2, 3], vec![&[3, 4], &[3, 2]]);
+        assert_eq!(result, [1]);
+
+        assert_eq!(
+            vec![2, 1, 2, 3].difference_all_hashed(vec![&[3, 4], &[3, 2]]),
+            [1]
+        );
+
+        assert_eq!([2, 1].difference_all_hashed(vec![]), [2, 1]);
+    }
+
+    quickcheck! {
+        fn matches_difference_all(array: Vec<u8>, others: Vec<Vec<u8>>) -> bool {
+            let others = others.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+            difference_all_hashed(&array, others.clone()) == difference_all(&array, others, None)
+        }
+    }
+}
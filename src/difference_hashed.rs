@@ -0,0 +1,138 @@
+use crate::difference::LINEAR_SEARCH_THRESHOLD;
+use std::{
+    collections::HashSet,
+    hash::Hash,
+};
+
+/// Creates a vector of `array` values not included in any of the `others` arrays, looking values
+/// up in a hash set of the values of `others`, or with a linear search for small inputs.
+pub(crate) fn difference_hashed_of<A>(array: &[A], others: &[&[A]]) -> Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    let others_len = others.iter().map(|other| other.len()).sum::<usize>();
+
+    if array.len().min(others_len) <= LINEAR_SEARCH_THRESHOLD {
+        return array
+            .iter()
+            .filter(|item| !others.iter().any(|other| other.contains(item)))
+            .cloned()
+            .collect();
+    }
+
+    let excluded = others
+        .iter()
+        .flat_map(|other| other.iter())
+        .collect::<HashSet<_>>();
+
+    array
+        .iter()
+        .filter(|item| !excluded.contains(item))
+        .cloned()
+        .collect()
+}
+
+/// Creates a vector of `array` values not included in the `other` array, like [difference]
+/// except that values are looked up in a hash set of the values of `other`. This takes
+/// `O(n + m)` time, compared to the `O((n + m) log m)` time of [difference] on an unsorted
+/// `other` array, and only requires the values to be hashable rather than ordered. A linear search
+/// is used instead when the arrays are so small that it is faster.
+///
+/// * `array` - The array to inspect.
+/// * `other` - The values to exclude.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::difference_hashed;
+///
+/// assert_eq!(difference_hashed(&[2, 1], &[2, 3]), [1]);
+/// assert_eq!(
+///     difference_hashed(&["fred", "barney", "pebbles"], &["barney"]),
+///     ["fred", "pebbles"]
+/// );
+/// ```
+///
+/// [difference]: crate::difference
+pub fn difference_hashed<A>(array: &[A], other: &[A]) -> Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    difference_hashed_of(array, &[other])
+}
+
+/// A trait that implements the [DifferenceHashed::difference_hashed] method on arrays.
+pub trait DifferenceHashed<A, I>
+where
+    A: IntoIterator,
+    I: Hash + Eq + Clone,
+{
+    /// Creates a vector of values in this array that are not included in the `other` array,
+    /// looking values up in a hash set of the values of `other`.
+    ///
+    /// * `other` - The values to exclude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DifferenceHashed;
+    ///
+    /// assert_eq!([2, 1].difference_hashed(&[2, 3]), [1]);
+    /// ```
+    fn difference_hashed(&self, other: &[I]) -> Vec<I>;
+}
+
+impl<A> DifferenceHashed<Vec<A>, A> for Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    fn difference_hashed(&self, other: &[A]) -> Vec<A> {
+        difference_hashed(self, other)
+    }
+}
+
+impl<A, const N: usize> DifferenceHashed<[A; N], A> for [A; N]
+where
+    A: Hash + Eq + Clone,
+{
+    fn difference_hashed(&self, other: &[A]) -> Vec<A> {
+        difference_hashed(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::difference;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_return_difference_of_two_arrays() {
+        assert_eq!(difference_hashed(&[2, 1], &[2, 3]), [1]);
+
+        assert_eq!(vec![2, 1].difference_hashed(&[2, 3]), [1]);
+
+        assert_eq!([2, 1].difference_hashed(&[2, 3]), [1]);
+    }
+
+    #[test]
+    fn can_return_difference_of_large_arrays() {
+        let array = (0..1000).map(|n| n.to_string()).collect::<Vec<_>>();
+        let other = (0..1000)
+            .filter(|n| n % 10 != 0)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        let expected = (0..1000)
+            .step_by(10)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(difference_hashed(&array, &other), expected);
+    }
+
+    quickcheck! {
+        fn matches_difference(array: Vec<u8>, other: Vec<u8>) -> bool {
+            difference_hashed(&array, &other) == difference(&array, &other, None)
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+
+/// Creates a vector of `array` values not included in the `other` array, like [difference]
+/// except that both arrays must be sorted in ascending order. The arrays are merged in `O(n + m)`
+/// time, without allocating anything but the result. The result is unspecified if either array
+/// is not sorted.
+///
+/// * `array` - The sorted array to inspect.
+/// * `other` - The sorted values to exclude.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::difference_sorted;
+///
+/// assert_eq!(difference_sorted(&[1, 2, 2, 3, 5], &[2, 4, 5]), [1, 3]);
+/// ```
+///
+/// [difference]: crate::difference
+pub fn difference_sorted<A>(array: &[A], other: &[A]) -> Vec<A>
+where
+    A: Ord + Clone,
+{
+    let mut result = Vec::new();
+    let mut other = other.iter().peekable();
+
+    for item in array {
+        while other.next_if(|excluded| *excluded < item).is_some() {}
+
+        if other.peek().map(|excluded| (*excluded).cmp(item)) != Some(Ordering::Equal) {
+            result.push(item.clone());
+        }
+    }
+
+    result
+}
+
+/// A trait that implements the [DifferenceSorted::difference_sorted] method on arrays.
+pub trait DifferenceSorted<A, I>
+where
+    A: IntoIterator,
+    I: Ord + Clone,
+{
+    /// Creates a vector of values in this sorted array that are not included in the sorted
+    /// `other` array, by merging the arrays.
+    ///
+    /// * `other` - The sorted values to exclude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DifferenceSorted;
+    ///
+    /// assert_eq!([1, 2, 3].difference_sorted(&[2, 3, 4]), [1]);
+    /// ```
+    fn difference_sorted(&self, other: &[I]) -> Vec<I>;
+}
+
+impl<A> DifferenceSorted<Vec<A>, A> for Vec<A>
+where
+    A: Ord + Clone,
+{
+    fn difference_sorted(&self, other: &[A]) -> Vec<A> {
+        difference_sorted(self, other)
+    }
+}
+
+impl<A, const N: usize> DifferenceSorted<[A; N], A> for [A; N]
+where
+    A: Ord + Clone,
+{
+    fn difference_sorted(&self, other: &[A]) -> Vec<A> {
+        difference_sorted(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::difference;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_return_difference_of_two_sorted_arrays() {
+        assert_eq!(difference_sorted(&[1, 2], &[2, 3]), [1]);
+
+        assert_eq!(vec![1, 2].difference_sorted(&[2, 3]), [1]);
+
+        assert_eq!([1, 2].difference_sorted(&[2, 3]), [1]);
+    }
+
+    #[test]
+    fn can_keep_duplicates() {
+        assert_eq!(difference_sorted(&[1, 1, 2, 2, 3], &[2]), [1, 1, 3]);
+        assert_eq!(difference_sorted(&[1, 1], &[1, 1, 1]), [0; 0]);
+        assert_eq!(difference_sorted(&[], &[1]), [0; 0]);
+        assert_eq!(difference_sorted(&[1], &[]), [1]);
+    }
+
+    quickcheck! {
+        fn matches_difference(array: Vec<u8>, other: Vec<u8>) -> bool {
+            let (mut array, mut other) = (array, other);
+            array.sort_unstable();
+            other.sort_unstable();
+
+            difference_sorted(&array, &other) == difference(&array, &other, None)
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to dot case. The [words] of `value` are lowercased and joined with ".".
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::dot_case;
+///
+/// assert_eq!(dot_case("Foo Bar"), "foo.bar".to_string());
+/// assert_eq!(dot_case("fooBar"), "foo.bar".to_string());
+/// assert_eq!(dot_case("__FOO_BAR__"), "foo.bar".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn dot_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Lower,
+        WordCase::Lower,
+        ".",
+        AcronymMode::Normalize,
+    )
+}
+
+/// A trait that implements the [DotCase::dot_case] method on strings.
+pub trait DotCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to dot case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::DotCase;
+    ///
+    /// assert_eq!("fooBar".dot_case(), "foo.bar".to_string());
+    /// ```
+    fn dot_case(&self) -> String;
+}
+
+impl DotCase<String> for String {
+    fn dot_case(&self) -> String {
+        dot_case(self)
+    }
+}
+
+impl DotCase<str> for str {
+    fn dot_case(&self) -> String {
+        dot_case(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_dot_case() {
+        assert_eq!(dot_case("fooBar"), "foo.bar".to_string());
+        assert_eq!("XMLHttpRequest".dot_case(), "xml.http.request".to_string());
+        assert_eq!(
+            "version2Update".to_string().dot_case(),
+            "version.2.update".to_string()
+        );
+        assert_eq!(dot_case("don't stop"), "dont.stop".to_string());
+        assert_eq!("  ".dot_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().dot_case(),
+            "élan.vital".to_string()
+        );
+    }
+}
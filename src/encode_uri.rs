@@ -0,0 +1,390 @@
+use crate::codec::{
+    self,
+    ByteSet,
+    Encoder,
+};
+use std::fmt;
+
+/// A set of characters that are percent-encoded by [encode_uri_with].
+///
+/// Characters outside the set are kept as is, while characters in the set are replaced with the
+/// `%HH` format of each of their UTF-8 bytes. Non-ASCII characters and the "%" character are
+/// always encoded.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     encode_uri_with,
+///     EncodeSet,
+/// };
+///
+/// // Keep the path separator of a path segment unencoded.
+/// let set = EncodeSet::UNRESERVED.preserving("/");
+/// assert_eq!(encode_uri_with("a b/c?", &set), "a%20b/c%3F".to_string());
+///
+/// // Encode the "'" character, which is kept by `EncodeSet::URI_COMPONENT`.
+/// let set = EncodeSet::URI_COMPONENT.encoding("'");
+/// assert_eq!(encode_uri_with("fred's", &set), "fred%27s".to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EncodeSet(ByteSet);
+
+impl EncodeSet {
+    /// Encodes every character other than the alphanumeric characters and "-", ".", "_" and "~",
+    /// which are the unreserved characters of [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-2.3).
+    pub const UNRESERVED: Self = Self(
+        ByteSet::non_alphanumeric_ascii()
+            .without(b"-._~")
+            .with_non_ascii(),
+    );
+
+    /// Encodes the same characters as [encode_uri_component]: every character other than the
+    /// alphanumeric characters and "-", "_", ".", "!", "~", "*", "'", "(" and ")".
+    pub const URI_COMPONENT: Self = Self::UNRESERVED.preserving("!*'()");
+
+    /// Encodes the same characters as [encode_uri], which also keeps the reserved characters ";",
+    /// ",", "/", "?", ":", "@", "&", "=", "+", "$" and "#" of [EncodeSet::URI_COMPONENT].
+    pub const URI: Self = Self::URI_COMPONENT.preserving(";,/?:@&=+$#");
+
+    /// Encodes the same characters as [encode_form_component]: every character other than the
+    /// alphanumeric characters and "*", "-", "." and "_".
+    pub const FORM: Self = Self::UNRESERVED.preserving("*").encoding("~");
+
+    /// Returns a copy of this set in which the ASCII characters of `chars` are encoded.
+    ///
+    /// * `chars` - The characters to encode.
+    pub const fn encoding(self, chars: &str) -> Self {
+        Self(self.0.with_ascii(chars.as_bytes()))
+    }
+
+    /// Returns a copy of this set in which the ASCII characters of `chars`, other than "%", are
+    /// kept as is.
+    ///
+    /// * `chars` - The characters to keep as is.
+    pub const fn preserving(self, chars: &str) -> Self {
+        Self(self.0.without(chars.as_bytes()).with(b"%").with_non_ascii())
+    }
+
+    /// Returns `true` if `c` is percent-encoded by this set.
+    ///
+    /// * `c` - The character to check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EncodeSet;
+    ///
+    /// assert!(EncodeSet::URI_COMPONENT.contains('/'));
+    /// assert!(!EncodeSet::URI.contains('/'));
+    /// ```
+    pub const fn contains(&self, c: char) -> bool {
+        !c.is_ascii() || self.0.contains(c as u8)
+    }
+}
+
+impl Encoder for EncodeSet {
+    fn special_bytes(&self) -> &ByteSet {
+        &self.0
+    }
+
+    fn encode<W>(&self, c: char, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        c.encode_utf8(&mut [0; 4])
+            .bytes()
+            .try_for_each(|byte| write!(out, "%{byte:02X}"))
+    }
+}
+
+/// The rules applied by [encode_form_component], which encode spaces as "+".
+struct FormEncoder;
+
+impl Encoder for FormEncoder {
+    fn special_bytes(&self) -> &ByteSet {
+        EncodeSet::FORM.special_bytes()
+    }
+
+    fn encode<W>(&self, c: char, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        match c {
+            ' ' => out.write_char('+'),
+            _ => EncodeSet::FORM.encode(c, out),
+        }
+    }
+}
+
+/// Percent-encodes `value` so that it can be used as a complete URI, like the JavaScript
+/// `encodeURI` function. The characters of [EncodeSet::URI] are encoded, which keeps the
+/// characters with a special meaning in URIs, such as "/", "?" and "#".
+///
+/// * `value` - The URI to encode.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::encode_uri;
+///
+/// assert_eq!(
+///     encode_uri("https://example.com/fred & barney?q=é#top"),
+///     "https://example.com/fred%20&%20barney?q=%C3%A9#top".to_string()
+/// );
+/// ```
+pub fn encode_uri<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    encode_uri_with(value, &EncodeSet::URI)
+}
+
+/// Percent-encodes `value` so that it can be used as a single URI component, such as a path
+/// segment or a query parameter value, like the JavaScript `encodeURIComponent` function. The
+/// characters of [EncodeSet::URI_COMPONENT] are encoded.
+///
+/// * `value` - The URI component to encode.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::encode_uri_component;
+///
+/// assert_eq!(
+///     encode_uri_component("fred & barney/é"),
+///     "fred%20%26%20barney%2F%C3%A9".to_string()
+/// );
+/// ```
+pub fn encode_uri_component<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    encode_uri_with(value, &EncodeSet::URI_COMPONENT)
+}
+
+/// This method is like [encode_uri_component] except that it accepts the set of characters to
+/// encode.
+///
+/// * `value` - The string to encode.
+/// * `set` - The set of characters to encode.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     encode_uri_with,
+///     EncodeSet,
+/// };
+///
+/// assert_eq!(
+///     encode_uri_with("fred's (barney)", &EncodeSet::UNRESERVED),
+///     "fred%27s%20%28barney%29".to_string()
+/// );
+/// ```
+pub fn encode_uri_with<S>(value: S, set: &EncodeSet) -> String
+where
+    S: AsRef<str>,
+{
+    codec::encode_cow(set, value.as_ref()).into_owned()
+}
+
+/// Encodes `value` as a name or value of an `application/x-www-form-urlencoded` form, as
+/// browsers do when submitting forms. Spaces are encoded as "+", and the characters of
+/// [EncodeSet::FORM] are percent-encoded.
+///
+/// * `value` - The string to encode.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::encode_form_component;
+///
+/// assert_eq!(
+///     encode_form_component("fred & barney~"),
+///     "fred+%26+barney%7E".to_string()
+/// );
+/// ```
+pub fn encode_form_component<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    codec::encode_cow(&FormEncoder, value.as_ref()).into_owned()
+}
+
+/// A trait that implements the [EncodeUri::encode_uri] method on strings.
+pub trait EncodeUri<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Percent-encodes this string so that it can be used as a complete URI, like the JavaScript
+    /// `encodeURI` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EncodeUri;
+    ///
+    /// assert_eq!(
+    ///     "https://example.com/fred & barney".encode_uri(),
+    ///     "https://example.com/fred%20&%20barney".to_string()
+    /// );
+    /// ```
+    fn encode_uri(&self) -> String;
+
+    /// Percent-encodes this string so that it can be used as a single URI component, like the
+    /// JavaScript `encodeURIComponent` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EncodeUri;
+    ///
+    /// assert_eq!(
+    ///     "fred & barney/é".encode_uri_component(),
+    ///     "fred%20%26%20barney%2F%C3%A9".to_string()
+    /// );
+    /// ```
+    fn encode_uri_component(&self) -> String;
+
+    /// This method is like [EncodeUri::encode_uri_component] except that it accepts the set of
+    /// characters to encode.
+    ///
+    /// * `set` - The set of characters to encode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     EncodeSet,
+    ///     EncodeUri,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "fred's".encode_uri_with(&EncodeSet::UNRESERVED),
+    ///     "fred%27s".to_string()
+    /// );
+    /// ```
+    fn encode_uri_with(&self, set: &EncodeSet) -> String;
+
+    /// Encodes this string as a name or value of an `application/x-www-form-urlencoded` form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EncodeUri;
+    ///
+    /// assert_eq!(
+    ///     "fred & barney".encode_form_component(),
+    ///     "fred+%26+barney".to_string()
+    /// );
+    /// ```
+    fn encode_form_component(&self) -> String;
+}
+
+impl EncodeUri<String> for String {
+    fn encode_uri(&self) -> String {
+        encode_uri(self)
+    }
+
+    fn encode_uri_component(&self) -> String {
+        encode_uri_component(self)
+    }
+
+    fn encode_uri_with(&self, set: &EncodeSet) -> String {
+        encode_uri_with(self, set)
+    }
+
+    fn encode_form_component(&self) -> String {
+        encode_form_component(self)
+    }
+}
+
+impl EncodeUri<str> for str {
+    fn encode_uri(&self) -> String {
+        encode_uri(self)
+    }
+
+    fn encode_uri_component(&self) -> String {
+        encode_uri_component(self)
+    }
+
+    fn encode_uri_with(&self, set: &EncodeSet) -> String {
+        encode_uri_with(self, set)
+    }
+
+    fn encode_form_component(&self) -> String {
+        encode_form_component(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The ASCII characters that are not alphanumeric.
+    const PUNCTUATION: &str = r##" !"#$%&'()*+,-./:;<=>?@[\]^_`{|}~"##;
+
+    #[test]
+    fn can_encode_uris() {
+        assert_eq!(
+            encode_uri(PUNCTUATION),
+            r##"%20!%22#$%25&'()*+,-./:;%3C=%3E?@%5B%5C%5D%5E_%60%7B%7C%7D~"##.to_string()
+        );
+        assert_eq!(
+            encode_uri("é€😀"),
+            "%C3%A9%E2%82%AC%F0%9F%98%80".to_string()
+        );
+        assert_eq!("a b".encode_uri(), "a%20b".to_string());
+    }
+
+    #[test]
+    fn can_encode_uri_components() {
+        assert_eq!(
+            encode_uri_component(PUNCTUATION),
+            r##"%20!%22%23%24%25%26'()*%2B%2C-.%2F%3A%3B%3C%3D%3E%3F%40%5B%5C%5D%5E_%60%7B%7C%7D~"##
+                .to_string()
+        );
+        assert_eq!(
+            "a/b".to_string().encode_uri_component(),
+            "a%2Fb".to_string()
+        );
+    }
+
+    #[test]
+    fn can_encode_with_custom_sets() {
+        assert_eq!(
+            encode_uri_with(PUNCTUATION, &EncodeSet::UNRESERVED),
+            r##"%20%21%22%23%24%25%26%27%28%29%2A%2B%2C-.%2F%3A%3B%3C%3D%3E%3F%40%5B%5C%5D%5E_%60%7B%7C%7D~"##
+                .to_string()
+        );
+        assert_eq!(
+            encode_uri_with("a%b/c", &EncodeSet::UNRESERVED.preserving("%/")),
+            "a%25b/c".to_string()
+        );
+        assert_eq!(
+            "a.b".encode_uri_with(&EncodeSet::UNRESERVED.encoding(".")),
+            "a%2Eb".to_string()
+        );
+        assert!(EncodeSet::URI.contains('é'));
+        assert!(EncodeSet::URI.contains('%'));
+        assert!(!EncodeSet::URI.contains('a'));
+    }
+
+    #[test]
+    fn can_encode_form_components() {
+        assert_eq!(
+            encode_form_component(PUNCTUATION),
+            r##"+%21%22%23%24%25%26%27%28%29*%2B%2C-.%2F%3A%3B%3C%3D%3E%3F%40%5B%5C%5D%5E_%60%7B%7C%7D%7E"##
+                .to_string()
+        );
+        assert_eq!("é ".encode_form_component(), "%C3%A9+".to_string());
+    }
+
+    #[test]
+    fn can_handle_strings_with_nothing_to_encode() {
+        assert_eq!(encode_uri("abc"), "abc".to_string());
+        assert_eq!(encode_uri_component("abc"), "abc".to_string());
+        assert_eq!(encode_form_component("abc"), "abc".to_string());
+    }
+}
@@ -1,12 +1,148 @@
-use once_cell::sync::Lazy;
-use regex::{
-    Captures,
-    Regex,
+use crate::codec::{
+    self,
+    ByteSet,
+    Encoder,
 };
+use memchr::{
+    memchr2,
+    memchr3,
+};
+use std::{
+    borrow::Cow,
+    fmt,
+    io,
+};
+
+/// The bytes of the characters escaped in HTML text and quoted attributes.
+static HTML_SPECIAL_BYTES: ByteSet = ByteSet::new(b"&<>\"'");
+
+/// The bytes that may start a non-alphanumeric character below U+0100, which are escaped in
+/// unquoted attributes and in CSS.
+static LATIN1_SPECIAL_BYTES: ByteSet = ByteSet::non_alphanumeric_ascii().with(&[0xc2, 0xc3]);
+
+/// The bytes that may start a non-alphanumeric character below U+0100 or one of the line
+/// terminators U+2028 and U+2029, which are escaped in JavaScript.
+static SCRIPT_SPECIAL_BYTES: ByteSet = ByteSet::non_alphanumeric_ascii().with(&[0xc2, 0xc3, 0xe2]);
+
+/// The bytes that may start a character outside the unreserved set of RFC 3986.
+static URL_SPECIAL_BYTES: ByteSet = ByteSet::non_alphanumeric_ascii()
+    .without(b"-_.~")
+    .with_non_ascii();
+
+/// The output context of an escaped value, which determines the escaping rules applied by
+/// [escape_for]. The rules follow the [OWASP Cross Site Scripting Prevention Cheat Sheet].
+///
+/// [OWASP Cross Site Scripting Prevention Cheat Sheet]: https://cheatsheetseries.owasp.org/cheatsheets/Cross_Site_Scripting_Prevention_Cheat_Sheet.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscapeContext {
+    /// HTML text content, such as `<p>{value}</p>`. The characters "&", "<", ">", '"' and "'" are
+    /// converted to their HTML entities, exactly like [escape].
+    #[default]
+    Text,
+    /// A quoted HTML attribute value, such as `<div title="{value}">`. The same characters as in
+    /// [EscapeContext::Text] are converted to their HTML entities.
+    Attribute,
+    /// An unquoted HTML attribute value, such as `<div title={value}>`. Every non-alphanumeric
+    /// character below U+0100 is converted to the `&#xHH;` format.
+    UnquotedAttribute,
+    /// A quoted JavaScript string inside a `<script>` block or an event handler, such as
+    /// `<script>const name = "{value}";</script>`. Every non-alphanumeric character below U+0100
+    /// is converted to the `\xHH` format, and the line terminators U+2028 and U+2029 are converted
+    /// to the `\uHHHH` format.
+    Script,
+    /// A quoted CSS property value, such as `<div style="color: '{value}'">`. Every
+    /// non-alphanumeric character below U+0100 is converted to the six-digit `\HHHHHH` format,
+    /// which needs no trailing space.
+    Style,
+    /// A URL component, such as a query parameter value in `<a href="/?q={value}">`. Every
+    /// character outside the unreserved set of RFC 3986 is percent-encoded as UTF-8.
+    Url,
+}
+
+impl Encoder for EscapeContext {
+    fn special_bytes(&self) -> &ByteSet {
+        match self {
+            Self::Text | Self::Attribute => &HTML_SPECIAL_BYTES,
+            Self::UnquotedAttribute | Self::Style => &LATIN1_SPECIAL_BYTES,
+            Self::Script => &SCRIPT_SPECIAL_BYTES,
+            Self::Url => &URL_SPECIAL_BYTES,
+        }
+    }
 
-/// Used to match HTML entities and HTML characters.
-static UNESCAPED_HTML: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r#"[&<>"']"#).expect("unable to build the pattern"));
+    fn find_special(&self, bytes: &[u8]) -> Option<usize> {
+        match self {
+            Self::Text | Self::Attribute => {
+                // Special characters are often close together, so look up the next few bytes
+                // before falling back to vectorized searches.
+                let head = bytes.len().min(16);
+
+                if let Some(index) = bytes[..head]
+                    .iter()
+                    .position(|byte| HTML_SPECIAL_BYTES.contains(*byte))
+                {
+                    return Some(index);
+                }
+
+                // Searching all of the remaining bytes would rescan them for every special
+                // character, so windows that double in size bound each call to twice the
+                // distance to the next special character.
+                let mut start = head;
+                let mut window = 64;
+
+                while start < bytes.len() {
+                    let end = bytes.len().min(start + window);
+                    let chunk = &bytes[start..end];
+                    let tag_or_ampersand = memchr3(b'&', b'<', b'>', chunk);
+                    let quote = memchr2(
+                        b'"',
+                        b'\'',
+                        &chunk[..tag_or_ampersand.unwrap_or(chunk.len())],
+                    );
+
+                    if let Some(index) = quote.or(tag_or_ampersand) {
+                        return Some(start + index);
+                    }
+
+                    start = end;
+                    window *= 2;
+                }
+
+                None
+            }
+            _ => {
+                let set = self.special_bytes();
+
+                bytes.iter().position(|byte| set.contains(*byte))
+            }
+        }
+    }
+
+    fn encode<W>(&self, c: char, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        let code_point = c as u32;
+
+        match (self, c) {
+            (Self::Text | Self::Attribute, '&') => out.write_str("&amp;"),
+            (Self::Text | Self::Attribute, '<') => out.write_str("&lt;"),
+            (Self::Text | Self::Attribute, '>') => out.write_str("&gt;"),
+            (Self::Text | Self::Attribute, '"') => out.write_str("&quot;"),
+            (Self::Text | Self::Attribute, '\'') => out.write_str("&#39;"),
+            (Self::UnquotedAttribute, _) if code_point < 0x100 => {
+                write!(out, "&#x{code_point:02X};")
+            }
+            (Self::Script, '\u{2028}' | '\u{2029}') => write!(out, "\\u{code_point:04X}"),
+            (Self::Script, _) if code_point < 0x100 => write!(out, "\\x{code_point:02X}"),
+            (Self::Style, _) if code_point < 0x100 => write!(out, "\\{code_point:06X}"),
+            (Self::Url, _) => c
+                .encode_utf8(&mut [0; 4])
+                .bytes()
+                .try_for_each(|byte| write!(out, "%{byte:02X}")),
+            _ => out.write_char(c),
+        }
+    }
+}
 
 /// Converts the characters "&", "<", ">", '"', and "'" in `value` to their corresponding HTML
 /// entities.
@@ -39,27 +175,136 @@ pub fn escape<S>(value: S) -> String
 where
     S: AsRef<str>,
 {
-    let value = value.as_ref();
-
-    if UNESCAPED_HTML.is_match(value) {
-        UNESCAPED_HTML
-            .replace_all(value, |cap: &Captures| {
-                let item = &cap[0];
-
-                match item {
-                    "&" => "&amp;",
-                    "<" => "&lt;",
-                    ">" => "&gt;",
-                    r#"""# => "&quot;",
-                    "'" => "&#39;",
-                    _ => item,
-                }
-                .to_owned()
-            })
-            .to_string()
-    } else {
-        value.to_string()
-    }
+    escape_cow(value.as_ref(), EscapeContext::Text).into_owned()
+}
+
+/// This method is like [escape] except that it applies the escaping rules of the `context` in
+/// which the value is rendered. See [EscapeContext] for the rules of each context.
+///
+/// # Note
+///
+/// Escaping cannot make every context safe. Untrusted values should never be rendered inside
+/// script or style code outside of a quoted string, and URLs should additionally be checked for
+/// dangerous schemes such as `javascript:`.
+///
+/// * `value` - The string to escape.
+/// * `context` - The context in which the value is rendered.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     escape_for,
+///     EscapeContext,
+/// };
+///
+/// assert_eq!(
+///     escape_for("fred & barney", EscapeContext::Attribute),
+///     "fred &amp; barney".to_string()
+/// );
+/// assert_eq!(
+///     escape_for("fred & barney", EscapeContext::UnquotedAttribute),
+///     "fred&#x20;&#x26;&#x20;barney".to_string()
+/// );
+/// assert_eq!(
+///     escape_for("</script>", EscapeContext::Script),
+///     "\\x3C\\x2Fscript\\x3E".to_string()
+/// );
+/// assert_eq!(
+///     escape_for("red;", EscapeContext::Style),
+///     "red\\00003B".to_string()
+/// );
+/// assert_eq!(
+///     escape_for("fred & barney", EscapeContext::Url),
+///     "fred%20%26%20barney".to_string()
+/// );
+/// ```
+pub fn escape_for<S>(value: S, context: EscapeContext) -> String
+where
+    S: AsRef<str>,
+{
+    escape_cow(value.as_ref(), context).into_owned()
+}
+
+/// This method is like [escape_for] except that it returns `value` as is, without allocating,
+/// when it has nothing to escape.
+///
+/// * `value` - The string to escape.
+/// * `context` - The context in which the value is rendered.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     escape_cow,
+///     EscapeContext,
+/// };
+/// use std::borrow::Cow;
+///
+/// assert_eq!(
+///     escape_cow("fred & barney", EscapeContext::Text),
+///     "fred &amp; barney"
+/// );
+/// assert!(matches!(
+///     escape_cow("fred, barney", EscapeContext::Text),
+///     Cow::Borrowed(_)
+/// ));
+/// ```
+pub fn escape_cow(value: &str, context: EscapeContext) -> Cow<'_, str> {
+    codec::encode_cow(&context, value)
+}
+
+/// This method is like [escape_for] except that it writes the escaped value into `out`, without
+/// allocating.
+///
+/// * `value` - The string to escape.
+/// * `context` - The context in which the value is rendered.
+/// * `out` - The writer to write the escaped value into.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     escape_to,
+///     EscapeContext,
+/// };
+///
+/// let mut html = String::from("<p>");
+/// escape_to("fred & barney", EscapeContext::Text, &mut html).unwrap();
+/// html.push_str("</p>");
+/// assert_eq!(html, "<p>fred &amp; barney</p>");
+/// ```
+pub fn escape_to<W>(value: &str, context: EscapeContext, out: &mut W) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    codec::encode_to(&context, value, out)
+}
+
+/// This method is like [escape_for] except that it writes the escaped value into the I/O writer
+/// `out`, without allocating.
+///
+/// * `value` - The string to escape.
+/// * `context` - The context in which the value is rendered.
+/// * `out` - The writer to write the escaped value into.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     escape_to_io,
+///     EscapeContext,
+/// };
+///
+/// let mut html = Vec::new();
+/// escape_to_io("fred & barney", EscapeContext::Text, &mut html).unwrap();
+/// assert_eq!(html, b"fred &amp; barney");
+/// ```
+pub fn escape_to_io<W>(value: &str, context: EscapeContext, out: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    codec::write_io(out, |out| codec::encode_to(&context, value, out))
 }
 
 /// A trait that implements the [Escape::escape] method on strings.
@@ -93,18 +338,75 @@ where
     /// );
     /// ```
     fn escape(&self) -> String;
+
+    /// This method is like [Escape::escape] except that it applies the escaping rules of the
+    /// `context` in which this string is rendered. See [EscapeContext] for the rules of each
+    /// context.
+    ///
+    /// * `context` - The context in which this string is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     Escape,
+    ///     EscapeContext,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "fred & barney".escape_for(EscapeContext::Url),
+    ///     "fred%20%26%20barney".to_string()
+    /// );
+    /// ```
+    fn escape_for(&self, context: EscapeContext) -> String;
+
+    /// This method is like [Escape::escape_for] except that it returns this string as is, without
+    /// allocating, when it has nothing to escape.
+    ///
+    /// * `context` - The context in which this string is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     Escape,
+    ///     EscapeContext,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "fred & barney".escape_cow(EscapeContext::Text),
+    ///     "fred &amp; barney"
+    /// );
+    /// ```
+    fn escape_cow(&self, context: EscapeContext) -> Cow<'_, str>;
 }
 
 impl Escape<String> for String {
     fn escape(&self) -> String {
         escape(self)
     }
+
+    fn escape_for(&self, context: EscapeContext) -> String {
+        escape_for(self, context)
+    }
+
+    fn escape_cow(&self, context: EscapeContext) -> Cow<'_, str> {
+        escape_cow(self, context)
+    }
 }
 
 impl Escape<str> for str {
     fn escape(&self) -> String {
         escape(self)
     }
+
+    fn escape_for(&self, context: EscapeContext) -> String {
+        escape_for(self, context)
+    }
+
+    fn escape_cow(&self, context: EscapeContext) -> Cow<'_, str> {
+        escape_cow(self, context)
+    }
 }
 
 #[cfg(test)]
@@ -149,4 +451,135 @@ mod tests {
         assert_eq!(escape("abc"), "abc".to_string());
         assert_eq!("abc".escape(), "abc".to_string());
     }
+
+    #[test]
+    fn can_escape_values_for_text_and_attributes() {
+        for context in [EscapeContext::Text, EscapeContext::Attribute] {
+            assert_eq!(
+                escape_for(r#"&<>"'/`"#, context),
+                "&amp;&lt;&gt;&quot;&#39;/`".to_string()
+            );
+            assert_eq!(
+                r#"&<>"'/`"#.escape_for(context),
+                "&amp;&lt;&gt;&quot;&#39;/`".to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn can_find_characters_across_search_windows() {
+        for gap in [0, 1, 15, 16, 17, 63, 64, 65, 200, 1000] {
+            for special in ['&', '<', '>', '"', '\''] {
+                let filler = "a".repeat(gap);
+                let value = format!("{filler}{special}{filler}{special}{filler}");
+                let expected = value
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;")
+                    .replace('"', "&quot;")
+                    .replace('\'', "&#39;");
+
+                assert_eq!(escape(&value), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn can_escape_values_for_unquoted_attributes() {
+        assert_eq!(
+            escape_for("a b=`c`\u{a0}é€", EscapeContext::UnquotedAttribute),
+            "a&#x20;b&#x3D;&#x60;c&#x60;&#xA0;&#xE9;€".to_string()
+        );
+        assert_eq!(
+            "a b".escape_for(EscapeContext::UnquotedAttribute),
+            "a&#x20;b".to_string()
+        );
+    }
+
+    #[test]
+    fn can_escape_values_for_scripts() {
+        assert_eq!(
+            escape_for("'</script>\u{2028}\u{2029}é€", EscapeContext::Script),
+            "\\x27\\x3C\\x2Fscript\\x3E\\u2028\\u2029\\xE9€".to_string()
+        );
+        assert_eq!(
+            "a\\b".escape_for(EscapeContext::Script),
+            "a\\x5Cb".to_string()
+        );
+    }
+
+    #[test]
+    fn can_escape_values_for_styles() {
+        assert_eq!(
+            escape_for("red;} body{", EscapeContext::Style),
+            "red\\00003B\\00007D\\000020body\\00007B".to_string()
+        );
+        assert_eq!(
+            "#fff".escape_for(EscapeContext::Style),
+            "\\000023fff".to_string()
+        );
+    }
+
+    #[test]
+    fn can_escape_values_for_urls() {
+        assert_eq!(
+            escape_for("a-b_c.d~e f/g?h=é€", EscapeContext::Url),
+            "a-b_c.d~e%20f%2Fg%3Fh%3D%C3%A9%E2%82%AC".to_string()
+        );
+        assert_eq!("a&b".escape_for(EscapeContext::Url), "a%26b".to_string());
+    }
+
+    #[test]
+    fn can_handle_strings_with_nothing_to_escape_for_any_context() {
+        for context in [
+            EscapeContext::Text,
+            EscapeContext::Attribute,
+            EscapeContext::UnquotedAttribute,
+            EscapeContext::Script,
+            EscapeContext::Style,
+            EscapeContext::Url,
+        ] {
+            assert_eq!(escape_for("abc123", context), "abc123".to_string());
+            assert_eq!("abc123".escape_for(context), "abc123".to_string());
+        }
+    }
+
+    #[test]
+    fn can_borrow_strings_with_nothing_to_escape() {
+        assert!(matches!(
+            escape_cow("abc", EscapeContext::Text),
+            Cow::Borrowed("abc")
+        ));
+        assert!(matches!(
+            escape_cow("abc-123", EscapeContext::Url),
+            Cow::Borrowed("abc-123")
+        ));
+        assert!(matches!(
+            "abc".to_string().escape_cow(EscapeContext::Style),
+            Cow::Borrowed("abc")
+        ));
+        assert!(matches!(
+            escape_cow("a&b", EscapeContext::Text),
+            Cow::Owned(_)
+        ));
+    }
+
+    #[test]
+    fn can_escape_values_into_writers() {
+        let mut out = String::new();
+        escape_to(r#"&<>"'/"#, EscapeContext::Text, &mut out).unwrap();
+        escape_to("é ", EscapeContext::Url, &mut out).unwrap();
+        assert_eq!(out, "&amp;&lt;&gt;&quot;&#39;/%C3%A9%20");
+
+        let mut out = Vec::new();
+        escape_to_io("<p>€</p>", EscapeContext::Text, &mut out).unwrap();
+        assert_eq!(out, "&lt;p&gt;€&lt;/p&gt;".as_bytes());
+    }
+
+    #[test]
+    fn can_surface_io_errors() {
+        let mut out = [0_u8; 4];
+        let error = escape_to_io("<p>", EscapeContext::Text, &mut out.as_mut_slice()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+    }
 }
@@ -0,0 +1,250 @@
+use crate::codec::ByteSet;
+use std::borrow::Cow;
+
+/// The bytes that make a field need quotes.
+static CSV_SPECIAL_BYTES: ByteSet = ByteSet::new(b",\"\r\n");
+
+/// The characters that make a spreadsheet read a field as a formula, which are neutralized by
+/// [escape_csv_field_with] in formula-safe mode.
+const FORMULA_TRIGGERS: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// Returns `true` if `value` starts with a formula trigger, after any number of single quotes.
+/// Fields with leading single quotes are neutralized too, so that unquoting a neutralized field,
+/// which removes one single quote, is never ambiguous.
+pub(crate) fn is_formula(value: &str) -> bool {
+    value.trim_start_matches('\'').starts_with(FORMULA_TRIGGERS)
+}
+
+/// Quotes `value` for use as a field of a comma-separated file, as defined by [RFC 4180]. Fields
+/// that contain a comma, a double quote or a line break are enclosed in double quotes, in which
+/// double quotes are doubled. Other fields are returned as they are.
+///
+/// * `value` - The field to quote.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_csv_field;
+///
+/// assert_eq!(escape_csv_field("fred"), "fred".to_string());
+/// assert_eq!(
+///     escape_csv_field("fred, \"barney\""),
+///     r#""fred, ""barney""""#.to_string()
+/// );
+/// ```
+///
+/// [RFC 4180]: https://www.rfc-editor.org/rfc/rfc4180
+pub fn escape_csv_field<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    escape_csv_field_cow(value.as_ref(), false).into_owned()
+}
+
+/// This method is like [escape_csv_field] except that, if `formula_safe` is `true`, fields that
+/// start with "=", "+", "-", "@", a tab or a carriage return, possibly after single quotes, are
+/// prefixed with a single quote and enclosed in double quotes, so that spreadsheets display them
+/// as text instead of evaluating them as formulas. See the [OWASP article on CSV injection].
+///
+/// * `value` - The field to quote.
+/// * `formula_safe` - Whether fields that would be read as formulas are neutralized.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_csv_field_with;
+///
+/// assert_eq!(
+///     escape_csv_field_with("=HYPERLINK(\"https://example.com\")", true),
+///     r#""'=HYPERLINK(""https://example.com"")""#.to_string()
+/// );
+/// assert_eq!(escape_csv_field_with("-1", false), "-1".to_string());
+/// ```
+///
+/// [OWASP article on CSV injection]: https://owasp.org/www-community/attacks/CSV_Injection
+pub fn escape_csv_field_with<S>(value: S, formula_safe: bool) -> String
+where
+    S: AsRef<str>,
+{
+    escape_csv_field_cow(value.as_ref(), formula_safe).into_owned()
+}
+
+/// This method is like [escape_csv_field_with] except that it returns `value` as is, without
+/// allocating, when it does not need quotes.
+///
+/// * `value` - The field to quote.
+/// * `formula_safe` - Whether fields that would be read as formulas are neutralized.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_csv_field_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(escape_csv_field_cow("a\nb", false), "\"a\nb\"");
+/// assert!(matches!(
+///     escape_csv_field_cow("fred barney", true),
+///     Cow::Borrowed(_)
+/// ));
+/// ```
+pub fn escape_csv_field_cow(value: &str, formula_safe: bool) -> Cow<'_, str> {
+    let is_formula = formula_safe && is_formula(value);
+
+    if !is_formula && !value.bytes().any(|byte| CSV_SPECIAL_BYTES.contains(byte)) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 4);
+    quoted.push('"');
+
+    if is_formula {
+        quoted.push('\'');
+    }
+
+    for (index, part) in value.split('"').enumerate() {
+        if index > 0 {
+            quoted.push_str("\"\"");
+        }
+
+        quoted.push_str(part);
+    }
+
+    quoted.push('"');
+
+    Cow::Owned(quoted)
+}
+
+/// A trait that implements the [EscapeCsvField::escape_csv_field] method on strings.
+pub trait EscapeCsvField<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Quotes this string for use as a field of a comma-separated file, as defined by RFC 4180.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeCsvField;
+    ///
+    /// assert_eq!(
+    ///     "fred, \"barney\"".escape_csv_field(),
+    ///     r#""fred, ""barney""""#.to_string()
+    /// );
+    /// ```
+    fn escape_csv_field(&self) -> String;
+
+    /// This method is like [EscapeCsvField::escape_csv_field] except that, if `formula_safe` is
+    /// `true`, fields that spreadsheets would read as formulas are neutralized.
+    ///
+    /// * `formula_safe` - Whether fields that would be read as formulas are neutralized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeCsvField;
+    ///
+    /// assert_eq!(
+    ///     "@SUM(A1:A9)".escape_csv_field_with(true),
+    ///     r#""'@SUM(A1:A9)""#.to_string()
+    /// );
+    /// ```
+    fn escape_csv_field_with(&self, formula_safe: bool) -> String;
+
+    /// This method is like [EscapeCsvField::escape_csv_field_with] except that it returns this
+    /// string as is, without allocating, when it does not need quotes.
+    ///
+    /// * `formula_safe` - Whether fields that would be read as formulas are neutralized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeCsvField;
+    ///
+    /// assert_eq!("a,b".escape_csv_field_cow(false), "\"a,b\"");
+    /// ```
+    fn escape_csv_field_cow(&self, formula_safe: bool) -> Cow<'_, str>;
+}
+
+impl EscapeCsvField<String> for String {
+    fn escape_csv_field(&self) -> String {
+        escape_csv_field(self)
+    }
+
+    fn escape_csv_field_with(&self, formula_safe: bool) -> String {
+        escape_csv_field_with(self, formula_safe)
+    }
+
+    fn escape_csv_field_cow(&self, formula_safe: bool) -> Cow<'_, str> {
+        escape_csv_field_cow(self, formula_safe)
+    }
+}
+
+impl EscapeCsvField<str> for str {
+    fn escape_csv_field(&self) -> String {
+        escape_csv_field(self)
+    }
+
+    fn escape_csv_field_with(&self, formula_safe: bool) -> String {
+        escape_csv_field_with(self, formula_safe)
+    }
+
+    fn escape_csv_field_cow(&self, formula_safe: bool) -> Cow<'_, str> {
+        escape_csv_field_cow(self, formula_safe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_escape_fields() {
+        assert_eq!(escape_csv_field(""), "".to_string());
+        assert_eq!(escape_csv_field(" fred é "), " fred é ".to_string());
+        assert_eq!(escape_csv_field("a,b"), "\"a,b\"".to_string());
+        assert_eq!(escape_csv_field("a\r\nb"), "\"a\r\nb\"".to_string());
+        assert_eq!("\"".escape_csv_field(), r#""""""#.to_string());
+        assert_eq!(
+            "\"a\"\"b\"".to_string().escape_csv_field(),
+            r#""""a""""b""""#.to_string()
+        );
+    }
+
+    #[test]
+    fn can_escape_formulas() {
+        for trigger in FORMULA_TRIGGERS {
+            assert_eq!(
+                escape_csv_field_with(format!("{trigger}1"), true),
+                format!("\"'{trigger}1\"")
+            );
+            assert_eq!(
+                escape_csv_field_with(format!("a{trigger}1"), true),
+                escape_csv_field(format!("a{trigger}1"))
+            );
+        }
+
+        assert_eq!(
+            "=1,\"2\"".escape_csv_field_with(true),
+            r#""'=1,""2""""#.to_string()
+        );
+        assert_eq!("=1".escape_csv_field_with(false), "=1".to_string());
+        assert_eq!(
+            escape_csv_field_with("''-1", true),
+            r#""'''-1""#.to_string()
+        );
+        assert_eq!(escape_csv_field_with("'a", true), "'a".to_string());
+    }
+
+    #[test]
+    fn can_borrow_fields_without_quotes() {
+        assert!(matches!(
+            escape_csv_field_cow("fred 'barney'", true),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!("=1".escape_csv_field_cow(true), Cow::Owned(_)));
+        assert!(matches!(
+            "=1".to_string().escape_csv_field_cow(false),
+            Cow::Borrowed(_)
+        ));
+    }
+}
@@ -0,0 +1,441 @@
+use crate::codec::{
+    self,
+    ByteSet,
+    Encoder,
+};
+use std::{
+    borrow::Cow,
+    fmt,
+};
+
+/// The C0 control characters, which are escaped in every string literal.
+const CONTROL_BYTES: &[u8; 32] =
+    b"\x00\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\x0e\x0f\
+    \x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\x1c\x1d\x1e\x1f";
+
+/// The bytes that may start a character escaped in JavaScript string literals, including the
+/// line terminators U+2028 and U+2029.
+static JS_SPECIAL_BYTES: ByteSet = ByteSet::new(b"\\\"'<>").with(CONTROL_BYTES).with(&[0xe2]);
+
+/// The bytes that may start a character escaped in JavaScript string literals that only contain
+/// ASCII characters.
+static JS_ASCII_SPECIAL_BYTES: ByteSet = JS_SPECIAL_BYTES.with_non_ascii();
+
+/// The bytes that may start a character escaped in JSON strings, including the line terminators
+/// U+2028 and U+2029.
+static JSON_SPECIAL_BYTES: ByteSet = ByteSet::new(b"\\\"<>&").with(CONTROL_BYTES).with(&[0xe2]);
+
+/// The bytes that may start a character escaped in JSON strings that only contain ASCII
+/// characters.
+static JSON_ASCII_SPECIAL_BYTES: ByteSet = JSON_SPECIAL_BYTES.with_non_ascii();
+
+/// The rules applied by [escape_js_string_with] and [escape_json_string_with].
+struct StringEncoder {
+    is_json: bool,
+    is_ascii_only: bool,
+}
+
+impl Encoder for StringEncoder {
+    fn special_bytes(&self) -> &ByteSet {
+        match (self.is_json, self.is_ascii_only) {
+            (false, false) => &JS_SPECIAL_BYTES,
+            (false, true) => &JS_ASCII_SPECIAL_BYTES,
+            (true, false) => &JSON_SPECIAL_BYTES,
+            (true, true) => &JSON_ASCII_SPECIAL_BYTES,
+        }
+    }
+
+    fn encode<W>(&self, c: char, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        match c {
+            '\\' => out.write_str("\\\\"),
+            '"' => out.write_str("\\\""),
+            '\'' if !self.is_json => out.write_str("\\'"),
+            '\u{8}' => out.write_str("\\b"),
+            '\u{c}' => out.write_str("\\f"),
+            '\n' => out.write_str("\\n"),
+            '\r' => out.write_str("\\r"),
+            '\t' => out.write_str("\\t"),
+            '\u{0}'..='\u{1f}' | '<' | '>' if !self.is_json => write!(out, "\\x{:02X}", c as u32),
+            '\u{0}'..='\u{1f}' | '<' | '>' | '&' | '\u{2028}' | '\u{2029}' => {
+                write!(out, "\\u{:04X}", c as u32)
+            }
+            c if self.is_ascii_only && !c.is_ascii() => {
+                let mut units = [0; 2];
+
+                for unit in c.encode_utf16(&mut units) {
+                    write!(out, "\\u{unit:04X}")?;
+                }
+
+                Ok(())
+            }
+            c => out.write_char(c),
+        }
+    }
+}
+
+/// Escapes `value` for use inside a single- or double-quoted JavaScript string literal. Quotes,
+/// backslashes and control characters are escaped, and so are "<", ">" and the line terminators
+/// U+2028 and U+2029, which makes the literal safe to embed in an HTML `<script>` block.
+///
+/// * `value` - The string to escape.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_js_string;
+///
+/// assert_eq!(
+///     escape_js_string("fred's \"barney\"\n</script>"),
+///     r#"fred\'s \"barney\"\n\x3C/script\x3E"#.to_string()
+/// );
+/// ```
+pub fn escape_js_string<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    escape_js_string_cow(value.as_ref(), false).into_owned()
+}
+
+/// This method is like [escape_js_string] except that, if `ascii_only` is `true`, characters
+/// outside ASCII are also escaped as UTF-16 code units, such as "\uD83D\uDE00" for "😀".
+///
+/// * `value` - The string to escape.
+/// * `ascii_only` - Whether characters outside ASCII are escaped.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_js_string_with;
+///
+/// assert_eq!(
+///     escape_js_string_with("café 😀", true),
+///     r"caf\u00E9 \uD83D\uDE00".to_string()
+/// );
+/// ```
+pub fn escape_js_string_with<S>(value: S, ascii_only: bool) -> String
+where
+    S: AsRef<str>,
+{
+    escape_js_string_cow(value.as_ref(), ascii_only).into_owned()
+}
+
+/// This method is like [escape_js_string_with] except that it returns `value` as is, without
+/// allocating, when it has nothing to escape.
+///
+/// * `value` - The string to escape.
+/// * `ascii_only` - Whether characters outside ASCII are escaped.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_js_string_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(escape_js_string_cow("a\\b", false), r"a\\b");
+/// assert!(matches!(
+///     escape_js_string_cow("fred, barney", false),
+///     Cow::Borrowed(_)
+/// ));
+/// ```
+pub fn escape_js_string_cow(value: &str, ascii_only: bool) -> Cow<'_, str> {
+    codec::encode_cow(
+        &StringEncoder {
+            is_json: false,
+            is_ascii_only: ascii_only,
+        },
+        value,
+    )
+}
+
+/// Escapes `value` for use inside a JSON string, as defined by [RFC 8259]. Quotes, backslashes
+/// and control characters are escaped, and so are "<", ">", "&" and the line terminators U+2028
+/// and U+2029, which makes the JSON safe to embed in an HTML `<script>` block.
+///
+/// * `value` - The string to escape.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_json_string;
+///
+/// assert_eq!(
+///     escape_json_string("fred's \"barney\"\n</script>"),
+///     r#"fred's \"barney\"\n\u003C/script\u003E"#.to_string()
+/// );
+/// ```
+///
+/// [RFC 8259]: https://www.rfc-editor.org/rfc/rfc8259
+pub fn escape_json_string<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    escape_json_string_cow(value.as_ref(), false).into_owned()
+}
+
+/// This method is like [escape_json_string] except that, if `ascii_only` is `true`, characters
+/// outside ASCII are also escaped as UTF-16 code units, such as "\uD83D\uDE00" for "😀".
+///
+/// * `value` - The string to escape.
+/// * `ascii_only` - Whether characters outside ASCII are escaped.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_json_string_with;
+///
+/// assert_eq!(
+///     escape_json_string_with("café 😀", true),
+///     r"caf\u00E9 \uD83D\uDE00".to_string()
+/// );
+/// ```
+pub fn escape_json_string_with<S>(value: S, ascii_only: bool) -> String
+where
+    S: AsRef<str>,
+{
+    escape_json_string_cow(value.as_ref(), ascii_only).into_owned()
+}
+
+/// This method is like [escape_json_string_with] except that it returns `value` as is, without
+/// allocating, when it has nothing to escape.
+///
+/// * `value` - The string to escape.
+/// * `ascii_only` - Whether characters outside ASCII are escaped.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_json_string_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(escape_json_string_cow("a\\b", false), r"a\\b");
+/// assert!(matches!(
+///     escape_json_string_cow("fred, barney", false),
+///     Cow::Borrowed(_)
+/// ));
+/// ```
+pub fn escape_json_string_cow(value: &str, ascii_only: bool) -> Cow<'_, str> {
+    codec::encode_cow(
+        &StringEncoder {
+            is_json: true,
+            is_ascii_only: ascii_only,
+        },
+        value,
+    )
+}
+
+/// A trait that implements the [EscapeJsString::escape_js_string] method on strings.
+pub trait EscapeJsString<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Escapes this string for use inside a single- or double-quoted JavaScript string literal,
+    /// which is safe to embed in an HTML `<script>` block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeJsString;
+    ///
+    /// assert_eq!(
+    ///     "fred's </script>".escape_js_string(),
+    ///     r"fred\'s \x3C/script\x3E".to_string()
+    /// );
+    /// ```
+    fn escape_js_string(&self) -> String;
+
+    /// This method is like [EscapeJsString::escape_js_string] except that, if `ascii_only` is
+    /// `true`, characters outside ASCII are also escaped as UTF-16 code units.
+    ///
+    /// * `ascii_only` - Whether characters outside ASCII are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeJsString;
+    ///
+    /// assert_eq!("café".escape_js_string_with(true), r"caf\u00E9".to_string());
+    /// ```
+    fn escape_js_string_with(&self, ascii_only: bool) -> String;
+
+    /// This method is like [EscapeJsString::escape_js_string_with] except that it returns this
+    /// string as is, without allocating, when it has nothing to escape.
+    ///
+    /// * `ascii_only` - Whether characters outside ASCII are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeJsString;
+    ///
+    /// assert_eq!("a\tb".escape_js_string_cow(false), r"a\tb");
+    /// ```
+    fn escape_js_string_cow(&self, ascii_only: bool) -> Cow<'_, str>;
+
+    /// Escapes this string for use inside a JSON string, which is safe to embed in an HTML
+    /// `<script>` block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeJsString;
+    ///
+    /// assert_eq!(
+    ///     "fred's </script>".escape_json_string(),
+    ///     r"fred's \u003C/script\u003E".to_string()
+    /// );
+    /// ```
+    fn escape_json_string(&self) -> String;
+
+    /// This method is like [EscapeJsString::escape_json_string] except that, if `ascii_only` is
+    /// `true`, characters outside ASCII are also escaped as UTF-16 code units.
+    ///
+    /// * `ascii_only` - Whether characters outside ASCII are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeJsString;
+    ///
+    /// assert_eq!(
+    ///     "café".escape_json_string_with(true),
+    ///     r"caf\u00E9".to_string()
+    /// );
+    /// ```
+    fn escape_json_string_with(&self, ascii_only: bool) -> String;
+
+    /// This method is like [EscapeJsString::escape_json_string_with] except that it returns this
+    /// string as is, without allocating, when it has nothing to escape.
+    ///
+    /// * `ascii_only` - Whether characters outside ASCII are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeJsString;
+    ///
+    /// assert_eq!("a\tb".escape_json_string_cow(false), r"a\tb");
+    /// ```
+    fn escape_json_string_cow(&self, ascii_only: bool) -> Cow<'_, str>;
+}
+
+impl EscapeJsString<String> for String {
+    fn escape_js_string(&self) -> String {
+        escape_js_string(self)
+    }
+
+    fn escape_js_string_with(&self, ascii_only: bool) -> String {
+        escape_js_string_with(self, ascii_only)
+    }
+
+    fn escape_js_string_cow(&self, ascii_only: bool) -> Cow<'_, str> {
+        escape_js_string_cow(self, ascii_only)
+    }
+
+    fn escape_json_string(&self) -> String {
+        escape_json_string(self)
+    }
+
+    fn escape_json_string_with(&self, ascii_only: bool) -> String {
+        escape_json_string_with(self, ascii_only)
+    }
+
+    fn escape_json_string_cow(&self, ascii_only: bool) -> Cow<'_, str> {
+        escape_json_string_cow(self, ascii_only)
+    }
+}
+
+impl EscapeJsString<str> for str {
+    fn escape_js_string(&self) -> String {
+        escape_js_string(self)
+    }
+
+    fn escape_js_string_with(&self, ascii_only: bool) -> String {
+        escape_js_string_with(self, ascii_only)
+    }
+
+    fn escape_js_string_cow(&self, ascii_only: bool) -> Cow<'_, str> {
+        escape_js_string_cow(self, ascii_only)
+    }
+
+    fn escape_json_string(&self) -> String {
+        escape_json_string(self)
+    }
+
+    fn escape_json_string_with(&self, ascii_only: bool) -> String {
+        escape_json_string_with(self, ascii_only)
+    }
+
+    fn escape_json_string_cow(&self, ascii_only: bool) -> Cow<'_, str> {
+        escape_json_string_cow(self, ascii_only)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_escape_js_strings() {
+        assert_eq!(
+            escape_js_string("\\\"'\u{8}\u{c}\n\r\t\u{0}\u{b}\u{1f}<>&/\u{7f}"),
+            r#"\\\"\'\b\f\n\r\t\x00\x0B\x1F\x3C\x3E&/"#.to_string() + "\u{7f}"
+        );
+        assert_eq!(
+            "<!-- </script> \u{2028}\u{2029}".escape_js_string(),
+            r"\x3C!-- \x3C/script\x3E \u2028\u2029".to_string()
+        );
+        assert_eq!(
+            "é€😀\u{2027}".to_string().escape_js_string(),
+            "é€😀\u{2027}".to_string()
+        );
+    }
+
+    #[test]
+    fn can_escape_js_strings_as_ascii() {
+        assert_eq!(
+            escape_js_string_with("é€😀\u{2028}'", true),
+            r"\u00E9\u20AC\uD83D\uDE00\u2028\'".to_string()
+        );
+        assert_eq!(
+            "a\u{80}b".escape_js_string_with(true),
+            r"a\u0080b".to_string()
+        );
+    }
+
+    #[test]
+    fn can_escape_json_strings() {
+        assert_eq!(
+            escape_json_string("\\\"'\u{8}\u{c}\n\r\t\u{0}\u{b}\u{1f}<>&/"),
+            r#"\\\"'\b\f\n\r\t\u0000\u000B\u001F\u003C\u003E\u0026/"#.to_string()
+        );
+        assert_eq!(
+            "\u{2028}\u{2029}é".escape_json_string(),
+            r"\u2028\u2029é".to_string()
+        );
+        assert_eq!(
+            escape_json_string_with("é€😀", true),
+            r"\u00E9\u20AC\uD83D\uDE00".to_string()
+        );
+        assert_eq!(
+            "é".to_string().escape_json_string_with(true),
+            r"\u00E9".to_string()
+        );
+    }
+
+    #[test]
+    fn can_borrow_strings_with_nothing_to_escape() {
+        assert!(matches!(
+            escape_js_string_cow("fred & barney é", false),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            "fred 'barney' é".escape_json_string_cow(false),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(escape_json_string_cow("é", true), Cow::Owned(_)));
+        assert_eq!("abc".escape_js_string_cow(true), "abc");
+    }
+}
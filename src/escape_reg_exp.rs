@@ -0,0 +1,271 @@
+use crate::codec::{
+    self,
+    ByteSet,
+    Encoder,
+};
+use regex::{
+    Regex,
+    RegexBuilder,
+};
+use std::fmt;
+
+/// The regex metacharacters escaped by [escape_reg_exp].
+static REG_EXP_CHARS: ByteSet = ByteSet::new(br"\^$.*+?()[]{}|");
+
+/// The rules applied by [escape_reg_exp].
+struct RegExpEncoder;
+
+impl Encoder for RegExpEncoder {
+    fn special_bytes(&self) -> &ByteSet {
+        &REG_EXP_CHARS
+    }
+
+    fn encode<W>(&self, c: char, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        out.write_char('\\')?;
+        out.write_char(c)
+    }
+}
+
+/// The options used by [literal_reg_exp] to build a regex.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegExpOptions {
+    /// Whether letters match regardless of their case, using Unicode simple case folding.
+    pub case_insensitive: bool,
+    /// Whether matches must not be preceded or followed by a word character, so that "cat" does
+    /// not match within "concatenate".
+    pub whole_word: bool,
+}
+
+/// Escapes the regex metacharacters "\\", "^", "$", ".", "*", "+", "?", "(", ")", "[", "]", "{",
+/// "}" and "|" in `value`.
+///
+/// * `value` - The string to escape.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_reg_exp;
+///
+/// assert_eq!(
+///     escape_reg_exp("[lodash](https://lodash.com/)"),
+///     r"\[lodash\]\(https://lodash\.com/\)".to_string()
+/// );
+/// ```
+pub fn escape_reg_exp<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    codec::encode_cow(&RegExpEncoder, value.as_ref()).into_owned()
+}
+
+/// Builds a [Regex] that matches `value` literally, as if every character of it had been escaped
+/// with [escape_reg_exp].
+///
+/// * `value` - The text to match.
+/// * `options` - The options applied to the regex.
+///
+/// # Errors
+///
+/// Returns a [regex::Error] if the compiled regex would exceed the default size limit of the
+/// [regex] crate, which can only happen for very long values.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     literal_reg_exp,
+///     RegExpOptions,
+/// };
+///
+/// let regex = literal_reg_exp("C++", RegExpOptions::default()).unwrap();
+/// assert!(regex.is_match("learning C++ today"));
+/// assert!(!regex.is_match("learning C today"));
+///
+/// let options = RegExpOptions {
+///     case_insensitive: true,
+///     whole_word: true,
+/// };
+/// let regex = literal_reg_exp("cat", options).unwrap();
+/// assert!(regex.is_match("The Cat sat"));
+/// assert!(!regex.is_match("concatenate"));
+/// ```
+pub fn literal_reg_exp<S>(value: S, options: RegExpOptions) -> Result<Regex, regex::Error>
+where
+    S: AsRef<str>,
+{
+    let escaped = escape_reg_exp(value);
+    let pattern = if options.whole_word {
+        format!(r"\b{{start-half}}(?:{escaped})\b{{end-half}}")
+    } else {
+        escaped
+    };
+
+    RegexBuilder::new(&pattern)
+        .case_insensitive(options.case_insensitive)
+        .build()
+}
+
+/// A trait that implements the [EscapeRegExp::escape_reg_exp] method on strings.
+pub trait EscapeRegExp<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Escapes the regex metacharacters "\\", "^", "$", ".", "*", "+", "?", "(", ")", "[", "]",
+    /// "{", "}" and "|" in this string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeRegExp;
+    ///
+    /// assert_eq!(
+    ///     "[lodash](https://lodash.com/)".escape_reg_exp(),
+    ///     r"\[lodash\]\(https://lodash\.com/\)".to_string()
+    /// );
+    /// ```
+    fn escape_reg_exp(&self) -> String;
+
+    /// Builds a [Regex] that matches this string literally.
+    ///
+    /// * `options` - The options applied to the regex.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [regex::Error] if the compiled regex would exceed the default size limit of the
+    /// [regex] crate, which can only happen for very long strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     EscapeRegExp,
+    ///     RegExpOptions,
+    /// };
+    ///
+    /// let regex = "1.5".to_literal_reg_exp(RegExpOptions::default()).unwrap();
+    /// assert!(regex.is_match("version 1.5"));
+    /// assert!(!regex.is_match("version 125"));
+    /// ```
+    fn to_literal_reg_exp(&self, options: RegExpOptions) -> Result<Regex, regex::Error>;
+}
+
+impl EscapeRegExp<String> for String {
+    fn escape_reg_exp(&self) -> String {
+        escape_reg_exp(self)
+    }
+
+    fn to_literal_reg_exp(&self, options: RegExpOptions) -> Result<Regex, regex::Error> {
+        literal_reg_exp(self, options)
+    }
+}
+
+impl EscapeRegExp<str> for str {
+    fn escape_reg_exp(&self) -> String {
+        escape_reg_exp(self)
+    }
+
+    fn to_literal_reg_exp(&self, options: RegExpOptions) -> Result<Regex, regex::Error> {
+        literal_reg_exp(self, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    /// Every character escaped by [escape_reg_exp].
+    const METACHARACTERS: &str = r"\^$.*+?()[]{}|";
+
+    #[test]
+    fn can_escape_metacharacters() {
+        assert_eq!(
+            escape_reg_exp(METACHARACTERS),
+            r"\\\^\$\.\*\+\?\(\)\[\]\{\}\|".to_string()
+        );
+        assert_eq!("a.b*c é".escape_reg_exp(), r"a\.b\*c é".to_string());
+        assert_eq!(
+            String::from("#&-~ /").escape_reg_exp(),
+            "#&-~ /".to_string()
+        );
+    }
+
+    #[test]
+    fn can_build_literal_regexes() {
+        let regex = literal_reg_exp(METACHARACTERS, RegExpOptions::default()).unwrap();
+        assert!(regex.is_match(&format!("a{METACHARACTERS}b")));
+        assert!(!regex.is_match(r"\^$.*+?()[]{}"));
+
+        let regex = "Straße"
+            .to_literal_reg_exp(RegExpOptions::default())
+            .unwrap();
+        assert!(regex.is_match("Straße"));
+        assert!(!regex.is_match("STRASSE"));
+        assert!(!regex.is_match("straße"));
+    }
+
+    #[test]
+    fn can_build_case_insensitive_regexes() {
+        let options = RegExpOptions {
+            case_insensitive: true,
+            ..RegExpOptions::default()
+        };
+        let regex = literal_reg_exp("Déjà vu?", options).unwrap();
+        assert!(regex.is_match("DÉJÀ VU?"));
+        assert!(!regex.is_match("DÉJÀ VU"));
+    }
+
+    #[test]
+    fn can_build_whole_word_regexes() {
+        let options = RegExpOptions {
+            whole_word: true,
+            ..RegExpOptions::default()
+        };
+        let regex = literal_reg_exp("cat", options).unwrap();
+        assert!(regex.is_match("a cat."));
+        assert!(regex.is_match("cat"));
+        assert!(!regex.is_match("concatenate"));
+        assert!(!regex.is_match("cats"));
+
+        let regex = literal_reg_exp("-x-", options).unwrap();
+        assert!(regex.is_match("a -x- b"));
+        assert!(regex.is_match("a--x--b"));
+        assert!(!regex.is_match("a-x-b"));
+
+        let regex = literal_reg_exp("C++", options).unwrap();
+        assert!(regex.is_match("C++ and Rust"));
+        assert!(!regex.is_match("C++x"));
+    }
+
+    quickcheck! {
+        fn escaped_regexes_match_exactly_the_input(value: String) -> bool {
+            let regex = literal_reg_exp(&value, RegExpOptions::default()).unwrap();
+            let anchored = Regex::new(&format!("^(?:{})$", escape_reg_exp(&value))).unwrap();
+
+            regex.find(&value).is_some_and(|found| found.as_str() == value)
+                && anchored.is_match(&value)
+        }
+
+        fn escaped_regexes_only_match_the_input(value: String, other: String) -> bool {
+            let anchored = Regex::new(&format!("^(?:{})$", escape_reg_exp(&value))).unwrap();
+
+            anchored.is_match(&other) == (value == other)
+        }
+
+        fn escaped_regexes_find_the_input_within_text(
+            prefix: String,
+            value: String,
+            suffix: String
+        ) -> bool {
+            let regex = literal_reg_exp(&value, RegExpOptions::default()).unwrap();
+            let text = format!("{prefix}{value}{suffix}");
+
+            regex.find_iter(&text).map(|found| found.range()).eq(text
+                .match_indices(value.as_str())
+                .map(|(index, found)| index..index + found.len()))
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    codec::utf8_width,
+    escape_to_io,
+    EscapeContext,
+};
+use std::{
+    io,
+    io::Write,
+    str,
+};
+
+/// A writer that escapes the UTF-8 text written into it before passing it to the `inner` writer,
+/// like [escape_for] does for whole strings.
+///
+/// Characters split across writes are buffered until they are complete, so the text may be
+/// written in chunks of any size. Writing bytes that are not valid UTF-8 fails with
+/// [io::ErrorKind::InvalidData].
+///
+/// # Examples
+///
+/// ```
+/// use rodash::EscapeWriter;
+/// use std::io::Write;
+///
+/// let mut writer = EscapeWriter::new(Vec::new());
+/// writer.write_all(b"fred, barney, ").unwrap();
+/// writer.write_all(b"& pebbles").unwrap();
+/// assert_eq!(writer.finish().unwrap(), b"fred, barney, &amp; pebbles");
+/// ```
+///
+/// [escape_for]: crate::escape_for
+#[derive(Debug)]
+pub struct EscapeWriter<W> {
+    inner: W,
+    context: EscapeContext,
+    pending: [u8; 4],
+    pending_length: usize,
+}
+
+impl<W> EscapeWriter<W>
+where
+    W: Write,
+{
+    /// Creates a writer that escapes text for the [EscapeContext::Text] context, like [escape]
+    /// does.
+    ///
+    /// * `inner` - The writer to write the escaped text into.
+    ///
+    /// [escape]: crate::escape
+    pub fn new(inner: W) -> Self {
+        Self::with_context(inner, EscapeContext::Text)
+    }
+
+    /// Creates a writer that escapes text for the given `context`.
+    ///
+    /// * `inner` - The writer to write the escaped text into.
+    /// * `context` - The context in which the text is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     EscapeContext,
+    ///     EscapeWriter,
+    /// };
+    /// use std::io::Write;
+    ///
+    /// let mut writer = EscapeWriter::with_context(Vec::new(), EscapeContext::Url);
+    /// writer.write_all("fred & barney".as_bytes()).unwrap();
+    /// assert_eq!(writer.finish().unwrap(), b"fred%20%26%20barney");
+    /// ```
+    pub fn with_context(inner: W, context: EscapeContext) -> Self {
+        Self {
+            inner,
+            context,
+            pending: [0; 4],
+            pending_length: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Flushes this writer and returns the underlying writer. Fails with
+    /// [io::ErrorKind::InvalidData] if the text written so far ends with an incomplete character.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.pending_length > 0 {
+            return Err(invalid_utf8());
+        }
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Completes the pending character with the leading bytes of `buf`, escaping it once it is
+    /// complete. Returns the number of bytes consumed from `buf`.
+    fn write_pending(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let width = utf8_width(self.pending[0]).ok_or_else(invalid_utf8)?;
+        let consumed = buf.len().min(width - self.pending_length);
+
+        self.pending[self.pending_length..self.pending_length + consumed]
+            .copy_from_slice(&buf[..consumed]);
+        self.pending_length += consumed;
+
+        if self.pending_length == width {
+            let pending = self.pending;
+            let c = str::from_utf8(&pending[..width]).map_err(|_| invalid_utf8())?;
+
+            self.pending_length = 0;
+            escape_to_io(c, self.context, &mut self.inner)?;
+        }
+
+        Ok(consumed)
+    }
+}
+
+impl<W> Write for EscapeWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let mut consumed = 0;
+
+        if self.pending_length > 0 {
+            consumed = self.write_pending(buf)?;
+
+            if consumed == buf.len() {
+                return Ok(consumed);
+            }
+        }
+
+        let rest = &buf[consumed..];
+
+        match str::from_utf8(rest) {
+            Ok(text) => {
+                escape_to_io(text, self.context, &mut self.inner)?;
+
+                Ok(buf.len())
+            }
+            Err(error) => {
+                let valid = error.valid_up_to();
+                let text = str::from_utf8(&rest[..valid]).map_err(|_| invalid_utf8())?;
+
+                escape_to_io(text, self.context, &mut self.inner)?;
+
+                match error.error_len() {
+                    // The text ends with an incomplete character.
+                    None => {
+                        let tail = &rest[valid..];
+
+                        self.pending[..tail.len()].copy_from_slice(tail);
+                        self.pending_length = tail.len();
+
+                        Ok(buf.len())
+                    }
+                    // Report the valid prefix first, so the error surfaces on the next write.
+                    Some(_) if consumed + valid > 0 => Ok(consumed + valid),
+                    Some(_) => Err(invalid_utf8()),
+                }
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Creates the error returned for text that is not valid UTF-8.
+fn invalid_utf8() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "stream did not contain valid UTF-8",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::escape_for;
+
+    #[test]
+    fn can_escape_written_text() {
+        let mut writer = EscapeWriter::new(Vec::new());
+        writer.write_all(br#"&<>"'/"#).unwrap();
+        assert_eq!(writer.finish().unwrap(), b"&amp;&lt;&gt;&quot;&#39;/");
+    }
+
+    #[test]
+    fn can_escape_text_written_one_byte_at_a_time() {
+        let text = "fred & barney € é 😀 </script>\u{2028}";
+
+        for context in [
+            EscapeContext::Text,
+            EscapeContext::UnquotedAttribute,
+            EscapeContext::Script,
+            EscapeContext::Url,
+        ] {
+            let mut writer = EscapeWriter::with_context(Vec::new(), context);
+
+            for byte in text.as_bytes() {
+                writer.write_all(&[*byte]).unwrap();
+            }
+
+            assert_eq!(
+                String::from_utf8(writer.finish().unwrap()).unwrap(),
+                escape_for(text, context)
+            );
+        }
+    }
+
+    #[test]
+    fn can_reject_invalid_utf8() {
+        let mut writer = EscapeWriter::new(Vec::new());
+        assert_eq!(writer.write(b"a&\xffb").unwrap(), 2);
+        assert_eq!(
+            writer.write(b"\xffb").unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(writer.get_ref(), b"a&amp;");
+
+        let mut writer = EscapeWriter::new(Vec::new());
+        writer.write_all(b"a\xe2\x82").unwrap();
+        assert_eq!(
+            writer.finish().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use crate::codec::{
+    self,
+    ByteSet,
+    Encoder,
+};
+use std::{
+    error::Error,
+    fmt,
+};
+
+/// The bytes that may start a character escaped in XML, or one that is illegal or restricted in
+/// XML 1.0 or 1.1.
+static XML_SPECIAL_BYTES: ByteSet = ByteSet::new(b"&<>\"'\x7f")
+    .with(&[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x0b, 0x0c, 0x0e, 0x0f, 0x10, 0x11,
+        0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+    ])
+    .with(&[0xc2, 0xef]);
+
+/// The version of the XML specification whose character rules are applied by [escape_xml_with].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum XmlVersion {
+    /// [XML 1.0](https://www.w3.org/TR/xml/), in which the C0 control characters other than tab,
+    /// line feed and carriage return are illegal, even as character references.
+    #[default]
+    V1_0,
+    /// [XML 1.1](https://www.w3.org/TR/xml11/), in which the C0 control characters other than
+    /// the null character, and the C1 control characters, may only appear as character references.
+    V1_1,
+}
+
+/// What [escape_xml_with] does with characters that are illegal in the chosen [XmlVersion].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IllegalCharPolicy {
+    /// Illegal characters are replaced with U+FFFD.
+    #[default]
+    Replace,
+    /// Illegal characters are removed.
+    Remove,
+    /// An [IllegalCharError] is returned for the first illegal character.
+    Reject,
+}
+
+/// The error returned by [escape_xml_with] when a value contains a character that is illegal in
+/// XML and the [IllegalCharPolicy::Reject] policy is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IllegalCharError {
+    /// The illegal character.
+    pub character: char,
+    /// The byte index of the illegal character in the value.
+    pub index: usize,
+}
+
+impl fmt::Display for IllegalCharError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "illegal XML character U+{:04X} at index {}",
+            self.character as u32, self.index
+        )
+    }
+}
+
+impl Error for IllegalCharError {}
+
+/// The rules applied by [escape_xml_with].
+struct XmlEncoder {
+    version: XmlVersion,
+    policy: IllegalCharPolicy,
+}
+
+impl XmlEncoder {
+    /// Returns `true` if `c` cannot appear in a document of this version, even as a character
+    /// reference.
+    fn is_illegal(&self, c: char) -> bool {
+        match self.version {
+            XmlVersion::V1_0 => matches!(
+                c,
+                '\u{0}'..='\u{8}' | '\u{b}' | '\u{c}' | '\u{e}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}'
+            ),
+            XmlVersion::V1_1 => matches!(c, '\u{0}' | '\u{fffe}' | '\u{ffff}'),
+        }
+    }
+
+    /// Returns `true` if `c` may only appear as a character reference in a document of this
+    /// version.
+    fn is_restricted(&self, c: char) -> bool {
+        self.version == XmlVersion::V1_1
+            && matches!(
+                c,
+                '\u{1}'..='\u{8}'
+                    | '\u{b}'
+                    | '\u{c}'
+                    | '\u{e}'..='\u{1f}'
+                    | '\u{7f}'..='\u{84}'
+                    | '\u{86}'..='\u{9f}'
+            )
+    }
+}
+
+impl Encoder for XmlEncoder {
+    fn special_bytes(&self) -> &ByteSet {
+        &XML_SPECIAL_BYTES
+    }
+
+    fn encode<W>(&self, c: char, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        match c {
+            '&' => out.write_str("&amp;"),
+            '<' => out.write_str("&lt;"),
+            '>' => out.write_str("&gt;"),
+            '"' => out.write_str("&quot;"),
+            '\'' => out.write_str("&apos;"),
+            _ if self.is_illegal(c) => match self.policy {
+                IllegalCharPolicy::Replace => out.write_char(char::REPLACEMENT_CHARACTER),
+                IllegalCharPolicy::Remove => Ok(()),
+                // Rejected values are never encoded.
+                IllegalCharPolicy::Reject => Err(fmt::Error),
+            },
+            _ if self.is_restricted(c) => write!(out, "&#x{:X};", c as u32),
+            _ => out.write_char(c),
+        }
+    }
+}
+
+/// Converts the characters "&", "<", ">", '"', and "'" in `value` to their corresponding XML
+/// entities `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;`, so that it can be used in XML 1.0
+/// text and attribute values. Characters that are illegal in XML 1.0, such as most C0 control
+/// characters, are replaced with U+FFFD.
+///
+/// * `value` - The string to escape.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::escape_xml;
+///
+/// assert_eq!(
+///     escape_xml("<name>fred & 'barney'</name>\u{1}"),
+///     "&lt;name&gt;fred &amp; &apos;barney&apos;&lt;/name&gt;\u{fffd}".to_string()
+/// );
+/// ```
+pub fn escape_xml<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    let encoder = XmlEncoder {
+        version: XmlVersion::V1_0,
+        policy: IllegalCharPolicy::Replace,
+    };
+
+    codec::encode_cow(&encoder, value.as_ref()).into_owned()
+}
+
+/// This method is like [escape_xml] except that it accepts the XML version whose character rules
+/// are applied and what to do with illegal characters. In [XmlVersion::V1_1], restricted
+/// characters are converted to the `&#xH;` format.
+///
+/// * `value` - The string to escape.
+/// * `version` - The XML version whose character rules are applied.
+/// * `policy` - What to do with characters that are illegal in `version`.
+///
+/// # Errors
+///
+/// Returns an [IllegalCharError] for the first illegal character of `value` when `policy` is
+/// [IllegalCharPolicy::Reject].
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     escape_xml_with,
+///     IllegalCharPolicy,
+///     XmlVersion,
+/// };
+///
+/// assert_eq!(
+///     escape_xml_with("fred\u{1}", XmlVersion::V1_1, IllegalCharPolicy::Reject),
+///     Ok("fred&#x1;".to_string())
+/// );
+/// assert!(escape_xml_with("fred\u{1}", XmlVersion::V1_0, IllegalCharPolicy::Reject).is_err());
+/// assert_eq!(
+///     escape_xml_with("fred\u{1}", XmlVersion::V1_0, IllegalCharPolicy::Remove),
+///     Ok("fred".to_string())
+/// );
+/// ```
+pub fn escape_xml_with<S>(
+    value: S,
+    version: XmlVersion,
+    policy: IllegalCharPolicy,
+) -> Result<String, IllegalCharError>
+where
+    S: AsRef<str>,
+{
+    let value = value.as_ref();
+    let encoder = XmlEncoder { version, policy };
+
+    if policy == IllegalCharPolicy::Reject {
+        if let Some((index, character)) = value
+            .char_indices()
+            .find(|(_, character)| encoder.is_illegal(*character))
+        {
+            return Err(IllegalCharError { character, index });
+        }
+    }
+
+    Ok(codec::encode_cow(&encoder, value).into_owned())
+}
+
+/// A trait that implements the [EscapeXml::escape_xml] method on strings.
+pub trait EscapeXml<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts the characters "&", "<", ">", '"', and "'" in this string to their corresponding
+    /// XML entities `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;`, so that it can be used in XML
+    /// 1.0 text and attribute values. Characters that are illegal in XML 1.0, such as most C0
+    /// control characters, are replaced with U+FFFD.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::EscapeXml;
+    ///
+    /// assert_eq!(
+    ///     "fred & 'barney'".escape_xml(),
+    ///     "fred &amp; &apos;barney&apos;".to_string()
+    /// );
+    /// ```
+    fn escape_xml(&self) -> String;
+
+    /// This method is like [EscapeXml::escape_xml] except that it accepts the XML version whose
+    /// character rules are applied and what to do with illegal characters.
+    ///
+    /// * `version` - The XML version whose character rules are applied.
+    /// * `policy` - What to do with characters that are illegal in `version`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [IllegalCharError] for the first illegal character of this string when `policy`
+    /// is [IllegalCharPolicy::Reject].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     EscapeXml,
+    ///     IllegalCharPolicy,
+    ///     XmlVersion,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "fred\u{1}".escape_xml_with(XmlVersion::V1_1, IllegalCharPolicy::Reject),
+    ///     Ok("fred&#x1;".to_string())
+    /// );
+    /// ```
+    fn escape_xml_with(
+        &self,
+        version: XmlVersion,
+        policy: IllegalCharPolicy,
+    ) -> Result<String, IllegalCharError>;
+}
+
+impl EscapeXml<String> for String {
+    fn escape_xml(&self) -> String {
+        escape_xml(self)
+    }
+
+    fn escape_xml_with(
+        &self,
+        version: XmlVersion,
+        policy: IllegalCharPolicy,
+    ) -> Result<String, IllegalCharError> {
+        escape_xml_with(self, version, policy)
+    }
+}
+
+impl EscapeXml<str> for str {
+    fn escape_xml(&self) -> String {
+        escape_xml(self)
+    }
+
+    fn escape_xml_with(
+        &self,
+        version: XmlVersion,
+        policy: IllegalCharPolicy,
+    ) -> Result<String, IllegalCharError> {
+        escape_xml_with(self, version, policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        unescape_xml,
+        UnescapeXml,
+    };
+
+    #[test]
+    fn can_escape_values() {
+        assert_eq!(
+            escape_xml(r#"&<>"'/&<>"'/"#),
+            "&amp;&lt;&gt;&quot;&apos;/&amp;&lt;&gt;&quot;&apos;/".to_string()
+        );
+        assert_eq!(
+            r#"&<>"'/"#.escape_xml(),
+            "&amp;&lt;&gt;&quot;&apos;/".to_string()
+        );
+        assert_eq!(
+            escape_xml(unescape_xml("&amp;&lt;&gt;&quot;&apos;")),
+            "&amp;&lt;&gt;&quot;&apos;".to_string()
+        );
+        assert_eq!(
+            "&amp;&lt;&gt;&quot;&apos;".unescape_xml().escape_xml(),
+            "&amp;&lt;&gt;&quot;&apos;".to_string()
+        );
+    }
+
+    #[test]
+    fn can_handle_strings_with_nothing_to_escape() {
+        assert_eq!(
+            escape_xml("abc\t\n\r€\u{85}"),
+            "abc\t\n\r€\u{85}".to_string()
+        );
+        assert_eq!("abc".escape_xml(), "abc".to_string());
+    }
+
+    #[test]
+    fn can_replace_illegal_characters() {
+        assert_eq!(
+            escape_xml("a\u{0}b\u{1f}c\u{fffe}d\u{ffff}"),
+            "a\u{fffd}b\u{fffd}c\u{fffd}d\u{fffd}".to_string()
+        );
+        assert_eq!(
+            escape_xml_with("a\u{0}b\u{1f}", XmlVersion::V1_0, IllegalCharPolicy::Remove),
+            Ok("ab".to_string())
+        );
+        assert_eq!(
+            "a\u{0}b".escape_xml_with(XmlVersion::V1_1, IllegalCharPolicy::Replace),
+            Ok("a\u{fffd}b".to_string())
+        );
+    }
+
+    #[test]
+    fn can_reject_illegal_characters() {
+        assert_eq!(
+            escape_xml_with("a&\u{1}", XmlVersion::V1_0, IllegalCharPolicy::Reject),
+            Err(IllegalCharError {
+                character: '\u{1}',
+                index: 2
+            })
+        );
+        assert_eq!(
+            "é\u{ffff}".escape_xml_with(XmlVersion::V1_1, IllegalCharPolicy::Reject),
+            Err(IllegalCharError {
+                character: '\u{ffff}',
+                index: 2
+            })
+        );
+        assert_eq!(
+            IllegalCharError {
+                character: '\u{1}',
+                index: 2
+            }
+            .to_string(),
+            "illegal XML character U+0001 at index 2"
+        );
+    }
+
+    #[test]
+    fn can_escape_restricted_characters_in_xml_1_1() {
+        assert_eq!(
+            escape_xml_with(
+                "\u{1}\u{1f}\u{7f}\u{85}\u{9f}\t",
+                XmlVersion::V1_1,
+                IllegalCharPolicy::Reject
+            ),
+            Ok("&#x1;&#x1F;&#x7F;\u{85}&#x9F;\t".to_string())
+        );
+    }
+}
@@ -0,0 +1,87 @@
+/// Adds `prefix` to the start of every line of `text` that is not blank. Lines that are empty or
+/// only have whitespace are left as they are, so that no trailing whitespace is added.
+///
+/// * `text` - The text to indent.
+/// * `prefix` - The string added to the start of the lines.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::indent;
+///
+/// assert_eq!(
+///     indent("fn main() {}\n\nmod tests;\n", "    "),
+///     "    fn main() {}\n\n    mod tests;\n".to_string()
+/// );
+/// assert_eq!(
+///     indent("Fixes #42\nSee #41", "> "),
+///     "> Fixes #42\n> See #41".to_string()
+/// );
+/// ```
+pub fn indent<S>(text: S, prefix: &str) -> String
+where
+    S: AsRef<str>,
+{
+    let text = text.as_ref();
+    let mut indented = String::with_capacity(text.len() + prefix.len());
+
+    for line in text.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            indented.push_str(prefix);
+        }
+
+        indented.push_str(line);
+    }
+
+    indented
+}
+
+/// A trait that implements the [Indent::indent] method on strings.
+pub trait Indent<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Adds `prefix` to the start of every line of this string that is not blank.
+    ///
+    /// * `prefix` - The string added to the start of the lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Indent;
+    ///
+    /// assert_eq!("a\n\nb".indent("  "), "  a\n\n  b".to_string());
+    /// ```
+    fn indent(&self, prefix: &str) -> String;
+}
+
+impl Indent<String> for String {
+    fn indent(&self, prefix: &str) -> String {
+        indent(self, prefix)
+    }
+}
+
+impl Indent<str> for str {
+    fn indent(&self, prefix: &str) -> String {
+        indent(self, prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_indent() {
+        assert_eq!(indent("", "  "), "".to_string());
+        assert_eq!(indent("a", ""), "a".to_string());
+        assert_eq!(
+            "a\r\n  \r\nb\r\n".indent("\t"),
+            "\ta\r\n  \r\n\tb\r\n".to_string()
+        );
+        assert_eq!(
+            "  a\n    b".to_string().indent("// "),
+            "//   a\n//     b".to_string()
+        );
+    }
+}
@@ -65,20 +65,20 @@ mod tests {
     fn can_exclude_the_last_element() {
         assert_eq!(initial(&[1, 2, 3]), [1, 2]);
         assert_eq!(initial(&[1, 2]), [1]);
-        assert_eq!(initial(&[1]), []);
+        assert_eq!(initial(&[1]), [0; 0]);
 
         assert_eq!(vec![1, 2, 3].initial(), [1, 2]);
         assert_eq!(vec![1, 2].initial(), [1]);
-        assert_eq!(vec![1].initial(), []);
+        assert_eq!(vec![1].initial(), [0; 0]);
 
         assert_eq!([1, 2, 3].initial(), [1, 2]);
         assert_eq!([1, 2].initial(), [1]);
-        assert_eq!([1].initial(), []);
+        assert_eq!([1].initial(), [0; 0]);
     }
 
     #[test]
     fn can_handle_empty_arrays() {
-        assert_eq!(initial::<char>(&[]), []);
+        assert_eq!(initial::<char>(&[]), Vec::<char>::new());
         assert_eq!(
             {
                 let array: Vec<char> = vec![];
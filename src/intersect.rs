@@ -3,8 +3,10 @@ use std::{
     hash::Hash,
 };
 
-/// Creates an array of unique values that are included in both the `array` and `other` arrays. The
-/// order of result values are determined by the `array`.
+/// Creates an array of unique values that are included in both the `array` and `other` arrays, like
+/// the lodash `_.intersection` function. The order of result values are determined by the
+/// `array`, in which only the first occurrence of each value is kept. See
+/// [intersect_multiset](crate::intersect_multiset) to keep duplicates.
 ///
 /// * `array` - The source array.
 /// * `other` - The array to inspect.
@@ -16,27 +18,104 @@ use std::{
 ///
 /// let result = intersect(&[2, 1], &[2, 3]);
 /// assert_eq!(result, [2]);
+///
+/// let result = intersect(&[3, 1, 3, 2, 1], &[1, 2, 3]);
+/// assert_eq!(result, [3, 1, 2]);
 /// ```
 pub fn intersect<A>(array: &[A], other: &[A]) -> Vec<A>
 where
     A: PartialEq + Ord + Clone + Hash,
 {
-    let unique_set: HashSet<A> = other.iter().map(|x| x.to_owned()).collect();
+    // Values are removed from the set once found, so that only their first occurrence is kept.
+    let mut included: HashSet<&A> = other.iter().collect();
+
+    array
+        .iter()
+        .filter(|item| included.remove(item))
+        .cloned()
+        .collect()
+}
+
+/// This method is like [intersect] except that it accepts `iteratee` which is invoked for each
+/// value of `array` and `other` to generate the key by which they are compared, like the lodash
+/// `_.intersectionBy` function. Only the first value of `array` with each key is kept, and the
+/// keys of `other` are looked up in a hash set, so the values themselves only need to be
+/// cloneable.
+///
+/// * `array` - The source array.
+/// * `other` - The array to inspect.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::intersect_by;
+///
+/// let result = intersect_by(&[2.1, 1.2, 2.5], &[2.3, 3.4], |n: &f64| n.floor() as i64);
+/// assert_eq!(result, [2.1]);
+/// ```
+pub fn intersect_by<A, K, F>(array: &[A], other: &[A], mut iteratee: F) -> Vec<A>
+where
+    A: Clone,
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    let mut included = other.iter().map(&mut iteratee).collect::<HashSet<_>>();
+
+    array
+        .iter()
+        .filter(|item| included.remove(&iteratee(item)))
+        .cloned()
+        .collect()
+}
+
+/// This method is like [intersect] except that it accepts `comparator` which is invoked to tell
+/// whether two values are equal, like the lodash `_.intersectionWith` function. It is invoked
+/// with a value of `array` and either a value of `other` or a value already in the result. Every
+/// value of `array` is compared with every value of `other`, in `O(n * m)` time, so
+/// [intersect_by] should be preferred when values can be compared by a key.
+///
+/// * `array` - The source array.
+/// * `other` - The array to inspect.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::intersect_with;
+///
+/// let result = intersect_with(&["Fred", "barney", "FRED"], &["fred"], |a, b| {
+///     a.eq_ignore_ascii_case(b)
+/// });
+/// assert_eq!(result, ["Fred"]);
+/// ```
+pub fn intersect_with<A, F>(array: &[A], other: &[A], mut comparator: F) -> Vec<A>
+where
+    A: Clone,
+    F: FnMut(&A, &A) -> bool,
+{
+    let mut result: Vec<A> = Vec::new();
 
-    unique_set
-        .intersection(&array.iter().map(|x| x.to_owned()).collect())
-        .map(|x| x.to_owned())
-        .collect::<Vec<_>>()
+    for item in array {
+        if other.iter().any(|value| comparator(item, value))
+            && !result.iter().any(|value| comparator(item, value))
+        {
+            result.push(item.clone());
+        }
+    }
+
+    result
 }
 
 /// A trait that implements the [Intersect::intersect] method on arrays.
 pub trait Intersect<A, I>
 where
     A: IntoIterator,
-    I: PartialEq + Ord + Clone + Hash,
+    I: Clone,
 {
     /// Creates an array of unique values that are included in this array and the `other` array. The
-    /// order of result values are determined by this array.
+    /// order of result values are determined by this array, in which only the first occurrence of
+    /// each value is kept.
     ///
     /// * `other` - The array to inspect.
     ///
@@ -47,30 +126,111 @@ where
     ///
     /// assert_eq!([2, 1].intersect(&[2, 3]), [2]);
     /// ```
-    fn intersect(&self, other: &[I]) -> Vec<I>;
+    fn intersect(&self, other: &[I]) -> Vec<I>
+    where
+        I: PartialEq + Ord + Hash;
+
+    /// This method is like [Intersect::intersect] except that it accepts `iteratee` which is
+    /// invoked for each value of this array and `other` to generate the key by which they are
+    /// compared.
+    ///
+    /// * `other` - The array to inspect.
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Intersect;
+    ///
+    /// assert_eq!(
+    ///     [2.1, 1.2].intersect_by(&[2.3, 3.4], |n: &f64| n.floor() as i64),
+    ///     [2.1]
+    /// );
+    /// ```
+    fn intersect_by<K, F>(&self, other: &[I], iteratee: F) -> Vec<I>
+    where
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [Intersect::intersect] except that it accepts `comparator` which is
+    /// invoked to tell whether two values are equal.
+    ///
+    /// * `other` - The array to inspect.
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Intersect;
+    ///
+    /// assert_eq!(
+    ///     ["Fred", "barney"].intersect_with(&["FRED"], |a, b| a.eq_ignore_ascii_case(b)),
+    ///     ["Fred"]
+    /// );
+    /// ```
+    fn intersect_with<F>(&self, other: &[I], comparator: F) -> Vec<I>
+    where
+        F: FnMut(&I, &I) -> bool;
 }
 
 impl<A> Intersect<Vec<A>, A> for Vec<A>
 where
-    A: PartialEq + Ord + Clone + Hash,
+    A: Clone,
 {
-    fn intersect(&self, other: &[A]) -> Vec<A> {
+    fn intersect(&self, other: &[A]) -> Vec<A>
+    where
+        A: PartialEq + Ord + Hash,
+    {
         intersect(self, other)
     }
+
+    fn intersect_by<K, F>(&self, other: &[A], iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        intersect_by(self, other, iteratee)
+    }
+
+    fn intersect_with<F>(&self, other: &[A], comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        intersect_with(self, other, comparator)
+    }
 }
 
 impl<A, const N: usize> Intersect<[A; N], A> for [A; N]
 where
-    A: PartialEq + Ord + Clone + Hash,
+    A: Clone,
 {
-    fn intersect(&self, other: &[A]) -> Vec<A> {
+    fn intersect(&self, other: &[A]) -> Vec<A>
+    where
+        A: PartialEq + Ord + Hash,
+    {
         intersect(self, other)
     }
+
+    fn intersect_by<K, F>(&self, other: &[A], iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        intersect_by(self, other, iteratee)
+    }
+
+    fn intersect_with<F>(&self, other: &[A], comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        intersect_with(self, other, comparator)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::user;
 
     #[test]
     fn can_return_intersection_of_two_arrays() {
@@ -81,4 +241,39 @@ mod tests {
 
         assert_eq!([2, 1].intersect(&[2, 3],), [2]);
     }
+
+    #[test]
+    fn can_keep_order_and_first_occurrences() {
+        assert_eq!(intersect(&[3, 1, 3, 2, 1], &[1, 2, 3, 4]), [3, 1, 2]);
+        assert_eq!(intersect(&[1, 1, 2], &[1, 1, 1]), [1]);
+        assert_eq!(intersect(&["b", "a"], &["a", "b", "a"]), ["b", "a"]);
+        assert_eq!(intersect(&[1, 2], &[]), [0; 0]);
+    }
+
+    #[test]
+    fn can_return_deterministic_results() {
+        let array = (0..1000).rev().map(|n| n % 250).collect::<Vec<_>>();
+        let other = (0..500).collect::<Vec<_>>();
+        let expected = (0..250).rev().collect::<Vec<_>>();
+
+        for _ in 0..10 {
+            assert_eq!(intersect(&array, &other), expected);
+        }
+    }
+
+    #[test]
+    fn can_keep_first_occurrence_of_each_key_or_match() {
+        let users = [user(3, "pebbles"), user(1, "fred"), user(3, "Fred")];
+        let others = [user(1, "FRED"), user(3, "betty")];
+
+        assert_eq!(
+            intersect_by(&users, &others, |user| user.id),
+            [user(3, "pebbles"), user(1, "fred")]
+        );
+        assert_eq!(
+            users.intersect_with(&others, |a, b| a.name.eq_ignore_ascii_case(b.name)),
+            [user(1, "fred")]
+        );
+        assert_eq!(vec![2, 1].intersect_by(&[], |n| *n), [0; 0]);
+    }
 }
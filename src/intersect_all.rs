@@ -1,11 +1,17 @@
-use crate::intersect;
-use std::hash::Hash;
+use std::{
+    collections::HashMap,
+    hash::Hash,
+};
 
 /// Creates an array of unique values that are included in both the `array` and `others` nested
-/// arrays. The order of result values are determined by the `array`.
+/// arrays, like the lodash `_.intersection` function. The order of result values are determined
+/// by the `array`, in which only the first occurrence of each value is kept. The values of
+/// `array` are counted in a single map as the `others` arrays are scanned, so this takes time
+/// linear in the total length of the arrays, however many there are. See
+/// [intersect_quorum](crate::intersect_quorum) for values included in only some of the arrays.
 ///
 /// * `array` - The source array.
-/// * `others` - The nested arrays to inspect.
+/// * `others` - The nested arrays to inspect, such as a vector of slices or of vectors.
 ///
 /// # Examples
 ///
@@ -14,30 +20,146 @@ use std::hash::Hash;
 ///
 /// let result = intersect_all(&[2, 1, 2, 3], vec![&[3, 4], &[3, 2]]);
 /// assert_eq!(result, [3]);
+///
+/// let others = vec![vec![1, 2, 3], vec![3, 2]];
+/// assert_eq!(intersect_all(&[2, 1, 2, 3], &others), [2, 3]);
 /// ```
-pub fn intersect_all<A>(array: &[A], others: Vec<&[A]>) -> Vec<A>
+pub fn intersect_all<A, O>(array: &[A], others: O) -> Vec<A>
 where
     A: PartialEq + Ord + Clone + Hash,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
 {
-    let mut common = array.to_vec();
+    // The number of arrays of `others`, from the first one, that each value is included in.
+    let mut counts: HashMap<&A, usize> = array.iter().map(|item| (item, 0)).collect();
+
+    for (index, other) in others.into_iter().enumerate() {
+        for item in other.as_ref() {
+            if let Some(count) = counts.get_mut(item) {
+                if *count == index {
+                    *count += 1;
+                }
+            }
+        }
+
+        // Values missing from an array are dropped, so that later arrays probe a smaller map.
+        counts.retain(|_, count| *count > index);
 
-    for item in others {
-        common = intersect(&common, item);
+        if counts.is_empty() {
+            break;
+        }
     }
 
-    common
+    array
+        .iter()
+        .filter(|item| counts.remove(item).is_some())
+        .cloned()
+        .collect()
+}
+
+/// This method is like [intersect_all] except that it accepts `iteratee` which is invoked for
+/// each value of `array` and `others` to generate the key by which they are compared. Only the
+/// first value of `array` with each key is kept, and the keys are counted in a hash map, so the
+/// values themselves only need to be cloneable.
+///
+/// * `array` - The source array.
+/// * `others` - The nested arrays to inspect, such as a vector of slices or of vectors.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::intersect_all_by;
+///
+/// let others = [vec![2.3, 3.4], vec![2.9, 1.0]];
+/// let result = intersect_all_by(&[2.1, 1.2, 2.5], &others, |n: &f64| n.floor() as i64);
+/// assert_eq!(result, [2.1]);
+/// ```
+pub fn intersect_all_by<A, O, K, F>(array: &[A], others: O, mut iteratee: F) -> Vec<A>
+where
+    A: Clone,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    // The number of arrays of `others`, from the first one, that each key is included in.
+    let mut counts: HashMap<K, usize> = array.iter().map(|item| (iteratee(item), 0)).collect();
+
+    for (index, other) in others.into_iter().enumerate() {
+        for item in other.as_ref() {
+            if let Some(count) = counts.get_mut(&iteratee(item)) {
+                if *count == index {
+                    *count += 1;
+                }
+            }
+        }
+
+        counts.retain(|_, count| *count > index);
+
+        if counts.is_empty() {
+            break;
+        }
+    }
+
+    array
+        .iter()
+        .filter(|item| counts.remove(&iteratee(item)).is_some())
+        .cloned()
+        .collect()
+}
+
+/// This method is like [intersect_all] except that it accepts `comparator` which is invoked to
+/// tell whether two values are equal. It is invoked with a value of `array` and either a value of
+/// `others` or a value already in the result.
+///
+/// * `array` - The source array.
+/// * `others` - The nested arrays to inspect, such as a vector of slices or of vectors.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::intersect_all_with;
+///
+/// let result = intersect_all_with(&["a", "B", "c"], [["b", "A"], ["C", "b"]], |a, b| {
+///     a.eq_ignore_ascii_case(b)
+/// });
+/// assert_eq!(result, ["B"]);
+/// ```
+pub fn intersect_all_with<A, O, F>(array: &[A], others: O, mut comparator: F) -> Vec<A>
+where
+    A: Clone,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
+    F: FnMut(&A, &A) -> bool,
+{
+    let others = others.into_iter().collect::<Vec<_>>();
+    let mut result: Vec<A> = Vec::new();
+
+    for item in array {
+        if !result.iter().any(|value| comparator(item, value))
+            && others
+                .iter()
+                .all(|other| other.as_ref().iter().any(|value| comparator(item, value)))
+        {
+            result.push(item.clone());
+        }
+    }
+
+    result
 }
 
 /// A trait that implements the [IntersectAll::intersect_all] method on arrays.
 pub trait IntersectAll<A, I>
 where
     A: IntoIterator,
-    I: PartialEq + Ord + Clone + Hash,
+    I: Clone,
 {
     /// Creates an array of unique values that are included in both the `array` and `others` nested
     /// arrays. The order of result values are determined by the `array`.
     ///
-    /// * `others` - The nested arrays to inspect.
+    /// * `others` - The nested arrays to inspect, such as a vector of slices or of vectors.
     ///
     /// # Examples
     ///
@@ -46,30 +168,136 @@ where
     ///
     /// assert_eq!([2, 1, 2, 3].intersect_all(vec![&[3, 4], &[3, 2]]), [3]);
     /// ```
-    fn intersect_all(&self, others: Vec<&[I]>) -> Vec<I>;
+    fn intersect_all<O>(&self, others: O) -> Vec<I>
+    where
+        I: PartialEq + Ord + Hash,
+        O: IntoIterator,
+        O::Item: AsRef<[I]>;
+
+    /// This method is like [IntersectAll::intersect_all] except that it accepts `iteratee` which is
+    /// invoked for each value of this array and `others` to generate the key by which they are
+    /// compared.
+    ///
+    /// * `others` - The nested arrays to inspect, such as a vector of slices or of vectors.
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::IntersectAll;
+    ///
+    /// assert_eq!(
+    ///     [2.1, 1.2].intersect_all_by([[2.3], [2.9]], |n: &f64| n.floor() as i64),
+    ///     [2.1]
+    /// );
+    /// ```
+    fn intersect_all_by<O, K, F>(&self, others: O, iteratee: F) -> Vec<I>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[I]>,
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [IntersectAll::intersect_all] except that it accepts `comparator` which
+    /// is invoked to tell whether two values are equal.
+    ///
+    /// * `others` - The nested arrays to inspect, such as a vector of slices or of vectors.
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::IntersectAll;
+    ///
+    /// assert_eq!(
+    ///     ["a", "B"].intersect_all_with([["b"], ["B"]], |a, b| a.eq_ignore_ascii_case(b)),
+    ///     ["B"]
+    /// );
+    /// ```
+    fn intersect_all_with<O, F>(&self, others: O, comparator: F) -> Vec<I>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[I]>,
+        F: FnMut(&I, &I) -> bool;
 }
 
 impl<A> IntersectAll<Vec<A>, A> for Vec<A>
 where
-    A: PartialEq + Ord + Clone + Hash,
+    A: Clone,
 {
-    fn intersect_all(&self, others: Vec<&[A]>) -> Vec<A> {
+    fn intersect_all<O>(&self, others: O) -> Vec<A>
+    where
+        A: PartialEq + Ord + Hash,
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+    {
         intersect_all(self, others)
     }
+
+    fn intersect_all_by<O, K, F>(&self, others: O, iteratee: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        intersect_all_by(self, others, iteratee)
+    }
+
+    fn intersect_all_with<O, F>(&self, others: O, comparator: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        F: FnMut(&A, &A) -> bool,
+    {
+        intersect_all_with(self, others, comparator)
+    }
 }
 
 impl<A, const N: usize> IntersectAll<[A; N], A> for [A; N]
 where
-    A: PartialEq + Ord + Clone + Hash,
+    A: Clone,
 {
-    fn intersect_all(&self, others: Vec<&[A]>) -> Vec<A> {
+    fn intersect_all<O>(&self, others: O) -> Vec<A>
+    where
+        A: PartialEq + Ord + Hash,
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+    {
         intersect_all(self, others)
     }
+
+    fn intersect_all_by<O, K, F>(&self, others: O, iteratee: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        intersect_all_by(self, others, iteratee)
+    }
+
+    fn intersect_all_with<O, F>(&self, others: O, comparator: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        F: FnMut(&A, &A) -> bool,
+    {
+        intersect_all_with(self, others, comparator)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        intersect,
+        test_helpers::{
+            user,
+            User,
+        },
+    };
+    use quickcheck::quickcheck;
 
     #[test]
     fn can_return_intersection_of_multiple_arrays() {
@@ -80,4 +308,67 @@ mod tests {
 
         assert_eq!([2, 1, 2, 3].intersect_all(vec![&[3, 4], &[3, 2]],), [3]);
     }
+
+    #[test]
+    fn can_accept_any_nested_arrays() {
+        let others = [vec![3, 2, 1], vec![2, 3]];
+        assert_eq!(intersect_all(&[3, 1, 2], others.iter()), [3, 2]);
+        assert_eq!(intersect_all(&[3, 1, 2], others), [3, 2]);
+
+        let values = [1, 2, 3, 2, 3, 4];
+        assert_eq!(intersect_all(&[4, 3, 2], values.chunks(3)), [3, 2]);
+
+        assert_eq!(intersect_all(&[2, 1, 2], Vec::<&[i32]>::new()), [2, 1]);
+    }
+
+    #[test]
+    fn can_accept_slices_of_different_lengths() {
+        // A `vec!` of array references with different lengths no longer coerces to slices, as the
+        // element type is now inferred from the first reference, so the slice type is annotated.
+        let others: Vec<&[i32]> = vec![&[1, 2], &[2, 3, 1]];
+        assert_eq!(intersect_all(&[1, 2, 3], others), [1, 2]);
+    }
+
+    #[test]
+    fn can_intersect_hundreds_of_arrays() {
+        let others = (0..500)
+            .map(|n| (0..1000).filter(|m| m % 2 == 0 || m % 500 != n).collect())
+            .collect::<Vec<Vec<_>>>();
+        let array = (0..1000).rev().collect::<Vec<_>>();
+        let expected = (0..1000).rev().filter(|n| n % 2 == 0).collect::<Vec<_>>();
+
+        assert_eq!(intersect_all(&array, &others), expected);
+    }
+
+    quickcheck! {
+        fn matches_repeated_intersect(array: Vec<u8>, others: Vec<Vec<u8>>) -> bool {
+            let expected = others
+                .iter()
+                .fold(intersect(&array, &array), |common, other| intersect(&common, other));
+
+            intersect_all(&array, &others) == expected
+        }
+    }
+
+    #[test]
+    fn can_keep_keys_or_matches_present_in_every_nested_array() {
+        let users = [user(3, "pebbles"), user(1, "fred"), user(3, "betty")];
+        let others = [
+            vec![user(1, "PEBBLES"), user(3, "b")],
+            vec![user(3, "Pebbles")],
+        ];
+
+        assert_eq!(
+            intersect_all_by(&users, &others, |user| user.id),
+            [user(3, "pebbles")]
+        );
+        assert_eq!(
+            users.intersect_all_with(&others, |a, b| a.name.eq_ignore_ascii_case(b.name)),
+            [user(3, "pebbles")]
+        );
+        assert_eq!(
+            users.intersect_all_by(Vec::<Vec<User>>::new(), |user| user.id),
+            [user(3, "pebbles"), user(1, "fred")]
+        );
+    }
 }
@@ -0,0 +1,129 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+};
+
+/// Creates an array of the values of `array` that are also included in the `other` array, taking
+/// duplicates into account, so that a value occurs in the result as many times as it occurs in
+/// the array where it occurs the fewest times. The order of result values are determined by the
+/// `array`, in which the first occurrences of each value are kept.
+///
+/// * `array` - The source array.
+/// * `other` - The array to inspect.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::intersect_multiset;
+///
+/// assert_eq!(intersect_multiset(&[1, 1, 2], &[1, 1, 1]), [1, 1]);
+/// assert_eq!(intersect_multiset(&[3, 1, 3, 3, 2], &[3, 2, 3]), [3, 3, 2]);
+/// ```
+pub fn intersect_multiset<A>(array: &[A], other: &[A]) -> Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    let mut counts = HashMap::<&A, usize>::with_capacity(other.len());
+
+    for item in other {
+        *counts.entry(item).or_default() += 1;
+    }
+
+    array
+        .iter()
+        .filter(|item| {
+            counts.get_mut(item).is_some_and(|count| {
+                let is_included = *count > 0;
+                *count = count.saturating_sub(1);
+
+                is_included
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+/// A trait that implements the [IntersectMultiset::intersect_multiset] method on arrays.
+pub trait IntersectMultiset<A, I>
+where
+    A: IntoIterator,
+    I: Hash + Eq + Clone,
+{
+    /// Creates an array of the values of this array that are also included in the `other` array,
+    /// taking duplicates into account. The order of result values are determined by this array.
+    ///
+    /// * `other` - The array to inspect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::IntersectMultiset;
+    ///
+    /// assert_eq!([1, 1, 2].intersect_multiset(&[1, 1, 1]), [1, 1]);
+    /// ```
+    fn intersect_multiset(&self, other: &[I]) -> Vec<I>;
+}
+
+impl<A> IntersectMultiset<Vec<A>, A> for Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    fn intersect_multiset(&self, other: &[A]) -> Vec<A> {
+        intersect_multiset(self, other)
+    }
+}
+
+impl<A, const N: usize> IntersectMultiset<[A; N], A> for [A; N]
+where
+    A: Hash + Eq + Clone,
+{
+    fn intersect_multiset(&self, other: &[A]) -> Vec<A> {
+        intersect_multiset(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_return_multiset_intersection_of_two_arrays() {
+        let result = intersect_multiset(&[1, 1, 2], &[1, 1, 1]);
+        assert_eq!(result, [1, 1]);
+
+        assert_eq!(vec![1, 1, 2].intersect_multiset(&[2, 1]), [1, 2]);
+
+        assert_eq!([1, 1, 2].intersect_multiset(&[]), [0; 0]);
+    }
+
+    #[test]
+    fn can_return_deterministic_results() {
+        let array = (0..1000).rev().map(|n| n % 250).collect::<Vec<_>>();
+        let other = (0..500).map(|n| n % 250).collect::<Vec<_>>();
+        let expected = (500..1000).rev().map(|n| n % 250).collect::<Vec<_>>();
+
+        for _ in 0..10 {
+            assert_eq!(intersect_multiset(&array, &other), expected);
+        }
+    }
+
+    quickcheck! {
+        fn keeps_the_fewest_occurrences(array: Vec<u8>, other: Vec<u8>) -> bool {
+            let result = intersect_multiset(&array, &other);
+            let count = |values: &[u8], value: u8| values.iter().filter(|v| **v == value).count();
+
+            (0..=u8::MAX).all(|value| {
+                count(&result, value) == count(&array, value).min(count(&other, value))
+            })
+        }
+
+        fn is_a_subsequence_of_array(array: Vec<u8>, other: Vec<u8>) -> bool {
+            let mut array = array.iter();
+
+            intersect_multiset(array.as_slice(), &other)
+                .iter()
+                .all(|value| array.any(|v| v == value))
+        }
+    }
+}
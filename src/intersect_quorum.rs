@@ -0,0 +1,128 @@
+use crate::occurrences::occurrences_by;
+use std::{
+    hash::Hash,
+    iter,
+};
+
+/// Creates an array of unique values that are included in at least `quorum` of the arrays made of
+/// `array` and the `others` nested arrays. The order of result values are determined by the
+/// order in which they first occur in `array`, followed by `others`. A `quorum` of the number of
+/// arrays gives the values included in all of them, as with [intersect_all], and a `quorum` of
+/// one or zero gives the values included in any of them.
+///
+/// * `array` - The source array.
+/// * `others` - The nested arrays to inspect, such as a vector of slices or of vectors.
+/// * `quorum` - The minimum number of arrays that result values are included in.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::intersect_quorum;
+///
+/// let others = vec![vec![3, 4], vec![3, 4, 5]];
+/// assert_eq!(intersect_quorum(&[1, 2, 3], &others, 3), [3]);
+/// assert_eq!(intersect_quorum(&[1, 2, 3], &others, 2), [3, 4]);
+/// assert_eq!(intersect_quorum(&[1, 2, 3], &others, 1), [1, 2, 3, 4, 5]);
+/// ```
+///
+/// [intersect_all]: crate::intersect_all
+pub fn intersect_quorum<A, O>(array: &[A], others: O, quorum: usize) -> Vec<A>
+where
+    A: Hash + Eq + Clone,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
+{
+    let others = others.into_iter().collect::<Vec<_>>();
+    let arrays = iter::once(array).chain(others.iter().map(AsRef::as_ref));
+
+    occurrences_by(arrays, |item| item)
+        .into_iter()
+        .filter(|(_, count)| *count >= quorum)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// A trait that implements the [IntersectQuorum::intersect_quorum] method on arrays.
+pub trait IntersectQuorum<A, I>
+where
+    A: IntoIterator,
+    I: Hash + Eq + Clone,
+{
+    /// Creates an array of unique values that are included in at least `quorum` of the arrays made
+    /// of this array and the `others` nested arrays. The order of result values are determined by
+    /// the order in which they first occur in this array, followed by `others`.
+    ///
+    /// * `others` - The nested arrays to inspect, such as a vector of slices or of vectors.
+    /// * `quorum` - The minimum number of arrays that result values are included in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::IntersectQuorum;
+    ///
+    /// assert_eq!([1, 2].intersect_quorum(vec![&[2, 3], &[3, 4]], 2), [2, 3]);
+    /// ```
+    fn intersect_quorum<O>(&self, others: O, quorum: usize) -> Vec<I>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[I]>;
+}
+
+impl<A> IntersectQuorum<Vec<A>, A> for Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    fn intersect_quorum<O>(&self, others: O, quorum: usize) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+    {
+        intersect_quorum(self, others, quorum)
+    }
+}
+
+impl<A, const N: usize> IntersectQuorum<[A; N], A> for [A; N]
+where
+    A: Hash + Eq + Clone,
+{
+    fn intersect_quorum<O>(&self, others: O, quorum: usize) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+    {
+        intersect_quorum(self, others, quorum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intersect_all;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_return_values_in_a_quorum_of_arrays() {
+        let others: Vec<&[i32]> = vec![&[3, 4], &[4, 3, 5]];
+        let result = intersect_quorum(&[1, 2, 3], others, 2);
+        assert_eq!(result, [3, 4]);
+
+        assert_eq!(
+            vec![1, 1].intersect_quorum(vec![&[1, 2], &[2, 2]], 2),
+            [1, 2]
+        );
+
+        assert_eq!([1, 2].intersect_quorum(vec![&[2]], 3), [0; 0]);
+    }
+
+    #[test]
+    fn can_count_duplicates_once_per_array() {
+        assert_eq!(intersect_quorum(&[1, 1, 1], vec![&[2, 2]], 2), [0; 0]);
+        assert_eq!(intersect_quorum(&[1, 1], Vec::<&[i32]>::new(), 0), [1]);
+    }
+
+    quickcheck! {
+        fn matches_intersect_all_with_full_quorum(array: Vec<u8>, others: Vec<Vec<u8>>) -> bool {
+            intersect_quorum(&array, &others, others.len() + 1) == intersect_all(&array, &others)
+        }
+    }
+}
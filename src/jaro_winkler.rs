@@ -0,0 +1,181 @@
+/// Computes the [Jaro similarity] between the `a` and `b` arrays, from 0 for arrays without
+/// elements in common to 1 for equal arrays. Elements match when they are equal and not farther
+/// apart than half the length of the longer array.
+///
+/// * `a` - The first array to compare.
+/// * `b` - The second array to compare.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::jaro;
+///
+/// assert_eq!(jaro(b"abc", b"abc"), 1.0);
+/// assert_eq!(jaro(b"abc", b"xyz"), 0.0);
+/// assert!((jaro(b"MARTHA", b"MARHTA") - 0.944).abs() < 0.001);
+/// ```
+///
+/// [Jaro similarity]: https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance
+pub fn jaro<A>(a: &[A], b: &[A]) -> f64
+where
+    A: PartialEq,
+{
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut is_matched = vec![false; b.len()];
+    let mut a_matches = Vec::new();
+
+    for (i, x) in a.iter().enumerate() {
+        let range = i.saturating_sub(window)..(i + window + 1).min(b.len());
+
+        if let Some(j) = range.into_iter().find(|&j| !is_matched[j] && b[j] == *x) {
+            is_matched[j] = true;
+            a_matches.push(x);
+        }
+    }
+
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b
+        .iter()
+        .zip(&is_matched)
+        .filter_map(|(y, is_matched)| is_matched.then_some(y));
+    let half_transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(x, y)| *x != y)
+        .count();
+    let matches = a_matches.len() as f64;
+    let transpositions = half_transpositions as f64 / 2.0;
+
+    (matches / a.len() as f64 + matches / b.len() as f64 + (matches - transpositions) / matches)
+        / 3.0
+}
+
+/// Computes the [Jaro-Winkler similarity] between the `a` and `b` arrays, which is like the
+/// [jaro] similarity except that it favors arrays with a common prefix of up to 4 elements, as
+/// typos are less common at the start of words.
+///
+/// * `a` - The first array to compare.
+/// * `b` - The second array to compare.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::jaro_winkler;
+///
+/// assert!((jaro_winkler(b"MARTHA", b"MARHTA") - 0.961).abs() < 0.001);
+/// assert!((jaro_winkler(b"DIXON", b"DICKSONX") - 0.813).abs() < 0.001);
+/// ```
+///
+/// [Jaro-Winkler similarity]: https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance
+pub fn jaro_winkler<A>(a: &[A], b: &[A]) -> f64
+where
+    A: PartialEq,
+{
+    let similarity = jaro(a, b);
+    let prefix = a.iter().zip(b).take(4).take_while(|(x, y)| x == y).count();
+
+    similarity + prefix as f64 * 0.1 * (1.0 - similarity)
+}
+
+/// A trait that implements the [JaroWinkler::jaro_winkler] method on strings.
+pub trait JaroWinkler<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Computes the Jaro similarity between the characters of this string and `other`.
+    ///
+    /// * `other` - The string to compare with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::JaroWinkler;
+    ///
+    /// assert!(("DWAYNE".jaro("DUANE") - 0.822).abs() < 0.001);
+    /// ```
+    fn jaro(&self, other: &str) -> f64;
+
+    /// Computes the Jaro-Winkler similarity between the characters of this string and `other`.
+    ///
+    /// * `other` - The string to compare with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::JaroWinkler;
+    ///
+    /// assert!(("DWAYNE".jaro_winkler("DUANE") - 0.84).abs() < 0.001);
+    /// ```
+    fn jaro_winkler(&self, other: &str) -> f64;
+}
+
+impl JaroWinkler<String> for String {
+    fn jaro(&self, other: &str) -> f64 {
+        self.as_str().jaro(other)
+    }
+
+    fn jaro_winkler(&self, other: &str) -> f64 {
+        self.as_str().jaro_winkler(other)
+    }
+}
+
+impl JaroWinkler<str> for str {
+    fn jaro(&self, other: &str) -> f64 {
+        jaro(
+            &self.chars().collect::<Vec<_>>(),
+            &other.chars().collect::<Vec<_>>(),
+        )
+    }
+
+    fn jaro_winkler(&self, other: &str) -> f64 {
+        jaro_winkler(
+            &self.chars().collect::<Vec<_>>(),
+            &other.chars().collect::<Vec<_>>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    /// Returns `true` if `a` and `b` are equal to 3 decimal places.
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 0.0005
+    }
+
+    #[test]
+    fn can_compute_jaro_similarities() {
+        assert_eq!(jaro::<u8>(&[], &[]), 1.0);
+        assert_eq!(jaro(b"", b"a"), 0.0);
+        assert!(is_close(jaro(b"DIXON", b"DICKSONX"), 0.767));
+        assert!(is_close(jaro(b"CRATE", b"TRACE"), 0.733));
+        assert!(is_close("ab".jaro("ba"), 0.0));
+        assert!(is_close("résumé".to_string().jaro("resume"), 0.778));
+    }
+
+    #[test]
+    fn can_compute_jaro_winkler_similarities() {
+        assert_eq!(jaro_winkler(b"same", b"same"), 1.0);
+        assert!(is_close(jaro_winkler(b"TRATE", b"TRACE"), 0.907));
+        assert!(is_close(jaro_winkler(b"abcdefgh", b"abcdxxxx"), 0.8));
+        assert!(is_close("JONES".jaro_winkler("JOHNSON"), 0.832));
+        assert!(is_close("résumé".to_string().jaro_winkler("resume"), 0.800));
+    }
+
+    quickcheck! {
+        fn similarities_are_symmetric_and_bounded(a: Vec<u8>, b: Vec<u8>) -> bool {
+            let (similarity, reversed) = (jaro_winkler(&a, &b), jaro_winkler(&b, &a));
+
+            is_close(similarity, reversed) && (0.0..=1.0).contains(&similarity)
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to kebab case, like the lodash `_.kebabCase` function. The [words] of `value`
+/// are lowercased and joined with "-".
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::kebab_case;
+///
+/// assert_eq!(kebab_case("Foo Bar"), "foo-bar".to_string());
+/// assert_eq!(kebab_case("fooBar"), "foo-bar".to_string());
+/// assert_eq!(kebab_case("__FOO_BAR__"), "foo-bar".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn kebab_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Lower,
+        WordCase::Lower,
+        "-",
+        AcronymMode::Normalize,
+    )
+}
+
+/// A trait that implements the [KebabCase::kebab_case] method on strings.
+pub trait KebabCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to kebab case, like the lodash `_.kebabCase` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::KebabCase;
+    ///
+    /// assert_eq!("fooBar".kebab_case(), "foo-bar".to_string());
+    /// ```
+    fn kebab_case(&self) -> String;
+}
+
+impl KebabCase<String> for String {
+    fn kebab_case(&self) -> String {
+        kebab_case(self)
+    }
+}
+
+impl KebabCase<str> for str {
+    fn kebab_case(&self) -> String {
+        kebab_case(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_kebab_case() {
+        assert_eq!(kebab_case("fooBar"), "foo-bar".to_string());
+        assert_eq!(
+            "XMLHttpRequest".kebab_case(),
+            "xml-http-request".to_string()
+        );
+        assert_eq!(
+            "version2Update".to_string().kebab_case(),
+            "version-2-update".to_string()
+        );
+        assert_eq!(kebab_case("don't stop"), "dont-stop".to_string());
+        assert_eq!("  ".kebab_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().kebab_case(),
+            "élan-vital".to_string()
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use std::mem;
+
+/// Computes the edit distance between `a` and `b`, counting insertions, deletions, substitutions
+/// and, if `transpositions` is `true`, transpositions of adjacent elements that are not edited
+/// again. Returns `None` as soon as the distance is known to exceed `max_distance`.
+pub(crate) fn edit_distance<A>(
+    a: &[A],
+    b: &[A],
+    max_distance: usize,
+    transpositions: bool,
+) -> Option<usize>
+where
+    A: PartialEq,
+{
+    // Common prefixes and suffixes never need edits.
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+    // The rows of the distance matrix run along the longer sequence, so that they are short.
+    let (a, b) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    if b.len() - a.len() > max_distance {
+        return None;
+    }
+
+    let mut before_previous = vec![0; a.len() + 1];
+    let mut previous = (0..=a.len()).collect::<Vec<_>>();
+    let mut current = vec![0; a.len() + 1];
+    let mut previous_min = 0;
+
+    for (j, y) in b.iter().enumerate() {
+        current[0] = j + 1;
+        let mut current_min = current[0];
+
+        for (i, x) in a.iter().enumerate() {
+            let mut distance = (previous[i] + usize::from(x != y))
+                .min(previous[i + 1] + 1)
+                .min(current[i] + 1);
+
+            if transpositions && i > 0 && j > 0 && *x == b[j - 1] && a[i - 1] == *y {
+                distance = distance.min(before_previous[i - 1] + 1);
+            }
+
+            current[i + 1] = distance;
+            current_min = current_min.min(distance);
+        }
+
+        // Every alignment goes through each row, except that a transposition skips one row.
+        if current_min > max_distance && (!transpositions || previous_min > max_distance) {
+            return None;
+        }
+
+        previous_min = current_min;
+        mem::swap(&mut before_previous, &mut previous);
+        mem::swap(&mut previous, &mut current);
+    }
+
+    let distance = previous[a.len()];
+
+    (distance <= max_distance).then_some(distance)
+}
+
+/// Computes the [Levenshtein distance] between the `a` and `b` arrays, which is the minimum number
+/// of insertions, deletions and substitutions of elements that turn one into the other.
+///
+/// * `a` - The first array to compare.
+/// * `b` - The second array to compare.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::levenshtein;
+///
+/// assert_eq!(levenshtein(b"kitten", b"sitting"), 3);
+/// assert_eq!(levenshtein(&[1, 2, 3], &[1, 3]), 1);
+/// ```
+///
+/// [Levenshtein distance]: https://en.wikipedia.org/wiki/Levenshtein_distance
+pub fn levenshtein<A>(a: &[A], b: &[A]) -> usize
+where
+    A: PartialEq,
+{
+    edit_distance(a, b, usize::MAX, false).unwrap_or(usize::MAX)
+}
+
+/// This method is like [levenshtein] except that it returns `None` as soon as the distance is
+/// known to exceed `max_distance`, which is much faster than computing large distances.
+///
+/// * `a` - The first array to compare.
+/// * `b` - The second array to compare.
+/// * `max_distance` - The maximum distance of interest.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::levenshtein_within;
+///
+/// assert_eq!(levenshtein_within(b"kitten", b"sitting", 3), Some(3));
+/// assert_eq!(levenshtein_within(b"kitten", b"sitting", 2), None);
+/// ```
+pub fn levenshtein_within<A>(a: &[A], b: &[A], max_distance: usize) -> Option<usize>
+where
+    A: PartialEq,
+{
+    edit_distance(a, b, max_distance, false)
+}
+
+/// A trait that implements the [Levenshtein::levenshtein] method on strings.
+pub trait Levenshtein<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Computes the Levenshtein distance between the characters of this string and `other`.
+    ///
+    /// * `other` - The string to compare with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Levenshtein;
+    ///
+    /// assert_eq!("kitten".levenshtein("sitting"), 3);
+    /// assert_eq!("日本語".levenshtein("日本"), 1);
+    /// ```
+    fn levenshtein(&self, other: &str) -> usize;
+
+    /// This method is like [Levenshtein::levenshtein] except that it returns `None` as soon as
+    /// the distance is known to exceed `max_distance`.
+    ///
+    /// * `other` - The string to compare with.
+    /// * `max_distance` - The maximum distance of interest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Levenshtein;
+    ///
+    /// assert_eq!("kitten".levenshtein_within("sitting", 1), None);
+    /// ```
+    fn levenshtein_within(&self, other: &str, max_distance: usize) -> Option<usize>;
+}
+
+impl Levenshtein<String> for String {
+    fn levenshtein(&self, other: &str) -> usize {
+        self.as_str().levenshtein(other)
+    }
+
+    fn levenshtein_within(&self, other: &str, max_distance: usize) -> Option<usize> {
+        self.as_str().levenshtein_within(other, max_distance)
+    }
+}
+
+impl Levenshtein<str> for str {
+    fn levenshtein(&self, other: &str) -> usize {
+        levenshtein(
+            &self.chars().collect::<Vec<_>>(),
+            &other.chars().collect::<Vec<_>>(),
+        )
+    }
+
+    fn levenshtein_within(&self, other: &str, max_distance: usize) -> Option<usize> {
+        levenshtein_within(
+            &self.chars().collect::<Vec<_>>(),
+            &other.chars().collect::<Vec<_>>(),
+            max_distance,
+        )
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    /// Computes the edit distance between `a` and `b` with the full distance matrix.
+    pub(crate) fn naive_edit_distance(a: &[u8], b: &[u8], transpositions: bool) -> usize {
+        let mut matrix = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[0] = i;
+        }
+
+        for (j, cell) in matrix[0].iter_mut().enumerate() {
+            *cell = j;
+        }
+
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                let mut distance = (matrix[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]))
+                    .min(matrix[i - 1][j] + 1)
+                    .min(matrix[i][j - 1] + 1);
+
+                if transpositions && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1]
+                {
+                    distance = distance.min(matrix[i - 2][j - 2] + 1);
+                }
+
+                matrix[i][j] = distance;
+            }
+        }
+
+        matrix[a.len()][b.len()]
+    }
+
+    /// Maps arbitrary bytes to a small alphabet, so that random arrays share elements.
+    pub(crate) fn small_alphabet(bytes: Vec<u8>) -> Vec<u8> {
+        bytes.into_iter().map(|byte| b'a' + byte % 4).collect()
+    }
+
+    #[test]
+    fn can_compute_levenshtein_distances() {
+        assert_eq!(levenshtein(b"", b""), 0);
+        assert_eq!(levenshtein(b"abc", b""), 3);
+        assert_eq!(levenshtein(b"", b"abc"), 3);
+        assert_eq!(levenshtein(b"flaw", b"lawn"), 2);
+        assert_eq!(levenshtein(b"ab", b"ba"), 2);
+        assert_eq!("Saturday".levenshtein("Sunday"), 3);
+        assert_eq!("café".to_string().levenshtein("cafe"), 1);
+    }
+
+    #[test]
+    fn can_stop_at_the_maximum_distance() {
+        assert_eq!(levenshtein_within(b"abc", b"abc", 0), Some(0));
+        assert_eq!(levenshtein_within(b"abc", b"abd", 0), None);
+        assert_eq!(levenshtein_within(b"a", b"abcdef", 4), None);
+        assert_eq!("flaw".levenshtein_within("lawn", 2), Some(2));
+        assert_eq!("flaw".to_string().levenshtein_within("lawn", 1), None);
+    }
+
+    quickcheck! {
+        fn levenshtein_matches_the_full_matrix(a: Vec<u8>, b: Vec<u8>) -> bool {
+            let (a, b) = (small_alphabet(a), small_alphabet(b));
+
+            levenshtein(&a, &b) == naive_edit_distance(&a, &b, false)
+        }
+
+        fn bounded_levenshtein_matches_unbounded(a: Vec<u8>, b: Vec<u8>, max: u8) -> bool {
+            let (a, b, max) = (small_alphabet(a), small_alphabet(b), usize::from(max % 8));
+            let distance = levenshtein(&a, &b);
+
+            levenshtein_within(&a, &b, max) == (distance <= max).then_some(distance)
+        }
+    }
+}
@@ -11,34 +11,153 @@
 #![forbid(unsafe_code)]
 #![warn(future_incompatible, missing_docs)]
 
+mod camel_case;
+mod case;
+mod closest_matches;
+mod codec;
+mod collapse_whitespace;
+mod constant_case;
+mod damerau_levenshtein;
+mod deburr;
+mod decode_uri;
+mod dedent;
 mod difference;
 mod difference_all;
+mod difference_all_hashed;
+mod difference_hashed;
+mod difference_sorted;
+mod dot_case;
+mod encode_uri;
+mod entities;
 mod escape;
+mod escape_csv_field;
+mod escape_js_string;
+mod escape_reg_exp;
+mod escape_writer;
+mod escape_xml;
+mod indent;
 mod index_of;
 mod initial;
 mod intersect;
 mod intersect_all;
+mod intersect_multiset;
+mod intersect_quorum;
+mod jaro_winkler;
+mod kebab_case;
+mod levenshtein;
+mod lower_case;
 mod mean;
+mod occurrences;
+mod pad;
+mod pad_end;
+mod pad_start;
+mod pascal_case;
+mod path_case;
 mod pull;
 mod pull_all;
+mod repeat;
 mod sample;
+mod sentence_case;
+mod shell_quote;
+mod shell_split;
 mod shuffle;
+mod slugify;
+mod snake_case;
+mod start_case;
 mod tail;
+mod template;
+#[cfg(test)]
+mod test_helpers;
+mod transliterate;
+mod transliterations;
+mod trim;
+mod trim_end;
+mod trim_lines;
+mod trim_margin;
+mod trim_start;
+mod truncate;
 mod unescape;
+mod unescape_csv_field;
+mod unescape_js_string;
+mod unescape_reader;
+mod unescape_xml;
+mod union;
+mod union_all;
 mod uniq;
+mod upper_case;
+mod words;
+mod wrap;
+mod xor;
+mod xor_all;
 
+pub use camel_case::*;
+pub use closest_matches::*;
+pub use collapse_whitespace::*;
+pub use constant_case::*;
+pub use damerau_levenshtein::*;
+pub use deburr::*;
+pub use decode_uri::*;
+pub use dedent::*;
 pub use difference::*;
 pub use difference_all::*;
+pub use difference_all_hashed::*;
+pub use difference_hashed::*;
+pub use difference_sorted::*;
+pub use dot_case::*;
+pub use encode_uri::*;
 pub use escape::*;
+pub use escape_csv_field::*;
+pub use escape_js_string::*;
+pub use escape_reg_exp::*;
+pub use escape_writer::*;
+pub use escape_xml::*;
+pub use indent::*;
 pub use index_of::*;
 pub use initial::*;
 pub use intersect::*;
 pub use intersect_all::*;
+pub use intersect_multiset::*;
+pub use intersect_quorum::*;
+pub use jaro_winkler::*;
+pub use kebab_case::*;
+pub use levenshtein::*;
+pub use lower_case::*;
 pub use mean::*;
+pub use pad::*;
+pub use pad_end::*;
+pub use pad_start::*;
+pub use pascal_case::*;
+pub use path_case::*;
 pub use pull::*;
 pub use pull_all::*;
+pub use repeat::*;
 pub use sample::*;
+pub use sentence_case::*;
+pub use shell_quote::*;
+pub use shell_split::*;
 pub use shuffle::*;
+pub use slugify::*;
+pub use snake_case::*;
+pub use start_case::*;
 pub use tail::*;
+pub use template::*;
+pub use transliterate::*;
+pub use trim::*;
+pub use trim_end::*;
+pub use trim_lines::*;
+pub use trim_margin::*;
+pub use trim_start::*;
+pub use truncate::*;
 pub use unescape::*;
+pub use unescape_csv_field::*;
+pub use unescape_js_string::*;
+pub use unescape_reader::*;
+pub use unescape_xml::*;
+pub use union::*;
+pub use union_all::*;
 pub use uniq::*;
+pub use upper_case::*;
+pub use words::*;
+pub use wrap::*;
+pub use xor::*;
+pub use xor_all::*;
@@ -0,0 +1,89 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to lower case, like the lodash `_.lowerCase` function. The [words] of `value`
+/// are lowercased and joined with spaces.
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::lower_case;
+///
+/// assert_eq!(lower_case("--Foo-Bar--"), "foo bar".to_string());
+/// assert_eq!(lower_case("fooBar"), "foo bar".to_string());
+/// assert_eq!(lower_case("__FOO_BAR__"), "foo bar".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn lower_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Lower,
+        WordCase::Lower,
+        " ",
+        AcronymMode::Normalize,
+    )
+}
+
+/// A trait that implements the [LowerCase::lower_case] method on strings.
+pub trait LowerCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to lower case, like the lodash `_.lowerCase` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::LowerCase;
+    ///
+    /// assert_eq!("fooBar".lower_case(), "foo bar".to_string());
+    /// ```
+    fn lower_case(&self) -> String;
+}
+
+impl LowerCase<String> for String {
+    fn lower_case(&self) -> String {
+        lower_case(self)
+    }
+}
+
+impl LowerCase<str> for str {
+    fn lower_case(&self) -> String {
+        lower_case(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_lower_case() {
+        assert_eq!(lower_case("fooBar"), "foo bar".to_string());
+        assert_eq!(
+            "XMLHttpRequest".lower_case(),
+            "xml http request".to_string()
+        );
+        assert_eq!(
+            "version2Update".to_string().lower_case(),
+            "version 2 update".to_string()
+        );
+        assert_eq!(lower_case("don't stop"), "dont stop".to_string());
+        assert_eq!("  ".lower_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().lower_case(),
+            "élan vital".to_string()
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    collections::{
+        hash_map::Entry,
+        HashMap,
+    },
+    hash::Hash,
+};
+
+/// Returns the unique values of `arrays`, in the order in which they first occur, with the number
+/// of arrays that each of them is included in. Values are compared by the keys returned by
+/// `key`, which are looked up in a hash map, so that this takes time linear in the total length
+/// of the arrays.
+pub(crate) fn occurrences_by<'a, A, I, K, F>(arrays: I, mut key: F) -> Vec<(&'a A, usize)>
+where
+    I: IntoIterator<Item = &'a [A]>,
+    K: Hash + Eq,
+    F: FnMut(&'a A) -> K,
+{
+    // The index in `occurrences` of each key.
+    let mut indices = HashMap::new();
+    // The first occurrence of each value, the number of arrays it is included in and the index of
+    // the last of them.
+    let mut occurrences: Vec<(&A, usize, usize)> = Vec::new();
+
+    for (index, array) in arrays.into_iter().enumerate() {
+        for item in array {
+            match indices.entry(key(item)) {
+                Entry::Occupied(entry) => {
+                    let (_, count, last_index) = &mut occurrences[*entry.get()];
+
+                    if *last_index != index {
+                        *count += 1;
+                        *last_index = index;
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(occurrences.len());
+                    occurrences.push((item, 1, index));
+                }
+            }
+        }
+    }
+
+    occurrences
+        .into_iter()
+        .map(|(item, count, _)| (item, count))
+        .collect()
+}
+
+/// This method is like [occurrences_by] except that values are compared with `comparator`, which
+/// is invoked with a value and the first occurrences of the values found before it. Every value is
+/// compared with every unique value, in `O(n²)` time.
+pub(crate) fn occurrences_with<'a, A, I, F>(arrays: I, mut comparator: F) -> Vec<(&'a A, usize)>
+where
+    A: 'a,
+    I: IntoIterator<Item = &'a [A]>,
+    F: FnMut(&A, &A) -> bool,
+{
+    let mut occurrences: Vec<(&A, usize, usize)> = Vec::new();
+
+    for (index, array) in arrays.into_iter().enumerate() {
+        for item in array {
+            match occurrences
+                .iter_mut()
+                .find(|(value, ..)| comparator(item, value))
+            {
+                Some((_, count, last_index)) => {
+                    if *last_index != index {
+                        *count += 1;
+                        *last_index = index;
+                    }
+                }
+                None => occurrences.push((item, 1, index)),
+            }
+        }
+    }
+
+    occurrences
+        .into_iter()
+        .map(|(item, count, _)| (item, count))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_count_occurrences_once_per_array() {
+        let arrays: [&[i32]; 3] = [&[3, 1, 3], &[1, 2, 1], &[3]];
+        let expected = [(&3, 2), (&1, 2), (&2, 1)];
+
+        assert_eq!(occurrences_by(arrays, |item| item), expected);
+        assert_eq!(occurrences_with(arrays, |a, b| a == b), expected);
+    }
+
+    #[test]
+    fn can_count_occurrences_by_key() {
+        let arrays: [&[&str]; 2] = [&["a", "A"], &["b", "a"]];
+
+        assert_eq!(
+            occurrences_by(arrays, |item| item.to_lowercase()),
+            [(&"a", 2), (&"b", 1)]
+        );
+        assert_eq!(
+            occurrences_with(arrays, |a, b| a.eq_ignore_ascii_case(b)),
+            [(&"a", 2), (&"b", 1)]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use std::iter;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Returns padding that is `width` columns wide, made of the grapheme clusters of `chars`
+/// repeated and cut at a cluster boundary. The columns that the next cluster of `chars` is too
+/// wide to fit in are filled with spaces. The padding is empty if `chars` takes no columns.
+pub(crate) fn padding(width: usize, chars: &str) -> String {
+    let mut padding = String::new();
+
+    if width == 0 || chars.width() == 0 {
+        return padding;
+    }
+
+    let mut remaining = width;
+
+    for grapheme in chars.graphemes(true).cycle() {
+        let grapheme_width = grapheme.width();
+
+        if grapheme_width > remaining {
+            break;
+        }
+
+        padding.push_str(grapheme);
+        remaining -= grapheme_width;
+
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    padding.extend(iter::repeat_n(' ', remaining));
+
+    padding
+}
+
+/// Pads `value` on the left and right sides if it is narrower than `width`, like the lodash
+/// `_.pad` function. Widths are measured in columns of a monospace terminal, in which East Asian
+/// wide characters take two columns, and `chars` is only ever cut between grapheme clusters.
+/// Padding characters are truncated if they cannot be evenly divided by the padding width.
+///
+/// * `value` - The string to pad.
+/// * `width` - The padded width.
+/// * `chars` - The string used as padding.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::pad;
+///
+/// assert_eq!(pad("abc", 8, " "), "  abc   ".to_string());
+/// assert_eq!(pad("abc", 8, "_-"), "_-abc_-_".to_string());
+/// assert_eq!(pad("abc", 3, " "), "abc".to_string());
+/// assert_eq!(pad("日本", 8, "*"), "**日本**".to_string());
+/// ```
+pub fn pad<S>(value: S, width: usize, chars: &str) -> String
+where
+    S: AsRef<str>,
+{
+    let value = value.as_ref();
+    let total = width.saturating_sub(value.width());
+
+    [
+        padding(total / 2, chars).as_str(),
+        value,
+        padding(total - total / 2, chars).as_str(),
+    ]
+    .concat()
+}
+
+/// A trait that implements the [Pad::pad] method on strings.
+pub trait Pad<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Pads this string on the left and right sides if it is narrower than `width` columns, like
+    /// the lodash `_.pad` function.
+    ///
+    /// * `width` - The padded width.
+    /// * `chars` - The string used as padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Pad;
+    ///
+    /// assert_eq!("abc".pad(8, "_-"), "_-abc_-_".to_string());
+    /// ```
+    fn pad(&self, width: usize, chars: &str) -> String;
+}
+
+impl Pad<String> for String {
+    fn pad(&self, width: usize, chars: &str) -> String {
+        pad(self, width, chars)
+    }
+}
+
+impl Pad<str> for str {
+    fn pad(&self, width: usize, chars: &str) -> String {
+        pad(self, width, chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_pad_both_sides() {
+        assert_eq!(pad("abc", 9, " "), "   abc   ".to_string());
+        assert_eq!("abc".pad(6, "_-"), "_abc_-".to_string());
+        assert_eq!("abc".to_string().pad(2, " "), "abc".to_string());
+        assert_eq!(pad("", 3, "ab"), "aab".to_string());
+    }
+
+    #[test]
+    fn can_pad_with_wide_characters() {
+        assert_eq!(pad("x", 7, "日本"), "日 x日 ".to_string());
+        assert_eq!(pad("e\u{301}", 3, "*"), "*e\u{301}*".to_string());
+        assert_eq!(pad("x", 5, "👨‍👩‍👧"), "👨‍👩‍👧x👨‍👩‍👧".to_string());
+    }
+
+    #[test]
+    fn can_ignore_empty_padding() {
+        assert_eq!(pad("abc", 8, ""), "abc".to_string());
+        assert_eq!(pad("abc", 8, "\u{301}"), "abc".to_string());
+    }
+
+    #[test]
+    fn can_build_padding() {
+        assert_eq!(padding(0, "ab"), "".to_string());
+        assert_eq!(padding(5, "ab"), "ababa".to_string());
+        assert_eq!(padding(5, "日本"), "日本 ".to_string());
+        assert_eq!(padding(3, "a\u{301}b"), "a\u{301}ba\u{301}".to_string());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::pad::padding;
+use unicode_width::UnicodeWidthStr;
+
+/// Pads `value` on the right side if it is narrower than `width`, like the lodash
+/// `_.padEnd` function. Widths are measured in columns of a monospace terminal, in which East
+/// Asian wide characters take two columns, and `chars` is only ever cut between grapheme
+/// clusters.
+///
+/// * `value` - The string to pad.
+/// * `width` - The padded width.
+/// * `chars` - The string used as padding.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::pad_end;
+///
+/// assert_eq!(pad_end("abc", 6, " "), "abc   ".to_string());
+/// assert_eq!(pad_end("abc", 6, "_-"), "abc_-_".to_string());
+/// assert_eq!(pad_end("abc", 3, " "), "abc".to_string());
+/// assert_eq!(pad_end("日本", 6, " "), "日本  ".to_string());
+/// ```
+pub fn pad_end<S>(value: S, width: usize, chars: &str) -> String
+where
+    S: AsRef<str>,
+{
+    let value = value.as_ref();
+    let padding = padding(width.saturating_sub(value.width()), chars);
+
+    [value, padding.as_str()].concat()
+}
+
+/// A trait that implements the [PadEnd::pad_end] method on strings.
+pub trait PadEnd<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Pads this string on the right side if it is narrower than `width` columns, like the
+    /// lodash `_.padEnd` function.
+    ///
+    /// * `width` - The padded width.
+    /// * `chars` - The string used as padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::PadEnd;
+    ///
+    /// assert_eq!("abc".pad_end(6, "_-"), "abc_-_".to_string());
+    /// ```
+    fn pad_end(&self, width: usize, chars: &str) -> String;
+}
+
+impl PadEnd<String> for String {
+    fn pad_end(&self, width: usize, chars: &str) -> String {
+        pad_end(self, width, chars)
+    }
+}
+
+impl PadEnd<str> for str {
+    fn pad_end(&self, width: usize, chars: &str) -> String {
+        pad_end(self, width, chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_pad_the_end() {
+        assert_eq!(pad_end("7", 3, "0"), "700".to_string());
+        assert_eq!("abc".pad_end(2, " "), "abc".to_string());
+        assert_eq!("".to_string().pad_end(4, "ab"), "abab".to_string());
+    }
+
+    #[test]
+    fn can_align_table_columns() {
+        let rows = [("名前", "東京"), ("name", "Tokyo"), ("Zoë", "Kraków")];
+        let lines =
+            rows.map(|(name, city)| [pad_end(name, 6, " "), pad_end(city, 6, ".")].concat());
+
+        assert_eq!(lines, ["名前  東京..", "name  Tokyo.", "Zoë   Kraków"]);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::pad::padding;
+use unicode_width::UnicodeWidthStr;
+
+/// Pads `value` on the left side if it is narrower than `width`, like the lodash
+/// `_.padStart` function. Widths are measured in columns of a monospace terminal, in which East
+/// Asian wide characters take two columns, and `chars` is only ever cut between grapheme
+/// clusters.
+///
+/// * `value` - The string to pad.
+/// * `width` - The padded width.
+/// * `chars` - The string used as padding.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::pad_start;
+///
+/// assert_eq!(pad_start("abc", 6, " "), "   abc".to_string());
+/// assert_eq!(pad_start("abc", 6, "_-"), "_-_abc".to_string());
+/// assert_eq!(pad_start("abc", 3, " "), "abc".to_string());
+/// assert_eq!(pad_start("日本", 6, " "), "  日本".to_string());
+/// ```
+pub fn pad_start<S>(value: S, width: usize, chars: &str) -> String
+where
+    S: AsRef<str>,
+{
+    let value = value.as_ref();
+    let padding = padding(width.saturating_sub(value.width()), chars);
+
+    [padding.as_str(), value].concat()
+}
+
+/// A trait that implements the [PadStart::pad_start] method on strings.
+pub trait PadStart<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Pads this string on the left side if it is narrower than `width` columns, like the
+    /// lodash `_.padStart` function.
+    ///
+    /// * `width` - The padded width.
+    /// * `chars` - The string used as padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::PadStart;
+    ///
+    /// assert_eq!("abc".pad_start(6, "_-"), "_-_abc".to_string());
+    /// ```
+    fn pad_start(&self, width: usize, chars: &str) -> String;
+}
+
+impl PadStart<String> for String {
+    fn pad_start(&self, width: usize, chars: &str) -> String {
+        pad_start(self, width, chars)
+    }
+}
+
+impl PadStart<str> for str {
+    fn pad_start(&self, width: usize, chars: &str) -> String {
+        pad_start(self, width, chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_pad_the_start() {
+        assert_eq!(pad_start("7", 3, "0"), "007".to_string());
+        assert_eq!("abc".pad_start(2, " "), "abc".to_string());
+        assert_eq!("".to_string().pad_start(4, "ab"), "abab".to_string());
+    }
+
+    #[test]
+    fn can_align_wide_characters() {
+        assert_eq!(pad_start("名前", 6, " "), "  名前".to_string());
+        assert_eq!(pad_start("x", 4, "日"), "日 x".to_string());
+        assert_eq!(pad_start("abc", 6, ""), "abc".to_string());
+    }
+}
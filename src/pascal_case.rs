@@ -0,0 +1,159 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to pascal case, also known as upper camel case. The [words] of `value` are
+/// capitalized and joined without separators.
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::pascal_case;
+///
+/// assert_eq!(pascal_case("Foo Bar"), "FooBar".to_string());
+/// assert_eq!(pascal_case("--foo-bar--"), "FooBar".to_string());
+/// assert_eq!(pascal_case("__FOO_BAR__"), "FooBar".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn pascal_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    pascal_case_with(value, AcronymMode::Normalize)
+}
+
+/// This method is like [pascal_case] except that acronyms are converted according to `acronyms`.
+///
+/// * `value` - The string to convert.
+/// * `acronyms` - How acronyms such as "HTTP" are converted.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     pascal_case_with,
+///     AcronymMode,
+/// };
+///
+/// assert_eq!(
+///     pascal_case_with("get HTTP response", AcronymMode::Preserve),
+///     "GetHTTPResponse".to_string()
+/// );
+/// ```
+pub fn pascal_case_with<S>(value: S, acronyms: AcronymMode) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Capitalized,
+        WordCase::Capitalized,
+        "",
+        acronyms,
+    )
+}
+
+/// A trait that implements the [PascalCase::pascal_case] method on strings.
+pub trait PascalCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to pascal case, also known as upper camel case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::PascalCase;
+    ///
+    /// assert_eq!("foo_bar".pascal_case(), "FooBar".to_string());
+    /// ```
+    fn pascal_case(&self) -> String;
+
+    /// This method is like [PascalCase::pascal_case] except that acronyms are converted according
+    /// to `acronyms`.
+    ///
+    /// * `acronyms` - How acronyms such as "HTTP" are converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     AcronymMode,
+    ///     PascalCase,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "xml_http_request".pascal_case_with(AcronymMode::Preserve),
+    ///     "XmlHttpRequest".to_string()
+    /// );
+    /// ```
+    fn pascal_case_with(&self, acronyms: AcronymMode) -> String;
+}
+
+impl PascalCase<String> for String {
+    fn pascal_case(&self) -> String {
+        pascal_case(self)
+    }
+
+    fn pascal_case_with(&self, acronyms: AcronymMode) -> String {
+        pascal_case_with(self, acronyms)
+    }
+}
+
+impl PascalCase<str> for str {
+    fn pascal_case(&self) -> String {
+        pascal_case(self)
+    }
+
+    fn pascal_case_with(&self, acronyms: AcronymMode) -> String {
+        pascal_case_with(self, acronyms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_pascal_case() {
+        assert_eq!(pascal_case("fooBar"), "FooBar".to_string());
+        assert_eq!("XMLHttpRequest".pascal_case(), "XmlHttpRequest".to_string());
+        assert_eq!(
+            "version2Update".to_string().pascal_case(),
+            "Version2Update".to_string()
+        );
+        assert_eq!(pascal_case("don't stop"), "DontStop".to_string());
+        assert_eq!("  ".pascal_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().pascal_case(),
+            "ÉlanVital".to_string()
+        );
+    }
+
+    #[test]
+    fn can_preserve_acronyms() {
+        assert_eq!(
+            pascal_case_with("getHTTPResponse", AcronymMode::Normalize),
+            "GetHttpResponse".to_string()
+        );
+        assert_eq!(
+            pascal_case_with("getHTTPResponse", AcronymMode::Preserve),
+            "GetHTTPResponse".to_string()
+        );
+        assert_eq!(
+            pascal_case_with("XMLHttpRequest", AcronymMode::Normalize),
+            "XmlHttpRequest".to_string()
+        );
+        assert_eq!(
+            "XMLHttpRequest".pascal_case_with(AcronymMode::Preserve),
+            "XMLHttpRequest".to_string()
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to path case. The [words] of `value` are lowercased and joined with "/".
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::path_case;
+///
+/// assert_eq!(path_case("Foo Bar"), "foo/bar".to_string());
+/// assert_eq!(path_case("fooBar"), "foo/bar".to_string());
+/// assert_eq!(path_case("__FOO_BAR__"), "foo/bar".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn path_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Lower,
+        WordCase::Lower,
+        "/",
+        AcronymMode::Normalize,
+    )
+}
+
+/// A trait that implements the [PathCase::path_case] method on strings.
+pub trait PathCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to path case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::PathCase;
+    ///
+    /// assert_eq!("fooBar".path_case(), "foo/bar".to_string());
+    /// ```
+    fn path_case(&self) -> String;
+}
+
+impl PathCase<String> for String {
+    fn path_case(&self) -> String {
+        path_case(self)
+    }
+}
+
+impl PathCase<str> for str {
+    fn path_case(&self) -> String {
+        path_case(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_path_case() {
+        assert_eq!(path_case("fooBar"), "foo/bar".to_string());
+        assert_eq!("XMLHttpRequest".path_case(), "xml/http/request".to_string());
+        assert_eq!(
+            "version2Update".to_string().path_case(),
+            "version/2/update".to_string()
+        );
+        assert_eq!(path_case("don't stop"), "dont/stop".to_string());
+        assert_eq!("  ".path_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().path_case(),
+            "élan/vital".to_string()
+        );
+    }
+}
@@ -1,4 +1,8 @@
-use std::ops::Not;
+use std::{
+    collections::HashSet,
+    hash::Hash,
+    ops::Not,
+};
 
 /// This method is like [pull] except that it accepts an array of values to remove.
 ///
@@ -34,11 +38,61 @@ where
     });
 }
 
+/// This method is like [pull_all] except that it accepts `iteratee` which is invoked for each
+/// value of `array` and `values` to generate the key by which they are compared, like the lodash
+/// `_.pullAllBy` function. The keys of `values` are looked up in a hash set.
+///
+/// * `array` - The array to modify.
+/// * `values` - The values to remove.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::pull_all_by;
+///
+/// let mut array = vec![(1, 'a'), (2, 'b'), (3, 'c'), (1, 'd')];
+/// pull_all_by(&mut array, &[(1, 'x'), (3, 'x')], |(id, _)| *id);
+/// assert_eq!(array, [(2, 'b')]);
+/// ```
+pub fn pull_all_by<A, K, F>(array: &mut Vec<A>, values: &[A], mut iteratee: F)
+where
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    let excluded = values.iter().map(&mut iteratee).collect::<HashSet<_>>();
+
+    array.retain(|x| !excluded.contains(&iteratee(x)));
+}
+
+/// This method is like [pull_all] except that it accepts `comparator` which is invoked with a
+/// value of `array` and a value of `values` to tell whether they are equal, like the lodash
+/// `_.pullAllWith` function.
+///
+/// * `array` - The array to modify.
+/// * `values` - The values to remove.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::pull_all_with;
+///
+/// let mut array = vec!["a", "B", "c", "b"];
+/// pull_all_with(&mut array, &["b"], |a, b| a.eq_ignore_ascii_case(b));
+/// assert_eq!(array, ["a", "c"]);
+/// ```
+pub fn pull_all_with<A, F>(array: &mut Vec<A>, values: &[A], mut comparator: F)
+where
+    F: FnMut(&A, &A) -> bool,
+{
+    array.retain(|x| !values.iter().any(|value| comparator(x, value)));
+}
+
 /// A trait that implements the [PullAll::pull_all] method on arrays.
 pub trait PullAll<A, I>
 where
     A: IntoIterator,
-    I: PartialEq + Ord,
 {
     /// This method is like [pull] except that it accepts an array of values to remove.
     ///
@@ -57,21 +111,78 @@ where
     /// array.pull_all(&['a', 'c'], None);
     /// assert_eq!(array, ['b', 'b']);
     /// ```
-    fn pull_all(&mut self, values: &[I], is_sorted: Option<bool>);
+    fn pull_all(&mut self, values: &[I], is_sorted: Option<bool>)
+    where
+        I: PartialEq + Ord;
+
+    /// This method is like [PullAll::pull_all] except that it accepts `iteratee` which is invoked
+    /// for each value of this array and `values` to generate the key by which they are compared.
+    ///
+    /// * `values` - The values to remove.
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::PullAll;
+    ///
+    /// let mut array = vec![1.5, 2.5, 1.9];
+    /// array.pull_all_by(&[1.0], |n: &f64| n.floor() as i64);
+    /// assert_eq!(array, [2.5]);
+    /// ```
+    fn pull_all_by<K, F>(&mut self, values: &[I], iteratee: F)
+    where
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [PullAll::pull_all] except that it accepts `comparator` which is
+    /// invoked with a value of this array and a value of `values` to tell whether they are equal.
+    ///
+    /// * `values` - The values to remove.
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::PullAll;
+    ///
+    /// let mut array = vec!["a", "B", "c"];
+    /// array.pull_all_with(&["b"], |a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(array, ["a", "c"]);
+    /// ```
+    fn pull_all_with<F>(&mut self, values: &[I], comparator: F)
+    where
+        F: FnMut(&I, &I) -> bool;
 }
 
-impl<A> PullAll<Vec<A>, A> for Vec<A>
-where
-    A: PartialEq + Ord,
-{
-    fn pull_all(&mut self, values: &[A], is_sorted: Option<bool>) {
+impl<A> PullAll<Vec<A>, A> for Vec<A> {
+    fn pull_all(&mut self, values: &[A], is_sorted: Option<bool>)
+    where
+        A: PartialEq + Ord,
+    {
         pull_all(self, values, is_sorted)
     }
+
+    fn pull_all_by<K, F>(&mut self, values: &[A], iteratee: F)
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        pull_all_by(self, values, iteratee)
+    }
+
+    fn pull_all_with<F>(&mut self, values: &[A], comparator: F)
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        pull_all_with(self, values, comparator)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::user;
 
     #[test]
     fn can_pull_values_from_an_array() {
@@ -94,4 +205,17 @@ mod tests {
         array.pull_all(&[1, 3], Some(true));
         assert_eq!(array, [2]);
     }
+
+    #[test]
+    fn can_pull_values_in_place_by_key_or_comparator() {
+        let mut users = vec![user(1, "fred"), user(2, "barney"), user(1, "pebbles")];
+        pull_all_by(&mut users, &[user(1, "x")], |user| user.id);
+        assert_eq!(users, [user(2, "barney")]);
+
+        let mut users = vec![user(1, "fred"), user(2, "barney"), user(3, "Fred")];
+        users.pull_all_with(&[user(4, "FRED")], |a, b| {
+            a.name.eq_ignore_ascii_case(b.name)
+        });
+        assert_eq!(users, [user(2, "barney")]);
+    }
 }
@@ -0,0 +1,66 @@
+/// Repeats `value` `n` times, like the lodash `_.repeat` function.
+///
+/// * `value` - The string to repeat.
+/// * `n` - The number of times to repeat the string.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::repeat;
+///
+/// assert_eq!(repeat("*", 3), "***".to_string());
+/// assert_eq!(repeat("abc", 2), "abcabc".to_string());
+/// assert_eq!(repeat("abc", 0), "".to_string());
+/// ```
+pub fn repeat<S>(value: S, n: usize) -> String
+where
+    S: AsRef<str>,
+{
+    value.as_ref().repeat(n)
+}
+
+/// A trait that implements the [Repeat::repeat_str] method on strings.
+pub trait Repeat<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Repeats this string `n` times. This method is not named `repeat` so as not to be shadowed
+    /// by [str::repeat].
+    ///
+    /// * `n` - The number of times to repeat the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Repeat;
+    ///
+    /// assert_eq!("ab".repeat_str(3), "ababab".to_string());
+    /// ```
+    fn repeat_str(&self, n: usize) -> String;
+}
+
+impl Repeat<String> for String {
+    fn repeat_str(&self, n: usize) -> String {
+        repeat(self, n)
+    }
+}
+
+impl Repeat<str> for str {
+    fn repeat_str(&self, n: usize) -> String {
+        repeat(self, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_repeat_strings() {
+        assert_eq!(repeat("日本", 2), "日本日本".to_string());
+        assert_eq!(repeat(String::from("-"), 1), "-".to_string());
+        assert_eq!(repeat("", 5), "".to_string());
+        assert_eq!("=".repeat_str(4), "====".to_string());
+        assert_eq!(String::from("ab").repeat_str(0), "".to_string());
+    }
+}
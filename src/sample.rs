@@ -1,6 +1,6 @@
 use rand::{
+    rng,
     seq::IndexedRandom,
-    thread_rng,
 };
 
 /// Gets a random element from `array`.
@@ -15,7 +15,7 @@ use rand::{
 /// assert!(sample(&[1, 2, 3, 4]).is_some());
 /// ```
 pub fn sample<A>(array: &[A]) -> Option<&A> {
-    array.choose(&mut thread_rng())
+    array.choose(&mut rng())
 }
 
 /// A trait that implements the [Sample::sample] method on arrays.
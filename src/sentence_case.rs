@@ -0,0 +1,162 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to sentence case. The first of the [words] of `value` is capitalized, and the
+/// others are lowercased and joined with spaces.
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::sentence_case;
+///
+/// assert_eq!(sentence_case("Foo Bar"), "Foo bar".to_string());
+/// assert_eq!(sentence_case("fooBar"), "Foo bar".to_string());
+/// assert_eq!(sentence_case("__FOO_BAR__"), "Foo bar".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn sentence_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    sentence_case_with(value, AcronymMode::Normalize)
+}
+
+/// This method is like [sentence_case] except that acronyms are converted according to `acronyms`.
+///
+/// * `value` - The string to convert.
+/// * `acronyms` - How acronyms such as "HTTP" are converted.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     sentence_case_with,
+///     AcronymMode,
+/// };
+///
+/// assert_eq!(
+///     sentence_case_with("the HTTP API", AcronymMode::Preserve),
+///     "The HTTP API".to_string()
+/// );
+/// ```
+pub fn sentence_case_with<S>(value: S, acronyms: AcronymMode) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Capitalized,
+        WordCase::Lower,
+        " ",
+        acronyms,
+    )
+}
+
+/// A trait that implements the [SentenceCase::sentence_case] method on strings.
+pub trait SentenceCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to sentence case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::SentenceCase;
+    ///
+    /// assert_eq!("fooBar".sentence_case(), "Foo bar".to_string());
+    /// ```
+    fn sentence_case(&self) -> String;
+
+    /// This method is like [SentenceCase::sentence_case] except that acronyms are converted
+    /// according to `acronyms`.
+    ///
+    /// * `acronyms` - How acronyms such as "HTTP" are converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     AcronymMode,
+    ///     SentenceCase,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "useHTTPClient".sentence_case_with(AcronymMode::Preserve),
+    ///     "Use HTTP client".to_string()
+    /// );
+    /// ```
+    fn sentence_case_with(&self, acronyms: AcronymMode) -> String;
+}
+
+impl SentenceCase<String> for String {
+    fn sentence_case(&self) -> String {
+        sentence_case(self)
+    }
+
+    fn sentence_case_with(&self, acronyms: AcronymMode) -> String {
+        sentence_case_with(self, acronyms)
+    }
+}
+
+impl SentenceCase<str> for str {
+    fn sentence_case(&self) -> String {
+        sentence_case(self)
+    }
+
+    fn sentence_case_with(&self, acronyms: AcronymMode) -> String {
+        sentence_case_with(self, acronyms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_sentence_case() {
+        assert_eq!(sentence_case("fooBar"), "Foo bar".to_string());
+        assert_eq!(
+            "XMLHttpRequest".sentence_case(),
+            "Xml http request".to_string()
+        );
+        assert_eq!(
+            "version2Update".to_string().sentence_case(),
+            "Version 2 update".to_string()
+        );
+        assert_eq!(sentence_case("don't stop"), "Dont stop".to_string());
+        assert_eq!("  ".sentence_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().sentence_case(),
+            "Élan vital".to_string()
+        );
+    }
+
+    #[test]
+    fn can_preserve_acronyms() {
+        assert_eq!(
+            sentence_case_with("getHTTPResponse", AcronymMode::Normalize),
+            "Get http response".to_string()
+        );
+        assert_eq!(
+            sentence_case_with("getHTTPResponse", AcronymMode::Preserve),
+            "Get HTTP response".to_string()
+        );
+        assert_eq!(
+            sentence_case_with("XMLHttpRequest", AcronymMode::Normalize),
+            "Xml http request".to_string()
+        );
+        assert_eq!(
+            "XMLHttpRequest".sentence_case_with(AcronymMode::Preserve),
+            "XML http request".to_string()
+        );
+    }
+}
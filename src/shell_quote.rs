@@ -0,0 +1,235 @@
+use crate::codec::ByteSet;
+use std::iter;
+
+/// The bytes that may start a character that [shell_quote] cannot leave unquoted.
+static POSIX_SPECIAL_BYTES: ByteSet = ByteSet::non_alphanumeric_ascii()
+    .without(b"@%+=:,./-_")
+    .with_non_ascii();
+
+/// The command-line syntax used by [shell_quote_with] and [shell_split_with].
+///
+/// [shell_split_with]: crate::shell_split_with
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShellMode {
+    /// The syntax of POSIX `sh`, in which arguments are quoted with single quotes.
+    #[default]
+    Posix,
+    /// The rules of the Windows `CommandLineToArgvW` function, which most Windows programs use to
+    /// split their command line, and in which arguments are quoted with double quotes.
+    Windows,
+}
+
+/// Quotes `value` for a POSIX shell, so that `sh` reads it back as a single argument. Values made
+/// of alphanumeric ASCII characters and "@", "%", "+", "=", ":", ",", ".", "/", "-" and "_" are
+/// returned as they are.
+///
+/// * `value` - The string to quote.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::shell_quote;
+///
+/// assert_eq!(shell_quote("fred.txt"), "fred.txt".to_string());
+/// assert_eq!(
+///     shell_quote("fred's $HOME"),
+///     r"'fred'\''s $HOME'".to_string()
+/// );
+/// assert_eq!(shell_quote(""), "''".to_string());
+/// ```
+pub fn shell_quote<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    shell_quote_with(value, ShellMode::Posix)
+}
+
+/// Quotes `value` as a single argument of a command line using the syntax of `mode`.
+///
+/// * `value` - The string to quote.
+/// * `mode` - The command-line syntax to quote for.
+///
+/// # Note
+///
+/// In [ShellMode::Windows], the quoted argument is only read back as is by programs that split
+/// their command line with the `CommandLineToArgvW` rules. It is not escaped for `cmd.exe`, which
+/// interprets characters such as "%" and "^" before the program sees them.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     shell_quote_with,
+///     ShellMode,
+/// };
+///
+/// assert_eq!(
+///     shell_quote_with(r#"C:\Program Files\"fred"\"#, ShellMode::Windows),
+///     r#""C:\Program Files\\\"fred\"\\""#.to_string()
+/// );
+/// ```
+pub fn shell_quote_with<S>(value: S, mode: ShellMode) -> String
+where
+    S: AsRef<str>,
+{
+    let value = value.as_ref();
+
+    match mode {
+        ShellMode::Posix => quote_posix(value),
+        ShellMode::Windows => quote_windows(value),
+    }
+}
+
+/// Quotes `value` with single quotes, ending the quotes around each single quote of `value`.
+fn quote_posix(value: &str) -> String {
+    if !value.is_empty() && !value.bytes().any(|byte| POSIX_SPECIAL_BYTES.contains(byte)) {
+        return value.to_string();
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+
+    for c in value.chars() {
+        if c == '\'' {
+            quoted.push_str(r"'\''");
+        } else {
+            quoted.push(c);
+        }
+    }
+
+    quoted.push('\'');
+    quoted
+}
+
+/// Quotes `value` with double quotes, doubling the backslashes that precede a double quote.
+fn quote_windows(value: &str) -> String {
+    if !value.is_empty() && !value.contains([' ', '\t', '\n', '\u{b}', '"']) {
+        return value.to_string();
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    let mut backslashes = 0;
+    quoted.push('"');
+
+    for c in value.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.extend(iter::repeat_n('\\', backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            c => {
+                quoted.extend(iter::repeat_n('\\', backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+
+    quoted.extend(iter::repeat_n('\\', backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// A trait that implements the [ShellQuote::shell_quote] method on strings.
+pub trait ShellQuote<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Quotes this string for a POSIX shell, so that `sh` reads it back as a single argument.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::ShellQuote;
+    ///
+    /// assert_eq!(
+    ///     "fred's $HOME".shell_quote(),
+    ///     r"'fred'\''s $HOME'".to_string()
+    /// );
+    /// ```
+    fn shell_quote(&self) -> String;
+
+    /// Quotes this string as a single argument of a command line using the syntax of `mode`.
+    ///
+    /// * `mode` - The command-line syntax to quote for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     ShellMode,
+    ///     ShellQuote,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "fred & barney".shell_quote_with(ShellMode::Windows),
+    ///     r#""fred & barney""#.to_string()
+    /// );
+    /// ```
+    fn shell_quote_with(&self, mode: ShellMode) -> String;
+}
+
+impl ShellQuote<String> for String {
+    fn shell_quote(&self) -> String {
+        shell_quote(self)
+    }
+
+    fn shell_quote_with(&self, mode: ShellMode) -> String {
+        shell_quote_with(self, mode)
+    }
+}
+
+impl ShellQuote<str> for str {
+    fn shell_quote(&self) -> String {
+        shell_quote(self)
+    }
+
+    fn shell_quote_with(&self, mode: ShellMode) -> String {
+        shell_quote_with(self, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_quote_posix_arguments() {
+        assert_eq!(shell_quote("a-Z_0.9/@%+=:,"), "a-Z_0.9/@%+=:,".to_string());
+        assert_eq!(shell_quote(""), "''".to_string());
+        assert_eq!(shell_quote("fred barney"), "'fred barney'".to_string());
+        assert_eq!(shell_quote("'"), r"''\'''".to_string());
+        assert_eq!(
+            "$(rm -rf ~) `x` \\ \"!\n*é".shell_quote(),
+            "'$(rm -rf ~) `x` \\ \"!\n*é'".to_string()
+        );
+    }
+
+    #[test]
+    fn can_quote_windows_arguments() {
+        assert_eq!(
+            shell_quote_with(r"C:\fred\barney.txt", ShellMode::Windows),
+            r"C:\fred\barney.txt".to_string()
+        );
+        assert_eq!(
+            shell_quote_with("", ShellMode::Windows),
+            r#""""#.to_string()
+        );
+        assert_eq!(
+            shell_quote_with(r#"a\\b c\"d"#, ShellMode::Windows),
+            r#""a\\b c\\\"d""#.to_string()
+        );
+        assert_eq!(
+            r"fred barney\\".shell_quote_with(ShellMode::Windows),
+            r#""fred barney\\\\""#.to_string()
+        );
+        assert_eq!(
+            "fred\tbarney"
+                .to_string()
+                .shell_quote_with(ShellMode::Windows),
+            "\"fred\tbarney\"".to_string()
+        );
+    }
+}
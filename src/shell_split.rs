@@ -0,0 +1,475 @@
+use crate::ShellMode;
+use std::{
+    error::Error,
+    fmt,
+    iter,
+    mem,
+};
+
+/// The error returned when splitting a malformed POSIX command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShellSplitError {
+    /// A single or double quote that is never closed.
+    UnterminatedQuote {
+        /// The byte index of the opening quote in the value.
+        index: usize,
+    },
+    /// A backslash at the end of the value, with no character to escape.
+    TrailingBackslash {
+        /// The byte index of the backslash in the value.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ShellSplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnterminatedQuote { index } => write!(f, "unterminated quote at index {index}"),
+            Self::TrailingBackslash { index } => write!(f, "trailing backslash at index {index}"),
+        }
+    }
+}
+
+impl Error for ShellSplitError {}
+
+/// The inverse of [shell_quote]. This method splits `value` into arguments the way a POSIX shell
+/// does, removing the quotes and backslashes that protect special characters.
+///
+/// * `value` - The command line to split.
+///
+/// # Note
+///
+/// No expansion is performed, so "$HOME" and "*" are kept as they are, and operators such as "|"
+/// and ";" are part of the surrounding argument. A "#" at the start of an argument begins a
+/// comment that runs to the end of the line.
+///
+/// # Errors
+///
+/// Returns a [ShellSplitError] if `value` contains a quote that is never closed or ends with an
+/// unescaped backslash.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     shell_split,
+///     ShellSplitError,
+/// };
+///
+/// assert_eq!(
+///     shell_split(r#"grep -r 'fred'\''s' "$HOME/a b" # search"#),
+///     Ok(vec![
+///         "grep".to_string(),
+///         "-r".to_string(),
+///         "fred's".to_string(),
+///         "$HOME/a b".to_string()
+///     ])
+/// );
+/// assert_eq!(
+///     shell_split("echo 'fred"),
+///     Err(ShellSplitError::UnterminatedQuote { index: 5 })
+/// );
+/// ```
+///
+/// [shell_quote]: crate::shell_quote
+pub fn shell_split<S>(value: S) -> Result<Vec<String>, ShellSplitError>
+where
+    S: AsRef<str>,
+{
+    shell_split_with(value, ShellMode::Posix)
+}
+
+/// The inverse of [shell_quote_with]. This method splits `value` into arguments using the syntax
+/// of `mode`.
+///
+/// * `value` - The command line to split.
+/// * `mode` - The command-line syntax to split with.
+///
+/// # Note
+///
+/// In [ShellMode::Windows], every argument follows the rules applied by `CommandLineToArgvW` to
+/// the arguments after the program name, and an empty or blank `value` yields no arguments.
+/// Unlike a POSIX command line, a Windows command line is never malformed: an unterminated quote
+/// runs to the end of the value.
+///
+/// # Errors
+///
+/// In [ShellMode::Posix], returns a [ShellSplitError] if `value` contains a quote that is never
+/// closed or ends with an unescaped backslash.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     shell_split_with,
+///     ShellMode,
+/// };
+///
+/// assert_eq!(
+///     shell_split_with(r#"copy "C:\Program Files\" a\\\"b"#, ShellMode::Windows),
+///     Ok(vec![
+///         "copy".to_string(),
+///         r#"C:\Program Files" a\"b"#.to_string()
+///     ])
+/// );
+/// ```
+///
+/// [shell_quote_with]: crate::shell_quote_with
+pub fn shell_split_with<S>(value: S, mode: ShellMode) -> Result<Vec<String>, ShellSplitError>
+where
+    S: AsRef<str>,
+{
+    let value = value.as_ref();
+
+    match mode {
+        ShellMode::Posix => split_posix(value),
+        ShellMode::Windows => Ok(split_windows(value)),
+    }
+}
+
+/// Splits `value` into words like the token recognition of the POSIX shell command language.
+fn split_posix(value: &str) -> Result<Vec<String>, ShellSplitError> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut is_in_word = false;
+    let mut chars = value.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            ' ' | '\t' | '\n' => {
+                if is_in_word {
+                    words.push(mem::take(&mut word));
+                    is_in_word = false;
+                }
+            }
+            '#' if !is_in_word => {
+                chars.by_ref().find(|(_, c)| *c == '\n');
+            }
+            '\\' => match chars.next() {
+                Some((_, '\n')) => {}
+                Some((_, c)) => {
+                    word.push(c);
+                    is_in_word = true;
+                }
+                None => return Err(ShellSplitError::TrailingBackslash { index }),
+            },
+            '\'' => {
+                is_in_word = true;
+
+                loop {
+                    match chars.next() {
+                        Some((_, '\'')) => break,
+                        Some((_, c)) => word.push(c),
+                        None => return Err(ShellSplitError::UnterminatedQuote { index }),
+                    }
+                }
+            }
+            '"' => {
+                is_in_word = true;
+
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, c @ ('$' | '`' | '"' | '\\'))) => word.push(c),
+                            Some((_, '\n')) => {}
+                            Some((_, c)) => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                            None => return Err(ShellSplitError::UnterminatedQuote { index }),
+                        },
+                        Some((_, c)) => word.push(c),
+                        None => return Err(ShellSplitError::UnterminatedQuote { index }),
+                    }
+                }
+            }
+            c => {
+                word.push(c);
+                is_in_word = true;
+            }
+        }
+    }
+
+    if is_in_word {
+        words.push(word);
+    }
+
+    Ok(words)
+}
+
+/// Splits `value` into arguments like `CommandLineToArgvW`, in which backslashes are only special
+/// before a double quote, and every third consecutive double quote is a literal one.
+fn split_windows(value: &str) -> Vec<String> {
+    let mut arguments = Vec::new();
+    let mut argument = String::new();
+    let mut is_in_argument = false;
+    let mut backslashes = 0;
+    // The number of consecutive double quotes that opened or closed a quoted block, modulo 3.
+    // Arguments are only split outside quoted blocks, when it is zero.
+    let mut quotes = 0;
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | '\t' if quotes == 0 => {
+                argument.extend(iter::repeat_n('\\', mem::take(&mut backslashes)));
+
+                if is_in_argument {
+                    arguments.push(mem::take(&mut argument));
+                    is_in_argument = false;
+                }
+            }
+            '\\' => {
+                backslashes += 1;
+                is_in_argument = true;
+            }
+            '"' => {
+                argument.extend(iter::repeat_n('\\', backslashes / 2));
+                is_in_argument = true;
+
+                if backslashes % 2 == 1 {
+                    argument.push('"');
+                } else {
+                    quotes += 1;
+                }
+
+                backslashes = 0;
+
+                while chars.next_if_eq(&'"').is_some() {
+                    quotes += 1;
+
+                    if quotes == 3 {
+                        argument.push('"');
+                        quotes = 0;
+                    }
+                }
+
+                if quotes == 2 {
+                    quotes = 0;
+                }
+            }
+            c => {
+                argument.extend(iter::repeat_n('\\', mem::take(&mut backslashes)));
+                argument.push(c);
+                is_in_argument = true;
+            }
+        }
+    }
+
+    argument.extend(iter::repeat_n('\\', backslashes));
+
+    if is_in_argument {
+        arguments.push(argument);
+    }
+
+    arguments
+}
+
+/// A trait that implements the [ShellSplit::shell_split] method on strings.
+pub trait ShellSplit<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// The inverse of [shell_quote]. This method splits this string into arguments the way a
+    /// POSIX shell does, removing the quotes and backslashes that protect special characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ShellSplitError] if this string contains a quote that is never closed or ends
+    /// with an unescaped backslash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::ShellSplit;
+    ///
+    /// assert_eq!(
+    ///     r"ls 'a b' c\ d".shell_split(),
+    ///     Ok(vec!["ls".to_string(), "a b".to_string(), "c d".to_string()])
+    /// );
+    /// ```
+    ///
+    /// [shell_quote]: crate::ShellQuote::shell_quote
+    fn shell_split(&self) -> Result<Vec<String>, ShellSplitError>;
+
+    /// The inverse of [shell_quote_with]. This method splits this string into arguments using the
+    /// syntax of `mode`.
+    ///
+    /// * `mode` - The command-line syntax to split with.
+    ///
+    /// # Errors
+    ///
+    /// In [ShellMode::Posix], returns a [ShellSplitError] if this string contains a quote that is
+    /// never closed or ends with an unescaped backslash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     ShellMode,
+    ///     ShellSplit,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     r#"dir "a b" c"#.shell_split_with(ShellMode::Windows),
+    ///     Ok(vec!["dir".to_string(), "a b".to_string(), "c".to_string()])
+    /// );
+    /// ```
+    ///
+    /// [shell_quote_with]: crate::ShellQuote::shell_quote_with
+    fn shell_split_with(&self, mode: ShellMode) -> Result<Vec<String>, ShellSplitError>;
+}
+
+impl ShellSplit<String> for String {
+    fn shell_split(&self) -> Result<Vec<String>, ShellSplitError> {
+        shell_split(self)
+    }
+
+    fn shell_split_with(&self, mode: ShellMode) -> Result<Vec<String>, ShellSplitError> {
+        shell_split_with(self, mode)
+    }
+}
+
+impl ShellSplit<str> for str {
+    fn shell_split(&self) -> Result<Vec<String>, ShellSplitError> {
+        shell_split(self)
+    }
+
+    fn shell_split_with(&self, mode: ShellMode) -> Result<Vec<String>, ShellSplitError> {
+        shell_split_with(self, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        shell_quote_with,
+        ShellQuote,
+    };
+    use quickcheck::quickcheck;
+
+    /// Quotes each of `arguments` with `mode` and joins them into a command line.
+    fn join(arguments: &[String], mode: ShellMode) -> String {
+        arguments
+            .iter()
+            .map(|argument| shell_quote_with(argument, mode))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn can_split_posix_command_lines() {
+        assert_eq!(
+            shell_split(" a\tb\n\nc  "),
+            Ok(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+        assert_eq!(
+            shell_split(
+                r#"'a'"b"\c'' "" '' d\ e "f\"\$\`\\\g" "h\
+i" j\
+k"#
+            ),
+            Ok(vec![
+                "abc".to_string(),
+                "".to_string(),
+                "".to_string(),
+                "d e".to_string(),
+                r#"f"$`\\g"#.to_string(),
+                "hi".to_string(),
+                "jk".to_string()
+            ])
+        );
+        assert_eq!(
+            "a#b # c d\ne #".shell_split(),
+            Ok(vec!["a#b".to_string(), "e".to_string()])
+        );
+        assert_eq!(shell_split("  # only a comment"), Ok(Vec::new()));
+        assert_eq!(
+            "a|b;c $HOME *".to_string().shell_split(),
+            Ok(vec![
+                "a|b;c".to_string(),
+                "$HOME".to_string(),
+                "*".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn can_report_malformed_posix_command_lines() {
+        assert_eq!(
+            shell_split("a 'b"),
+            Err(ShellSplitError::UnterminatedQuote { index: 2 })
+        );
+        assert_eq!(
+            shell_split(r#"a "b\""#),
+            Err(ShellSplitError::UnterminatedQuote { index: 2 })
+        );
+        assert_eq!(
+            shell_split(r"a b\"),
+            Err(ShellSplitError::TrailingBackslash { index: 3 })
+        );
+        assert_eq!(
+            ShellSplitError::UnterminatedQuote { index: 2 }.to_string(),
+            "unterminated quote at index 2"
+        );
+    }
+
+    #[test]
+    fn can_split_windows_command_lines() {
+        let split = |value: &str| shell_split_with(value, ShellMode::Windows).unwrap();
+
+        assert_eq!(split(""), Vec::<String>::new());
+        assert_eq!(split(" \t "), Vec::<String>::new());
+        assert_eq!(split(r#"a "b c" d"#), vec!["a", "b c", "d"]);
+        assert_eq!(split(r#""a\"b" c\\"d e""#), vec![r#"a"b"#, r"c\d e"]);
+        assert_eq!(split(r"a\\\b d\\"), vec![r"a\\\b", r"d\\"]);
+        assert_eq!(split(r#"a\\\\"b c" d"#), vec![r"a\\b c", "d"]);
+        assert_eq!(split(r#""" a"b"c"#), vec!["", "abc"]);
+        assert_eq!(split(r#""a""b" c"#), vec![r#"a"b c"#]);
+        assert_eq!(split(r#"a"""b c"#), vec![r#"a"b"#, "c"]);
+        assert_eq!(split(r#"""""" a"#), vec![r#"""#, "a"]);
+        assert_eq!(split("\"a b\nc"), vec!["a b\nc"]);
+        assert_eq!(
+            "a\n b".shell_split_with(ShellMode::Windows),
+            Ok(vec!["a\n".to_string(), "b".to_string()])
+        );
+    }
+
+    #[test]
+    fn can_round_trip_quoted_arguments() {
+        let arguments = [
+            "",
+            "fred",
+            "fred's",
+            r#"C:\Program Files\"fred"\"#,
+            "a b\tc\nd",
+            "#!$`\\*?~",
+        ]
+        .map(String::from);
+
+        for mode in [ShellMode::Posix, ShellMode::Windows] {
+            assert_eq!(
+                shell_split_with(join(&arguments, mode), mode),
+                Ok(arguments.to_vec())
+            );
+        }
+
+        assert_eq!(
+            "a'b".shell_quote().shell_split(),
+            Ok(vec!["a'b".to_string()])
+        );
+    }
+
+    quickcheck! {
+        fn posix_arguments_round_trip(arguments: Vec<String>) -> bool {
+            shell_split(join(&arguments, ShellMode::Posix)) == Ok(arguments)
+        }
+
+        fn windows_arguments_round_trip(arguments: Vec<String>) -> bool {
+            shell_split_with(join(&arguments, ShellMode::Windows), ShellMode::Windows)
+                == Ok(arguments)
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use rand::thread_rng;
+use rand::rng;
 
 /// Creates an array of shuffled values.
 ///
@@ -17,7 +17,7 @@ where
 {
     let mut array = array.to_vec();
     let array = array.as_mut_slice();
-    rand::seq::SliceRandom::shuffle(array, &mut thread_rng());
+    rand::seq::SliceRandom::shuffle(array, &mut rng());
     array.to_vec()
 }
 
@@ -0,0 +1,457 @@
+use crate::transliterate_cow;
+use std::collections::HashSet;
+
+/// The options used by [slugify_with] and [unique_slug] to build a slug.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlugOptions<'a> {
+    /// The separator placed between the words of the slug. Defaults to "-".
+    pub separator: &'a str,
+    /// Whether the words of the slug are lowercased. Defaults to `true`.
+    pub lowercase: bool,
+    /// The maximum length of the slug in bytes, which is reached by leaving out whole words from
+    /// the end of the slug. A first word longer than this is cut. Defaults to `None`, for no
+    /// limit.
+    pub max_length: Option<usize>,
+    /// The slugs that must not be returned, such as "new" or "edit" when they are routes of their
+    /// own, compared regardless of case. A reserved slug gets a numeric suffix, as in "new-2".
+    /// Defaults to none.
+    pub reserved: &'a [&'a str],
+}
+
+impl Default for SlugOptions<'_> {
+    fn default() -> Self {
+        Self {
+            separator: "-",
+            lowercase: true,
+            max_length: None,
+            reserved: &[],
+        }
+    }
+}
+
+/// Splits `value` into the words of a slug, which are the runs of ASCII letters and digits of its
+/// [transliteration](crate::transliterate). Apostrophes are left out, so that "don't" is a single
+/// word.
+fn slug_words(value: &str, lowercase: bool) -> Vec<String> {
+    transliterate_cow(value)
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '\'')
+        .map(|word| {
+            let mut word = word.replace('\'', "");
+
+            if lowercase {
+                word.make_ascii_lowercase();
+            }
+
+            word
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Joins as many of `words` with `separator` as fit in `max_length` bytes. The first word is cut
+/// if it does not fit on its own.
+fn join_words(words: &[String], separator: &str, max_length: usize) -> String {
+    let mut slug = String::new();
+
+    for word in words {
+        if slug.is_empty() {
+            // Words only contain ASCII characters, so any index is a character boundary.
+            slug.push_str(&word[..word.len().min(max_length)]);
+        } else if slug.len() + separator.len() + word.len() <= max_length {
+            slug.push_str(separator);
+            slug.push_str(word);
+        } else {
+            break;
+        }
+    }
+
+    slug
+}
+
+/// Returns the first slug of `words`, with the suffixes 2, 3 and so on after the first attempt,
+/// that is neither reserved in `options` nor rejected by `accept`. Returns `None` if `words` make
+/// an empty slug, or once a suffix leaves no room for any of `words` in the maximum length, as
+/// every later suffix is at least as long.
+fn find_slug<F>(words: &[String], options: &SlugOptions<'_>, mut accept: F) -> Option<String>
+where
+    F: FnMut(&str) -> bool,
+{
+    let max_length = options.max_length.unwrap_or(usize::MAX);
+    let mut number = 1_usize;
+
+    loop {
+        let suffix = number.to_string();
+        let base = if number == 1 {
+            join_words(words, options.separator, max_length)
+        } else {
+            join_words(
+                words,
+                options.separator,
+                max_length.saturating_sub(options.separator.len() + suffix.len()),
+            )
+        };
+
+        if base.is_empty() {
+            return None;
+        }
+
+        let slug = if number == 1 {
+            base
+        } else {
+            format!("{base}{}{suffix}", options.separator)
+        };
+
+        let is_reserved = options
+            .reserved
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(&slug));
+
+        if !is_reserved && accept(&slug) {
+            return Some(slug);
+        }
+
+        number += 1;
+    }
+}
+
+/// Converts `value` to a URL-safe slug, made of the lowercase ASCII letters and digits of its
+/// [transliteration](crate::transliterate) joined with "-".
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::slugify;
+///
+/// assert_eq!(slugify("Crème Brûlée"), "creme-brulee".to_string());
+/// assert_eq!(
+///     slugify("  Don't panic: it's only v2.0!  "),
+///     "dont-panic-its-only-v2-0".to_string()
+/// );
+/// assert_eq!(slugify("Łódź, Polska"), "lodz-polska".to_string());
+/// ```
+pub fn slugify<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    slugify_with(value, &SlugOptions::default())
+}
+
+/// This method is like [slugify] except that the slug is built according to `options`. An empty
+/// string is returned if `value` has no letters or digits, or in the unlikely case that every
+/// slug that fits in the maximum length is reserved.
+///
+/// * `value` - The string to convert.
+/// * `options` - The separator, case, maximum length and reserved slugs.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     slugify_with,
+///     SlugOptions,
+/// };
+///
+/// let options = SlugOptions {
+///     separator: "_",
+///     lowercase: false,
+///     max_length: Some(16),
+///     ..SlugOptions::default()
+/// };
+/// assert_eq!(
+///     slugify_with("The Quick Brown Fox", &options),
+///     "The_Quick_Brown".to_string()
+/// );
+///
+/// let options = SlugOptions {
+///     reserved: &["new", "edit"],
+///     ..SlugOptions::default()
+/// };
+/// assert_eq!(slugify_with("New", &options), "new-2".to_string());
+/// ```
+pub fn slugify_with<S>(value: S, options: &SlugOptions<'_>) -> String
+where
+    S: AsRef<str>,
+{
+    let words = slug_words(value.as_ref(), options.lowercase);
+
+    find_slug(&words, options, |_| true).unwrap_or_default()
+}
+
+/// This method is like [slugify_with] except that the slug is also made unique among the slugs
+/// of `existing`, by appending the first of the suffixes 2, 3 and so on that makes it unique.
+/// The returned slug is inserted into `existing`. Returns `None`, without inserting anything, if
+/// `value` has no letters or digits, or if every slug that fits in the maximum length is reserved
+/// or already in `existing`.
+///
+/// * `value` - The string to convert.
+/// * `options` - The separator, case, maximum length and reserved slugs.
+/// * `existing` - The slugs already in use.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     unique_slug,
+///     SlugOptions,
+/// };
+/// use std::collections::HashSet;
+///
+/// let options = SlugOptions::default();
+/// let mut existing = HashSet::from(["hello-world".to_string()]);
+/// assert_eq!(
+///     unique_slug("Hello, World!", &options, &mut existing),
+///     Some("hello-world-2".to_string())
+/// );
+/// assert_eq!(
+///     unique_slug("Hello world", &options, &mut existing),
+///     Some("hello-world-3".to_string())
+/// );
+/// ```
+pub fn unique_slug<S>(
+    value: S,
+    options: &SlugOptions<'_>,
+    existing: &mut HashSet<String>,
+) -> Option<String>
+where
+    S: AsRef<str>,
+{
+    let words = slug_words(value.as_ref(), options.lowercase);
+
+    find_slug(&words, options, |slug| existing.insert(slug.to_owned()))
+}
+
+/// A trait that implements the [Slugify::slugify] method on strings.
+pub trait Slugify<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to a URL-safe slug, made of the lowercase ASCII letters and digits of
+    /// its transliteration joined with "-".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Slugify;
+    ///
+    /// assert_eq!("Crème Brûlée".slugify(), "creme-brulee".to_string());
+    /// ```
+    fn slugify(&self) -> String;
+
+    /// This method is like [Slugify::slugify] except that the slug is built according to
+    /// `options`.
+    ///
+    /// * `options` - The separator, case, maximum length and reserved slugs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     SlugOptions,
+    ///     Slugify,
+    /// };
+    ///
+    /// let options = SlugOptions {
+    ///     separator: ".",
+    ///     ..SlugOptions::default()
+    /// };
+    /// assert_eq!(
+    ///     "Crème Brûlée".slugify_with(&options),
+    ///     "creme.brulee".to_string()
+    /// );
+    /// ```
+    fn slugify_with(&self, options: &SlugOptions<'_>) -> String;
+
+    /// This method is like [Slugify::slugify_with] except that the slug is also made unique
+    /// among the slugs of `existing`, into which it is inserted. Returns `None` if this string has
+    /// no letters or digits, or if every slug that fits in the maximum length is reserved or
+    /// already in `existing`.
+    ///
+    /// * `options` - The separator, case, maximum length and reserved slugs.
+    /// * `existing` - The slugs already in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     SlugOptions,
+    ///     Slugify,
+    /// };
+    /// use std::collections::HashSet;
+    ///
+    /// let mut existing = HashSet::new();
+    /// let options = SlugOptions::default();
+    /// assert_eq!(
+    ///     "Fred".unique_slug(&options, &mut existing),
+    ///     Some("fred".to_string())
+    /// );
+    /// assert_eq!(
+    ///     "fred".unique_slug(&options, &mut existing),
+    ///     Some("fred-2".to_string())
+    /// );
+    /// ```
+    fn unique_slug(
+        &self,
+        options: &SlugOptions<'_>,
+        existing: &mut HashSet<String>,
+    ) -> Option<String>;
+}
+
+impl Slugify<String> for String {
+    fn slugify(&self) -> String {
+        slugify(self)
+    }
+
+    fn slugify_with(&self, options: &SlugOptions<'_>) -> String {
+        slugify_with(self, options)
+    }
+
+    fn unique_slug(
+        &self,
+        options: &SlugOptions<'_>,
+        existing: &mut HashSet<String>,
+    ) -> Option<String> {
+        unique_slug(self, options, existing)
+    }
+}
+
+impl Slugify<str> for str {
+    fn slugify(&self) -> String {
+        slugify(self)
+    }
+
+    fn slugify_with(&self, options: &SlugOptions<'_>) -> String {
+        slugify_with(self, options)
+    }
+
+    fn unique_slug(
+        &self,
+        options: &SlugOptions<'_>,
+        existing: &mut HashSet<String>,
+    ) -> Option<String> {
+        unique_slug(self, options, existing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_slugify() {
+        assert_eq!(slugify("Hello, World!"), "hello-world".to_string());
+        assert_eq!(
+            "Ελληνικά και Русский".slugify(),
+            "ellinika-kai-russkiy".to_string()
+        );
+        assert_eq!(
+            "“Smart” quotes — 日本 🦀".to_string().slugify(),
+            "smart-quotes".to_string()
+        );
+        assert_eq!(slugify("rock 'n' roll"), "rock-n-roll".to_string());
+        assert_eq!(slugify("--"), "".to_string());
+    }
+
+    #[test]
+    fn can_slugify_with_separators_and_case() {
+        let options = SlugOptions {
+            separator: "",
+            lowercase: false,
+            ..SlugOptions::default()
+        };
+        assert_eq!(
+            slugify_with("Crème Brûlée", &options),
+            "CremeBrulee".to_string()
+        );
+
+        let options = SlugOptions {
+            separator: "--",
+            ..SlugOptions::default()
+        };
+        assert_eq!(slugify_with("a b c", &options), "a--b--c".to_string());
+    }
+
+    #[test]
+    fn can_truncate_slugs_on_word_boundaries() {
+        let options = |max_length| SlugOptions {
+            max_length: Some(max_length),
+            ..SlugOptions::default()
+        };
+        assert_eq!(slugify_with("one two three", &options(13)), "one-two-three");
+        assert_eq!(slugify_with("one two three", &options(12)), "one-two");
+        assert_eq!(slugify_with("one two three", &options(7)), "one-two");
+        assert_eq!(slugify_with("one two three", &options(6)), "one");
+        assert_eq!(slugify_with("supercalifragilistic", &options(5)), "super");
+        assert_eq!(slugify_with("one two", &options(0)), "");
+    }
+
+    #[test]
+    fn can_avoid_reserved_slugs() {
+        let options = SlugOptions {
+            reserved: &["new", "new-2", "Edit"],
+            ..SlugOptions::default()
+        };
+        assert_eq!(slugify_with("NEW", &options), "new-3".to_string());
+        assert_eq!(slugify_with("edit", &options), "edit-2".to_string());
+        assert_eq!(slugify_with("new post", &options), "new-post".to_string());
+    }
+
+    #[test]
+    fn can_make_unique_slugs() {
+        let mut existing = HashSet::new();
+        let options = SlugOptions {
+            max_length: Some(8),
+            reserved: &["admin"],
+            ..SlugOptions::default()
+        };
+        let slugs = ["Admin", "Hello World", "hello world", "hello", "Hello"]
+            .map(|value| unique_slug(value, &options, &mut existing));
+
+        assert_eq!(
+            slugs,
+            ["admin-2", "hello", "hello-2", "hello-3", "hello-4"]
+                .map(|slug| Some(slug.to_string()))
+        );
+        assert_eq!(existing.len(), 5);
+        assert_eq!("".unique_slug(&options, &mut existing), None);
+        assert_eq!("!".unique_slug(&options, &mut existing), None);
+        assert!(!existing.contains(""));
+        assert_eq!(existing.len(), 5);
+    }
+
+    #[test]
+    fn can_stop_once_suffixes_exceed_max_length() {
+        let mut existing = HashSet::new();
+        let options = SlugOptions {
+            max_length: Some(3),
+            ..SlugOptions::default()
+        };
+        let slugs = (0..10)
+            .map(|_| unique_slug("Hello", &options, &mut existing))
+            .collect::<Vec<_>>();
+
+        assert_eq!(slugs[0], Some("hel".to_string()));
+        assert_eq!(slugs[1], Some("h-2".to_string()));
+        assert_eq!(slugs[8], Some("h-9".to_string()));
+        assert_eq!(slugs[9], None);
+        assert!(existing.iter().all(|slug| slug.len() <= 3));
+
+        let options = SlugOptions {
+            max_length: Some(1),
+            ..SlugOptions::default()
+        };
+        assert_eq!(
+            "Hello".unique_slug(&options, &mut existing),
+            Some("h".to_string())
+        );
+        assert_eq!("Hello".unique_slug(&options, &mut existing), None);
+
+        let options = SlugOptions {
+            max_length: Some(1),
+            reserved: &["h"],
+            ..SlugOptions::default()
+        };
+        assert_eq!(slugify_with("Hello", &options), "".to_string());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to [snake case](https://en.wikipedia.org/wiki/Snake_case), like the lodash
+/// `_.snakeCase` function. The [words] of `value` are lowercased and joined with "_".
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::snake_case;
+///
+/// assert_eq!(snake_case("Foo Bar"), "foo_bar".to_string());
+/// assert_eq!(snake_case("fooBar"), "foo_bar".to_string());
+/// assert_eq!(snake_case("--FOO-BAR--"), "foo_bar".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn snake_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Lower,
+        WordCase::Lower,
+        "_",
+        AcronymMode::Normalize,
+    )
+}
+
+/// A trait that implements the [SnakeCase::snake_case] method on strings.
+pub trait SnakeCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to snake case, like the lodash `_.snakeCase` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::SnakeCase;
+    ///
+    /// assert_eq!("fooBar".snake_case(), "foo_bar".to_string());
+    /// ```
+    fn snake_case(&self) -> String;
+}
+
+impl SnakeCase<String> for String {
+    fn snake_case(&self) -> String {
+        snake_case(self)
+    }
+}
+
+impl SnakeCase<str> for str {
+    fn snake_case(&self) -> String {
+        snake_case(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_snake_case() {
+        assert_eq!(snake_case("fooBar"), "foo_bar".to_string());
+        assert_eq!(
+            "XMLHttpRequest".snake_case(),
+            "xml_http_request".to_string()
+        );
+        assert_eq!(
+            "version2Update".to_string().snake_case(),
+            "version_2_update".to_string()
+        );
+        assert_eq!(snake_case("don't stop"), "dont_stop".to_string());
+        assert_eq!("  ".snake_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().snake_case(),
+            "élan_vital".to_string()
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to start case, like the lodash `_.startCase` function. The [words] of `value`
+/// are capitalized and joined with spaces.
+///
+/// # Note
+///
+/// Unlike `_.startCase`, which only uppercases the first letter of each word, the other
+/// letters are lowercased unless acronyms are preserved with [start_case_with].
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::start_case;
+///
+/// assert_eq!(start_case("--foo-bar--"), "Foo Bar".to_string());
+/// assert_eq!(start_case("fooBar"), "Foo Bar".to_string());
+/// assert_eq!(start_case("__FOO_BAR__"), "Foo Bar".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn start_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    start_case_with(value, AcronymMode::Normalize)
+}
+
+/// This method is like [start_case] except that acronyms are converted according to `acronyms`.
+///
+/// * `value` - The string to convert.
+/// * `acronyms` - How acronyms such as "HTTP" are converted.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     start_case_with,
+///     AcronymMode,
+/// };
+///
+/// assert_eq!(
+///     start_case_with("__FOO_BAR__", AcronymMode::Preserve),
+///     "FOO BAR".to_string()
+/// );
+/// ```
+pub fn start_case_with<S>(value: S, acronyms: AcronymMode) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Capitalized,
+        WordCase::Capitalized,
+        " ",
+        acronyms,
+    )
+}
+
+/// A trait that implements the [StartCase::start_case] method on strings.
+pub trait StartCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to start case, like the lodash `_.startCase` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::StartCase;
+    ///
+    /// assert_eq!("foo_bar".start_case(), "Foo Bar".to_string());
+    /// ```
+    fn start_case(&self) -> String;
+
+    /// This method is like [StartCase::start_case] except that acronyms are converted according to
+    /// `acronyms`.
+    ///
+    /// * `acronyms` - How acronyms such as "HTTP" are converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     AcronymMode,
+    ///     StartCase,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "get HTTP response".start_case_with(AcronymMode::Preserve),
+    ///     "Get HTTP Response".to_string()
+    /// );
+    /// ```
+    fn start_case_with(&self, acronyms: AcronymMode) -> String;
+}
+
+impl StartCase<String> for String {
+    fn start_case(&self) -> String {
+        start_case(self)
+    }
+
+    fn start_case_with(&self, acronyms: AcronymMode) -> String {
+        start_case_with(self, acronyms)
+    }
+}
+
+impl StartCase<str> for str {
+    fn start_case(&self) -> String {
+        start_case(self)
+    }
+
+    fn start_case_with(&self, acronyms: AcronymMode) -> String {
+        start_case_with(self, acronyms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_start_case() {
+        assert_eq!(start_case("fooBar"), "Foo Bar".to_string());
+        assert_eq!(
+            "XMLHttpRequest".start_case(),
+            "Xml Http Request".to_string()
+        );
+        assert_eq!(
+            "version2Update".to_string().start_case(),
+            "Version 2 Update".to_string()
+        );
+        assert_eq!(start_case("don't stop"), "Dont Stop".to_string());
+        assert_eq!("  ".start_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().start_case(),
+            "Élan Vital".to_string()
+        );
+    }
+
+    #[test]
+    fn can_preserve_acronyms() {
+        assert_eq!(
+            start_case_with("getHTTPResponse", AcronymMode::Normalize),
+            "Get Http Response".to_string()
+        );
+        assert_eq!(
+            start_case_with("getHTTPResponse", AcronymMode::Preserve),
+            "Get HTTP Response".to_string()
+        );
+        assert_eq!(
+            start_case_with("XMLHttpRequest", AcronymMode::Normalize),
+            "Xml Http Request".to_string()
+        );
+        assert_eq!(
+            "XMLHttpRequest".start_case_with(AcronymMode::Preserve),
+            "XML Http Request".to_string()
+        );
+    }
+}
@@ -0,0 +1,586 @@
+use crate::{
+    escape_to,
+    EscapeContext,
+};
+use std::{
+    borrow::{
+        Borrow,
+        Cow,
+    },
+    collections::HashMap,
+    error::Error,
+    fmt,
+    hash::{
+        BuildHasher,
+        Hash,
+    },
+    str::FromStr,
+};
+
+/// An error raised while compiling or rendering a [Template]. Lines and columns start at 1, and
+/// columns are counted in characters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemplateError {
+    /// An opening delimiter that is never closed.
+    UnclosedDelimiter {
+        /// The line of the opening delimiter.
+        line: usize,
+        /// The column of the opening delimiter.
+        column: usize,
+    },
+    /// A tag without a value name, such as `<%= %>`.
+    EmptyName {
+        /// The line of the opening delimiter.
+        line: usize,
+        /// The column of the opening delimiter.
+        column: usize,
+    },
+    /// A value name that is not a dotted path of identifiers, such as `user.name`.
+    InvalidName {
+        /// The line of the first invalid character.
+        line: usize,
+        /// The column of the first invalid character.
+        column: usize,
+    },
+    /// A value that is missing from the data a template is rendered with.
+    MissingValue {
+        /// The name of the value.
+        name: String,
+        /// The line of the tag of the value.
+        line: usize,
+        /// The column of the tag of the value.
+        column: usize,
+    },
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedDelimiter { line, column } => {
+                write!(f, "unclosed delimiter at line {line}, column {column}")
+            }
+            Self::EmptyName { line, column } => {
+                write!(f, "empty value name at line {line}, column {column}")
+            }
+            Self::InvalidName { line, column } => {
+                write!(f, "invalid value name at line {line}, column {column}")
+            }
+            Self::MissingValue { name, line, column } => {
+                write!(f, "missing value `{name}` at line {line}, column {column}")
+            }
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+/// The delimiters of the tags of a [Template], as pairs of opening and closing strings. A kind of
+/// tag is disabled when its delimiters are `None` or contain an empty string. When several
+/// opening delimiters start at the same position, the longest one is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TemplateDelimiters<'a> {
+    /// The delimiters of values that are HTML-escaped with [escape](crate::escape). Defaults to
+    /// `<%=` and `%>`.
+    pub escape: Option<(&'a str, &'a str)>,
+    /// The delimiters of values that are inserted as they are. Defaults to `<%-` and `%>`.
+    pub raw: Option<(&'a str, &'a str)>,
+    /// The delimiters of values that are HTML-escaped, in the style of ES template literals.
+    /// Defaults to `${` and `}`.
+    pub interpolate: Option<(&'a str, &'a str)>,
+}
+
+impl Default for TemplateDelimiters<'_> {
+    fn default() -> Self {
+        Self {
+            escape: Some(("<%=", "%>")),
+            raw: Some(("<%-", "%>")),
+            interpolate: Some(("${", "}")),
+        }
+    }
+}
+
+/// The values that a [Template] is rendered with.
+pub trait TemplateData {
+    /// Returns the value named `name`, which is a dotted path such as "user.name", or `None` if
+    /// there is no such value.
+    fn value(&self, name: &str) -> Option<Cow<'_, str>>;
+}
+
+/// Values are looked up by their whole name, so that "user.name" is a key of its own.
+impl<K, V, H> TemplateData for HashMap<K, V, H>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: fmt::Display,
+    H: BuildHasher,
+{
+    fn value(&self, name: &str) -> Option<Cow<'_, str>> {
+        self.get(name).map(|value| Cow::Owned(value.to_string()))
+    }
+}
+
+/// Values are looked up by following the segments of their name through objects, and arrays for
+/// numeric segments. Strings are inserted without quotes, `null` as an empty string, and other
+/// values as JSON.
+#[cfg(feature = "json")]
+impl TemplateData for serde_json::Value {
+    fn value(&self, name: &str) -> Option<Cow<'_, str>> {
+        use serde_json::Value;
+
+        let mut value = self;
+
+        for segment in name.split('.') {
+            value = match value {
+                Value::Object(object) => object.get(segment)?,
+                Value::Array(array) => array.get(segment.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+
+        Some(match value {
+            Value::String(string) => Cow::Borrowed(string.as_str()),
+            Value::Null => Cow::Borrowed(""),
+            value => Cow::Owned(value.to_string()),
+        })
+    }
+}
+
+/// A part of a compiled [Template].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Segment {
+    /// Text that is rendered as it is.
+    Text(String),
+    /// A value that is looked up in the data a template is rendered with.
+    Value {
+        name: String,
+        is_escaped: bool,
+        line: usize,
+        column: usize,
+    },
+}
+
+/// Returns the byte index of the first character of `name` that makes it an invalid value name,
+/// if any. A valid name is a dotted path of one or more identifiers, made of alphanumeric
+/// characters, "_" and "$".
+fn find_invalid_char(name: &str) -> Option<usize> {
+    let mut previous = '.';
+
+    for (index, c) in name.char_indices() {
+        let is_valid = if c == '.' {
+            previous != '.'
+        } else {
+            c.is_alphanumeric() || c == '_' || c == '$'
+        };
+
+        if !is_valid {
+            return Some(index);
+        }
+
+        previous = c;
+    }
+
+    (previous == '.').then(|| name.len() - 1)
+}
+
+/// Tracks the line and column of byte indices of a source, which must be requested in
+/// increasing order.
+struct Positions<'a> {
+    source: &'a str,
+    index: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Positions<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            index: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// Returns the line and column of the byte at `index`.
+    fn at(&mut self, index: usize) -> (usize, usize) {
+        let skipped = &self.source[self.index..index];
+
+        match memchr::memrchr(b'\n', skipped.as_bytes()) {
+            Some(newline) => {
+                self.line += memchr::memchr_iter(b'\n', skipped.as_bytes()).count();
+                self.column = skipped[newline + 1..].chars().count() + 1;
+            }
+            None => self.column += skipped.chars().count(),
+        }
+
+        self.index = index;
+
+        (self.line, self.column)
+    }
+}
+
+/// A compiled string template, like the ones of the lodash `_.template` function, in which tags
+/// are replaced with values when rendering. The tags are `<%= name %>` for HTML-escaped values,
+/// `<%- name %>` for raw values and `${name}` for HTML-escaped values, where names are dotted
+/// paths such as `user.name`. Unlike lodash templates, templates cannot evaluate code.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::Template;
+/// use std::collections::HashMap;
+///
+/// let template = Template::compile("<p>Hello, <%= name %>!</p><%- footer %>").unwrap();
+/// let data = HashMap::from([("name", "<fred>"), ("footer", "<hr>")]);
+/// assert_eq!(
+///     template.render(&data).unwrap(),
+///     "<p>Hello, &lt;fred&gt;!</p><hr>"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    /// Compiles `source` with the default [TemplateDelimiters].
+    ///
+    /// * `source` - The template text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [TemplateError] if a tag is never closed or has an empty or invalid value name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     Template,
+    ///     TemplateError,
+    /// };
+    ///
+    /// assert!(Template::compile("Hi ${ user.name }").is_ok());
+    /// assert_eq!(
+    ///     Template::compile("Hi\n  <%= name"),
+    ///     Err(TemplateError::UnclosedDelimiter { line: 2, column: 3 })
+    /// );
+    /// assert_eq!(
+    ///     Template::compile("${first name}"),
+    ///     Err(TemplateError::InvalidName { line: 1, column: 8 })
+    /// );
+    /// ```
+    pub fn compile<S>(source: S) -> Result<Self, TemplateError>
+    where
+        S: AsRef<str>,
+    {
+        Self::compile_with(source, TemplateDelimiters::default())
+    }
+
+    /// This method is like [Template::compile] except that tags are found with `delimiters`.
+    ///
+    /// * `source` - The template text.
+    /// * `delimiters` - The delimiters of each kind of tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [TemplateError] if a tag is never closed or has an empty or invalid value name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     Template,
+    ///     TemplateDelimiters,
+    /// };
+    /// use std::collections::HashMap;
+    ///
+    /// let delimiters = TemplateDelimiters {
+    ///     escape: Some(("{{", "}}")),
+    ///     raw: Some(("{{{", "}}}")),
+    ///     interpolate: None,
+    /// };
+    /// let template = Template::compile_with("{{ a }} {{{ a }}} ${a}", delimiters).unwrap();
+    /// let data = HashMap::from([("a", "&")]);
+    /// assert_eq!(template.render(&data).unwrap(), "&amp; & ${a}");
+    /// ```
+    pub fn compile_with<S>(
+        source: S,
+        delimiters: TemplateDelimiters<'_>,
+    ) -> Result<Self, TemplateError>
+    where
+        S: AsRef<str>,
+    {
+        let source = source.as_ref();
+        let kinds = [
+            (delimiters.escape, true),
+            (delimiters.raw, false),
+            (delimiters.interpolate, true),
+        ]
+        .into_iter()
+        .filter_map(|(delimiters, is_escaped)| {
+            delimiters
+                .filter(|(open, close)| !open.is_empty() && !close.is_empty())
+                .map(|(open, close)| (open, close, is_escaped))
+        })
+        .collect::<Vec<_>>();
+        // The index of the next opening delimiter of each kind, which is searched for again once
+        // it is behind the end of the last tag.
+        let mut next_opens = kinds
+            .iter()
+            .map(|(open, ..)| source.find(open))
+            .collect::<Vec<_>>();
+        let mut positions = Positions::new(source);
+        let mut segments = Vec::new();
+        let mut start = 0;
+
+        loop {
+            let mut tag: Option<(usize, usize, &str, bool)> = None;
+
+            for (kind, next_open) in next_opens.iter_mut().enumerate() {
+                let (open, close, is_escaped) = kinds[kind];
+
+                if next_open.is_some_and(|index| index < start) {
+                    *next_open = source[start..].find(open).map(|index| start + index);
+                }
+
+                if let Some(index) = *next_open {
+                    let is_better = tag.is_none_or(|(other, other_open_length, ..)| {
+                        index < other || (index == other && open.len() > other_open_length)
+                    });
+
+                    if is_better {
+                        tag = Some((index, open.len(), close, is_escaped));
+                    }
+                }
+            }
+
+            let Some((index, open_length, close, is_escaped)) = tag else {
+                break;
+            };
+
+            let (line, column) = positions.at(index);
+            let content_start = index + open_length;
+            let Some(content_length) = source[content_start..].find(close) else {
+                return Err(TemplateError::UnclosedDelimiter { line, column });
+            };
+
+            let content = &source[content_start..content_start + content_length];
+            let name = content.trim();
+
+            if name.is_empty() {
+                return Err(TemplateError::EmptyName { line, column });
+            }
+
+            if let Some(invalid) = find_invalid_char(name) {
+                let name_start = content_start + (content.len() - content.trim_start().len());
+                let (line, column) = positions.at(name_start + invalid);
+
+                return Err(TemplateError::InvalidName { line, column });
+            }
+
+            if index > start {
+                segments.push(Segment::Text(source[start..index].to_owned()));
+            }
+
+            segments.push(Segment::Value {
+                name: name.to_owned(),
+                is_escaped,
+                line,
+                column,
+            });
+            start = content_start + content_length + close.len();
+        }
+
+        if start < source.len() {
+            segments.push(Segment::Text(source[start..].to_owned()));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Renders this template with the values of `data`.
+    ///
+    /// * `data` - The values of the tags, such as a [HashMap] of [fmt::Display] values, or a
+    ///   `serde_json::Value` with the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [TemplateError::MissingValue] if a value is missing from `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     Template,
+    ///     TemplateError,
+    /// };
+    /// use std::collections::HashMap;
+    ///
+    /// let template = Template::compile("${count} items for <%= name %>").unwrap();
+    /// let data = HashMap::from([("count", 3)]);
+    /// assert_eq!(
+    ///     template.render(&data),
+    ///     Err(TemplateError::MissingValue {
+    ///         name: "name".to_string(),
+    ///         line: 1,
+    ///         column: 20
+    ///     })
+    /// );
+    /// ```
+    pub fn render<D>(&self, data: &D) -> Result<String, TemplateError>
+    where
+        D: TemplateData + ?Sized,
+    {
+        let mut rendered = String::new();
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => rendered.push_str(text),
+                Segment::Value {
+                    name,
+                    is_escaped,
+                    line,
+                    column,
+                } => {
+                    let Some(value) = data.value(name) else {
+                        return Err(TemplateError::MissingValue {
+                            name: name.clone(),
+                            line: *line,
+                            column: *column,
+                        });
+                    };
+
+                    if *is_escaped {
+                        // Writing into a `String` never fails.
+                        let _ = escape_to(&value, EscapeContext::Text, &mut rendered);
+                    } else {
+                        rendered.push_str(&value);
+                    }
+                }
+            }
+        }
+
+        Ok(rendered)
+    }
+}
+
+impl FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::compile(source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_render_templates() {
+        let template = Template::compile("<%= a %>|<%- a %>|${a}|${ b.c }").unwrap();
+        let data = HashMap::from([("a", "<'&'>"), ("b.c", "d")]);
+
+        assert_eq!(
+            template.render(&data).unwrap(),
+            "&lt;&#39;&amp;&#39;&gt;|<'&'>|&lt;&#39;&amp;&#39;&gt;|d"
+        );
+        assert_eq!(
+            "plain text".parse::<Template>().unwrap().render(&data),
+            Ok("plain text".to_string())
+        );
+        assert_eq!(
+            Template::compile("").unwrap().render(&data),
+            Ok(String::new())
+        );
+    }
+
+    #[test]
+    fn can_render_display_values() {
+        let template = Template::compile("${ n } × ${ x } = <%- y %>").unwrap();
+        let data = HashMap::from([
+            ("n".to_string(), 2.5),
+            ("x".to_string(), 2.0),
+            ("y".to_string(), 5.0),
+        ]);
+
+        assert_eq!(template.render(&data).unwrap(), "2.5 × 2 = 5");
+    }
+
+    #[test]
+    fn can_report_compile_errors() {
+        assert_eq!(
+            Template::compile("a\nbc <%- x"),
+            Err(TemplateError::UnclosedDelimiter { line: 2, column: 4 })
+        );
+        assert_eq!(
+            Template::compile("é ${}"),
+            Err(TemplateError::EmptyName { line: 1, column: 3 })
+        );
+        assert_eq!(
+            Template::compile("\n\n<%=  a..b %>"),
+            Err(TemplateError::InvalidName { line: 3, column: 8 })
+        );
+        assert_eq!(
+            Template::compile("${a.}"),
+            Err(TemplateError::InvalidName { line: 1, column: 4 })
+        );
+        assert_eq!(
+            Template::compile("${.a}").unwrap_err().to_string(),
+            "invalid value name at line 1, column 3"
+        );
+    }
+
+    #[test]
+    fn can_report_missing_values() {
+        let template = Template::compile("line\n  ${ user.name }").unwrap();
+        let error = template.render(&HashMap::<&str, &str>::new()).unwrap_err();
+
+        assert_eq!(
+            error,
+            TemplateError::MissingValue {
+                name: "user.name".to_string(),
+                line: 2,
+                column: 3
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "missing value `user.name` at line 2, column 3"
+        );
+    }
+
+    #[test]
+    fn can_use_custom_delimiters() {
+        let delimiters = TemplateDelimiters {
+            escape: Some(("[[", "]]")),
+            raw: Some(("[[!", "]]")),
+            interpolate: Some(("", "}")),
+        };
+        let template = Template::compile_with("[[ a ]][[! a ]]<%= a %>${a}", delimiters).unwrap();
+        let data = HashMap::from([("a", "<>")]);
+
+        assert_eq!(template.render(&data).unwrap(), "&lt;&gt;<><%= a %>${a}");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn can_render_json_values() {
+        let template =
+            Template::compile("${user.name} (${user.age}) ${tags.1} ${none}|<%- user.raw %>")
+                .unwrap();
+        let data = serde_json::json!({
+            "user": { "name": "<fred>", "age": 40, "raw": "<b>" },
+            "tags": ["a", "b&c"],
+            "none": null
+        });
+
+        assert_eq!(
+            template.render(&data).unwrap(),
+            "&lt;fred&gt; (40) b&amp;c |<b>"
+        );
+        assert!(matches!(
+            template.render(&serde_json::json!({ "user": 1 })),
+            Err(TemplateError::MissingValue { .. })
+        ));
+    }
+}
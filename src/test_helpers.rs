@@ -0,0 +1,14 @@
+//! Fixtures shared by the tests of the array functions.
+
+/// A user that is only cloneable, so that it can only be compared by a key such as its id, or by a
+/// comparator such as one that ignores the case of its name.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct User {
+    pub(crate) id: u32,
+    pub(crate) name: &'static str,
+}
+
+/// Creates a [User] with the `id` and `name`.
+pub(crate) fn user(id: u32, name: &'static str) -> User {
+    User { id, name }
+}
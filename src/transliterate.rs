@@ -0,0 +1,197 @@
+use crate::{
+    codec::{
+        self,
+        ByteSet,
+        Encoder,
+    },
+    deburr::{
+        is_combining_mark,
+        NON_ASCII_BYTES,
+    },
+    transliterations::{
+        self,
+        DEBURRED_LETTERS,
+        TRANSLITERATIONS,
+    },
+};
+use std::{
+    borrow::Cow,
+    fmt,
+};
+
+/// The rules applied by [transliterate].
+struct TransliterateEncoder;
+
+impl Encoder for TransliterateEncoder {
+    fn special_bytes(&self) -> &ByteSet {
+        &NON_ASCII_BYTES
+    }
+
+    fn encode<W>(&self, c: char, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        if is_combining_mark(c) || matches!(c, '\u{1ab0}'..='\u{1aff}' | '\u{1dc0}'..='\u{1dff}') {
+            return Ok(());
+        }
+
+        match transliterations::lookup(&DEBURRED_LETTERS, c)
+            .or_else(|| transliterations::lookup(&TRANSLITERATIONS, c))
+        {
+            Some(replacement) => out.write_str(replacement),
+            None => out.write_char(c),
+        }
+    }
+}
+
+/// Transliterates `value` to ASCII. This method is like [deburr](crate::deburr) except that it
+/// also converts the letters of the Latin Extended-B and Latin Extended Additional blocks, Greek
+/// and Cyrillic letters, typographic quotes and dashes, the ellipsis and non-breaking spaces.
+/// Characters without a transliteration, such as CJK ideographs and emoji, are kept as they are.
+///
+/// * `value` - The string to transliterate.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::transliterate;
+///
+/// assert_eq!(transliterate("Łódź"), "Lodz".to_string());
+/// assert_eq!(transliterate("Tiếng Việt"), "Tieng Viet".to_string());
+/// assert_eq!(transliterate("Σίσυφος"), "Sisyfos".to_string());
+/// assert_eq!(
+///     transliterate("Щука из Москвы"),
+///     "Shchuka iz Moskvy".to_string()
+/// );
+/// assert_eq!(
+///     transliterate("“Crème brûlée” — 5 €"),
+///     "\"Creme brulee\" - 5 €".to_string()
+/// );
+/// ```
+pub fn transliterate<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    transliterate_cow(value.as_ref()).into_owned()
+}
+
+/// This method is like [transliterate] except that it returns `value` as is, without
+/// allocating, when it only contains ASCII characters.
+///
+/// * `value` - The string to transliterate.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::transliterate_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(transliterate_cow("Ελλάδα"), "Ellada");
+/// assert!(matches!(transliterate_cow("Hellas"), Cow::Borrowed(_)));
+/// ```
+pub fn transliterate_cow(value: &str) -> Cow<'_, str> {
+    codec::encode_cow(&TransliterateEncoder, value)
+}
+
+/// A trait that implements the [Transliterate::transliterate] method on strings.
+pub trait Transliterate<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Transliterates this string to ASCII, converting Latin, Greek and Cyrillic letters and
+    /// typographic punctuation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Transliterate;
+    ///
+    /// assert_eq!("Достоевский".transliterate(), "Dostoevskiy".to_string());
+    /// ```
+    fn transliterate(&self) -> String;
+
+    /// This method is like [Transliterate::transliterate] except that it returns this string as
+    /// is, without allocating, when it only contains ASCII characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Transliterate;
+    ///
+    /// assert_eq!("Ǆurđevac".transliterate_cow(), "DZurdevac");
+    /// ```
+    fn transliterate_cow(&self) -> Cow<'_, str>;
+}
+
+impl Transliterate<String> for String {
+    fn transliterate(&self) -> String {
+        transliterate(self)
+    }
+
+    fn transliterate_cow(&self) -> Cow<'_, str> {
+        transliterate_cow(self)
+    }
+}
+
+impl Transliterate<str> for str {
+    fn transliterate(&self) -> String {
+        transliterate(self)
+    }
+
+    fn transliterate_cow(&self) -> Cow<'_, str> {
+        transliterate_cow(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deburr;
+
+    #[test]
+    fn can_transliterate_latin_letters() {
+        assert_eq!(
+            transliterate("Crème Brûlée à Łódź"),
+            "Creme Brulee a Lodz".to_string()
+        );
+        assert_eq!(
+            "Ștefan Țiriac ǅemal Ơn Ấn".transliterate(),
+            "Stefan Tiriac Dzemal On An".to_string()
+        );
+        assert_eq!("ẞ Ə ẛ".to_string().transliterate(), "SS E s".to_string());
+    }
+
+    #[test]
+    fn can_transliterate_greek_and_cyrillic() {
+        assert_eq!(
+            transliterate("Αθήνα Θεσσαλονίκη ψυχή"),
+            "Athina Thessaloniki psychi".to_string()
+        );
+        assert_eq!(
+            transliterate("Съешь же ещё этих мягких булок"),
+            "Sesh zhe eshchyo etikh myagkikh bulok".to_string()
+        );
+        assert_eq!(transliterate("Ђорђе Љубић"), "Djordje Ljubic".to_string());
+    }
+
+    #[test]
+    fn can_transliterate_punctuation() {
+        assert_eq!(
+            transliterate("«‘a’ – “b”…»\u{a0}c"),
+            "<<'a' - \"b\"...>> c".to_string()
+        );
+    }
+
+    #[test]
+    fn can_keep_characters_without_transliteration() {
+        assert_eq!(transliterate("日本語 🦀 €"), "日本語 🦀 €".to_string());
+        assert_eq!(transliterate("a\u{1dc4}\u{301}"), "a".to_string());
+    }
+
+    #[test]
+    fn can_extend_deburr() {
+        let value = "ÀÉÎÕÜ àéîõü ÇÑ çñ Ææ Œœ ß";
+        assert_eq!(transliterate(value), deburr(value));
+        assert!(matches!(transliterate_cow("plain"), Cow::Borrowed(_)));
+    }
+}
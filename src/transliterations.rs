@@ -0,0 +1,828 @@
+//! Transliteration data for [deburr](crate::deburr) and [transliterate](crate::transliterate).
+
+/// The basic Latin replacements of the letters of the Latin-1 Supplement and Latin Extended-A
+/// blocks, as used by the lodash `_.deburr` function, sorted by character.
+pub(crate) static DEBURRED_LETTERS: [(char, &str); 190] = [
+    ('\u{00c0}', "A"),
+    ('\u{00c1}', "A"),
+    ('\u{00c2}', "A"),
+    ('\u{00c3}', "A"),
+    ('\u{00c4}', "A"),
+    ('\u{00c5}', "A"),
+    ('\u{00c6}', "Ae"),
+    ('\u{00c7}', "C"),
+    ('\u{00c8}', "E"),
+    ('\u{00c9}', "E"),
+    ('\u{00ca}', "E"),
+    ('\u{00cb}', "E"),
+    ('\u{00cc}', "I"),
+    ('\u{00cd}', "I"),
+    ('\u{00ce}', "I"),
+    ('\u{00cf}', "I"),
+    ('\u{00d0}', "D"),
+    ('\u{00d1}', "N"),
+    ('\u{00d2}', "O"),
+    ('\u{00d3}', "O"),
+    ('\u{00d4}', "O"),
+    ('\u{00d5}', "O"),
+    ('\u{00d6}', "O"),
+    ('\u{00d8}', "O"),
+    ('\u{00d9}', "U"),
+    ('\u{00da}', "U"),
+    ('\u{00db}', "U"),
+    ('\u{00dc}', "U"),
+    ('\u{00dd}', "Y"),
+    ('\u{00de}', "Th"),
+    ('\u{00df}', "ss"),
+    ('\u{00e0}', "a"),
+    ('\u{00e1}', "a"),
+    ('\u{00e2}', "a"),
+    ('\u{00e3}', "a"),
+    ('\u{00e4}', "a"),
+    ('\u{00e5}', "a"),
+    ('\u{00e6}', "ae"),
+    ('\u{00e7}', "c"),
+    ('\u{00e8}', "e"),
+    ('\u{00e9}', "e"),
+    ('\u{00ea}', "e"),
+    ('\u{00eb}', "e"),
+    ('\u{00ec}', "i"),
+    ('\u{00ed}', "i"),
+    ('\u{00ee}', "i"),
+    ('\u{00ef}', "i"),
+    ('\u{00f0}', "d"),
+    ('\u{00f1}', "n"),
+    ('\u{00f2}', "o"),
+    ('\u{00f3}', "o"),
+    ('\u{00f4}', "o"),
+    ('\u{00f5}', "o"),
+    ('\u{00f6}', "o"),
+    ('\u{00f8}', "o"),
+    ('\u{00f9}', "u"),
+    ('\u{00fa}', "u"),
+    ('\u{00fb}', "u"),
+    ('\u{00fc}', "u"),
+    ('\u{00fd}', "y"),
+    ('\u{00fe}', "th"),
+    ('\u{00ff}', "y"),
+    ('\u{0100}', "A"),
+    ('\u{0101}', "a"),
+    ('\u{0102}', "A"),
+    ('\u{0103}', "a"),
+    ('\u{0104}', "A"),
+    ('\u{0105}', "a"),
+    ('\u{0106}', "C"),
+    ('\u{0107}', "c"),
+    ('\u{0108}', "C"),
+    ('\u{0109}', "c"),
+    ('\u{010a}', "C"),
+    ('\u{010b}', "c"),
+    ('\u{010c}', "C"),
+    ('\u{010d}', "c"),
+    ('\u{010e}', "D"),
+    ('\u{010f}', "d"),
+    ('\u{0110}', "D"),
+    ('\u{0111}', "d"),
+    ('\u{0112}', "E"),
+    ('\u{0113}', "e"),
+    ('\u{0114}', "E"),
+    ('\u{0115}', "e"),
+    ('\u{0116}', "E"),
+    ('\u{0117}', "e"),
+    ('\u{0118}', "E"),
+    ('\u{0119}', "e"),
+    ('\u{011a}', "E"),
+    ('\u{011b}', "e"),
+    ('\u{011c}', "G"),
+    ('\u{011d}', "g"),
+    ('\u{011e}', "G"),
+    ('\u{011f}', "g"),
+    ('\u{0120}', "G"),
+    ('\u{0121}', "g"),
+    ('\u{0122}', "G"),
+    ('\u{0123}', "g"),
+    ('\u{0124}', "H"),
+    ('\u{0125}', "h"),
+    ('\u{0126}', "H"),
+    ('\u{0127}', "h"),
+    ('\u{0128}', "I"),
+    ('\u{0129}', "i"),
+    ('\u{012a}', "I"),
+    ('\u{012b}', "i"),
+    ('\u{012c}', "I"),
+    ('\u{012d}', "i"),
+    ('\u{012e}', "I"),
+    ('\u{012f}', "i"),
+    ('\u{0130}', "I"),
+    ('\u{0131}', "i"),
+    ('\u{0132}', "IJ"),
+    ('\u{0133}', "ij"),
+    ('\u{0134}', "J"),
+    ('\u{0135}', "j"),
+    ('\u{0136}', "K"),
+    ('\u{0137}', "k"),
+    ('\u{0138}', "k"),
+    ('\u{0139}', "L"),
+    ('\u{013a}', "l"),
+    ('\u{013b}', "L"),
+    ('\u{013c}', "l"),
+    ('\u{013d}', "L"),
+    ('\u{013e}', "l"),
+    ('\u{013f}', "L"),
+    ('\u{0140}', "l"),
+    ('\u{0141}', "L"),
+    ('\u{0142}', "l"),
+    ('\u{0143}', "N"),
+    ('\u{0144}', "n"),
+    ('\u{0145}', "N"),
+    ('\u{0146}', "n"),
+    ('\u{0147}', "N"),
+    ('\u{0148}', "n"),
+    ('\u{0149}', "'n"),
+    ('\u{014a}', "N"),
+    ('\u{014b}', "n"),
+    ('\u{014c}', "O"),
+    ('\u{014d}', "o"),
+    ('\u{014e}', "O"),
+    ('\u{014f}', "o"),
+    ('\u{0150}', "O"),
+    ('\u{0151}', "o"),
+    ('\u{0152}', "Oe"),
+    ('\u{0153}', "oe"),
+    ('\u{0154}', "R"),
+    ('\u{0155}', "r"),
+    ('\u{0156}', "R"),
+    ('\u{0157}', "r"),
+    ('\u{0158}', "R"),
+    ('\u{0159}', "r"),
+    ('\u{015a}', "S"),
+    ('\u{015b}', "s"),
+    ('\u{015c}', "S"),
+    ('\u{015d}', "s"),
+    ('\u{015e}', "S"),
+    ('\u{015f}', "s"),
+    ('\u{0160}', "S"),
+    ('\u{0161}', "s"),
+    ('\u{0162}', "T"),
+    ('\u{0163}', "t"),
+    ('\u{0164}', "T"),
+    ('\u{0165}', "t"),
+    ('\u{0166}', "T"),
+    ('\u{0167}', "t"),
+    ('\u{0168}', "U"),
+    ('\u{0169}', "u"),
+    ('\u{016a}', "U"),
+    ('\u{016b}', "u"),
+    ('\u{016c}', "U"),
+    ('\u{016d}', "u"),
+    ('\u{016e}', "U"),
+    ('\u{016f}', "u"),
+    ('\u{0170}', "U"),
+    ('\u{0171}', "u"),
+    ('\u{0172}', "U"),
+    ('\u{0173}', "u"),
+    ('\u{0174}', "W"),
+    ('\u{0175}', "w"),
+    ('\u{0176}', "Y"),
+    ('\u{0177}', "y"),
+    ('\u{0178}', "Y"),
+    ('\u{0179}', "Z"),
+    ('\u{017a}', "z"),
+    ('\u{017b}', "Z"),
+    ('\u{017c}', "z"),
+    ('\u{017d}', "Z"),
+    ('\u{017e}', "z"),
+    ('\u{017f}', "s"),
+];
+
+/// The ASCII replacements of the letters of the Latin Extended-B, Latin Extended Additional,
+/// Greek and Cyrillic blocks and of common typographic punctuation, sorted by character. These
+/// complement [DEBURRED_LETTERS], which has no character in common with this table.
+pub(crate) static TRANSLITERATIONS: [(char, &str); 618] = [
+    ('\u{00a0}', " "),
+    ('\u{00ab}', "<<"),
+    ('\u{00bb}', ">>"),
+    ('\u{0180}', "b"),
+    ('\u{0181}', "B"),
+    ('\u{0182}', "B"),
+    ('\u{0183}', "b"),
+    ('\u{0187}', "C"),
+    ('\u{0188}', "c"),
+    ('\u{018a}', "D"),
+    ('\u{018b}', "D"),
+    ('\u{018c}', "d"),
+    ('\u{018e}', "E"),
+    ('\u{018f}', "E"),
+    ('\u{0191}', "F"),
+    ('\u{0192}', "f"),
+    ('\u{0193}', "G"),
+    ('\u{0195}', "hv"),
+    ('\u{0197}', "I"),
+    ('\u{0198}', "K"),
+    ('\u{0199}', "k"),
+    ('\u{019a}', "l"),
+    ('\u{019b}', "l"),
+    ('\u{019d}', "N"),
+    ('\u{019e}', "n"),
+    ('\u{019f}', "O"),
+    ('\u{01a0}', "O"),
+    ('\u{01a1}', "o"),
+    ('\u{01a2}', "Oi"),
+    ('\u{01a3}', "oi"),
+    ('\u{01a4}', "P"),
+    ('\u{01a5}', "p"),
+    ('\u{01ab}', "t"),
+    ('\u{01ac}', "T"),
+    ('\u{01ad}', "t"),
+    ('\u{01ae}', "T"),
+    ('\u{01af}', "U"),
+    ('\u{01b0}', "u"),
+    ('\u{01b2}', "V"),
+    ('\u{01b3}', "Y"),
+    ('\u{01b4}', "y"),
+    ('\u{01b5}', "Z"),
+    ('\u{01b6}', "z"),
+    ('\u{01c4}', "DZ"),
+    ('\u{01c5}', "Dz"),
+    ('\u{01c6}', "dz"),
+    ('\u{01c7}', "LJ"),
+    ('\u{01c8}', "Lj"),
+    ('\u{01c9}', "lj"),
+    ('\u{01ca}', "NJ"),
+    ('\u{01cb}', "Nj"),
+    ('\u{01cc}', "nj"),
+    ('\u{01cd}', "A"),
+    ('\u{01ce}', "a"),
+    ('\u{01cf}', "I"),
+    ('\u{01d0}', "i"),
+    ('\u{01d1}', "O"),
+    ('\u{01d2}', "o"),
+    ('\u{01d3}', "U"),
+    ('\u{01d4}', "u"),
+    ('\u{01d5}', "U"),
+    ('\u{01d6}', "u"),
+    ('\u{01d7}', "U"),
+    ('\u{01d8}', "u"),
+    ('\u{01d9}', "U"),
+    ('\u{01da}', "u"),
+    ('\u{01db}', "U"),
+    ('\u{01dc}', "u"),
+    ('\u{01dd}', "e"),
+    ('\u{01de}', "A"),
+    ('\u{01df}', "a"),
+    ('\u{01e0}', "A"),
+    ('\u{01e1}', "a"),
+    ('\u{01e2}', "Ae"),
+    ('\u{01e3}', "ae"),
+    ('\u{01e4}', "G"),
+    ('\u{01e5}', "g"),
+    ('\u{01e6}', "G"),
+    ('\u{01e7}', "g"),
+    ('\u{01e8}', "K"),
+    ('\u{01e9}', "k"),
+    ('\u{01ea}', "O"),
+    ('\u{01eb}', "o"),
+    ('\u{01ec}', "O"),
+    ('\u{01ed}', "o"),
+    ('\u{01f0}', "j"),
+    ('\u{01f1}', "DZ"),
+    ('\u{01f2}', "Dz"),
+    ('\u{01f3}', "dz"),
+    ('\u{01f4}', "G"),
+    ('\u{01f5}', "g"),
+    ('\u{01f8}', "N"),
+    ('\u{01f9}', "n"),
+    ('\u{01fa}', "A"),
+    ('\u{01fb}', "a"),
+    ('\u{01fc}', "Ae"),
+    ('\u{01fd}', "ae"),
+    ('\u{01fe}', "O"),
+    ('\u{01ff}', "o"),
+    ('\u{0200}', "A"),
+    ('\u{0201}', "a"),
+    ('\u{0202}', "A"),
+    ('\u{0203}', "a"),
+    ('\u{0204}', "E"),
+    ('\u{0205}', "e"),
+    ('\u{0206}', "E"),
+    ('\u{0207}', "e"),
+    ('\u{0208}', "I"),
+    ('\u{0209}', "i"),
+    ('\u{020a}', "I"),
+    ('\u{020b}', "i"),
+    ('\u{020c}', "O"),
+    ('\u{020d}', "o"),
+    ('\u{020e}', "O"),
+    ('\u{020f}', "o"),
+    ('\u{0210}', "R"),
+    ('\u{0211}', "r"),
+    ('\u{0212}', "R"),
+    ('\u{0213}', "r"),
+    ('\u{0214}', "U"),
+    ('\u{0215}', "u"),
+    ('\u{0216}', "U"),
+    ('\u{0217}', "u"),
+    ('\u{0218}', "S"),
+    ('\u{0219}', "s"),
+    ('\u{021a}', "T"),
+    ('\u{021b}', "t"),
+    ('\u{021e}', "H"),
+    ('\u{021f}', "h"),
+    ('\u{0220}', "N"),
+    ('\u{0221}', "d"),
+    ('\u{0222}', "Ou"),
+    ('\u{0223}', "ou"),
+    ('\u{0224}', "Z"),
+    ('\u{0225}', "z"),
+    ('\u{0226}', "A"),
+    ('\u{0227}', "a"),
+    ('\u{0228}', "E"),
+    ('\u{0229}', "e"),
+    ('\u{022a}', "O"),
+    ('\u{022b}', "o"),
+    ('\u{022c}', "O"),
+    ('\u{022d}', "o"),
+    ('\u{022e}', "O"),
+    ('\u{022f}', "o"),
+    ('\u{0230}', "O"),
+    ('\u{0231}', "o"),
+    ('\u{0232}', "Y"),
+    ('\u{0233}', "y"),
+    ('\u{0234}', "l"),
+    ('\u{0235}', "n"),
+    ('\u{0236}', "t"),
+    ('\u{023a}', "A"),
+    ('\u{023b}', "C"),
+    ('\u{023c}', "c"),
+    ('\u{023d}', "L"),
+    ('\u{023e}', "T"),
+    ('\u{023f}', "s"),
+    ('\u{0240}', "z"),
+    ('\u{0243}', "B"),
+    ('\u{0246}', "E"),
+    ('\u{0247}', "e"),
+    ('\u{0248}', "J"),
+    ('\u{0249}', "j"),
+    ('\u{024b}', "q"),
+    ('\u{024c}', "R"),
+    ('\u{024d}', "r"),
+    ('\u{024e}', "Y"),
+    ('\u{024f}', "y"),
+    ('\u{0259}', "e"),
+    ('\u{0386}', "A"),
+    ('\u{0388}', "E"),
+    ('\u{0389}', "I"),
+    ('\u{038a}', "I"),
+    ('\u{038c}', "O"),
+    ('\u{038e}', "Y"),
+    ('\u{038f}', "O"),
+    ('\u{0390}', "i"),
+    ('\u{0391}', "A"),
+    ('\u{0392}', "V"),
+    ('\u{0393}', "G"),
+    ('\u{0394}', "D"),
+    ('\u{0395}', "E"),
+    ('\u{0396}', "Z"),
+    ('\u{0397}', "I"),
+    ('\u{0398}', "Th"),
+    ('\u{0399}', "I"),
+    ('\u{039a}', "K"),
+    ('\u{039b}', "L"),
+    ('\u{039c}', "M"),
+    ('\u{039d}', "N"),
+    ('\u{039e}', "X"),
+    ('\u{039f}', "O"),
+    ('\u{03a0}', "P"),
+    ('\u{03a1}', "R"),
+    ('\u{03a3}', "S"),
+    ('\u{03a4}', "T"),
+    ('\u{03a5}', "Y"),
+    ('\u{03a6}', "F"),
+    ('\u{03a7}', "Ch"),
+    ('\u{03a8}', "Ps"),
+    ('\u{03a9}', "O"),
+    ('\u{03aa}', "I"),
+    ('\u{03ab}', "Y"),
+    ('\u{03ac}', "a"),
+    ('\u{03ad}', "e"),
+    ('\u{03ae}', "i"),
+    ('\u{03af}', "i"),
+    ('\u{03b0}', "y"),
+    ('\u{03b1}', "a"),
+    ('\u{03b2}', "v"),
+    ('\u{03b3}', "g"),
+    ('\u{03b4}', "d"),
+    ('\u{03b5}', "e"),
+    ('\u{03b6}', "z"),
+    ('\u{03b7}', "i"),
+    ('\u{03b8}', "th"),
+    ('\u{03b9}', "i"),
+    ('\u{03ba}', "k"),
+    ('\u{03bb}', "l"),
+    ('\u{03bc}', "m"),
+    ('\u{03bd}', "n"),
+    ('\u{03be}', "x"),
+    ('\u{03bf}', "o"),
+    ('\u{03c0}', "p"),
+    ('\u{03c1}', "r"),
+    ('\u{03c2}', "s"),
+    ('\u{03c3}', "s"),
+    ('\u{03c4}', "t"),
+    ('\u{03c5}', "y"),
+    ('\u{03c6}', "f"),
+    ('\u{03c7}', "ch"),
+    ('\u{03c8}', "ps"),
+    ('\u{03c9}', "o"),
+    ('\u{03ca}', "i"),
+    ('\u{03cb}', "y"),
+    ('\u{03cc}', "o"),
+    ('\u{03cd}', "y"),
+    ('\u{03ce}', "o"),
+    ('\u{0400}', "E"),
+    ('\u{0401}', "Yo"),
+    ('\u{0402}', "Dj"),
+    ('\u{0403}', "Gj"),
+    ('\u{0404}', "Ye"),
+    ('\u{0405}', "Dz"),
+    ('\u{0406}', "I"),
+    ('\u{0407}', "Yi"),
+    ('\u{0408}', "J"),
+    ('\u{0409}', "Lj"),
+    ('\u{040a}', "Nj"),
+    ('\u{040b}', "C"),
+    ('\u{040c}', "Kj"),
+    ('\u{040d}', "I"),
+    ('\u{040e}', "U"),
+    ('\u{040f}', "Dz"),
+    ('\u{0410}', "A"),
+    ('\u{0411}', "B"),
+    ('\u{0412}', "V"),
+    ('\u{0413}', "G"),
+    ('\u{0414}', "D"),
+    ('\u{0415}', "E"),
+    ('\u{0416}', "Zh"),
+    ('\u{0417}', "Z"),
+    ('\u{0418}', "I"),
+    ('\u{0419}', "Y"),
+    ('\u{041a}', "K"),
+    ('\u{041b}', "L"),
+    ('\u{041c}', "M"),
+    ('\u{041d}', "N"),
+    ('\u{041e}', "O"),
+    ('\u{041f}', "P"),
+    ('\u{0420}', "R"),
+    ('\u{0421}', "S"),
+    ('\u{0422}', "T"),
+    ('\u{0423}', "U"),
+    ('\u{0424}', "F"),
+    ('\u{0425}', "Kh"),
+    ('\u{0426}', "Ts"),
+    ('\u{0427}', "Ch"),
+    ('\u{0428}', "Sh"),
+    ('\u{0429}', "Shch"),
+    ('\u{042a}', ""),
+    ('\u{042b}', "Y"),
+    ('\u{042c}', ""),
+    ('\u{042d}', "E"),
+    ('\u{042e}', "Yu"),
+    ('\u{042f}', "Ya"),
+    ('\u{0430}', "a"),
+    ('\u{0431}', "b"),
+    ('\u{0432}', "v"),
+    ('\u{0433}', "g"),
+    ('\u{0434}', "d"),
+    ('\u{0435}', "e"),
+    ('\u{0436}', "zh"),
+    ('\u{0437}', "z"),
+    ('\u{0438}', "i"),
+    ('\u{0439}', "y"),
+    ('\u{043a}', "k"),
+    ('\u{043b}', "l"),
+    ('\u{043c}', "m"),
+    ('\u{043d}', "n"),
+    ('\u{043e}', "o"),
+    ('\u{043f}', "p"),
+    ('\u{0440}', "r"),
+    ('\u{0441}', "s"),
+    ('\u{0442}', "t"),
+    ('\u{0443}', "u"),
+    ('\u{0444}', "f"),
+    ('\u{0445}', "kh"),
+    ('\u{0446}', "ts"),
+    ('\u{0447}', "ch"),
+    ('\u{0448}', "sh"),
+    ('\u{0449}', "shch"),
+    ('\u{044a}', ""),
+    ('\u{044b}', "y"),
+    ('\u{044c}', ""),
+    ('\u{044d}', "e"),
+    ('\u{044e}', "yu"),
+    ('\u{044f}', "ya"),
+    ('\u{0450}', "e"),
+    ('\u{0451}', "yo"),
+    ('\u{0452}', "dj"),
+    ('\u{0453}', "gj"),
+    ('\u{0454}', "ye"),
+    ('\u{0455}', "dz"),
+    ('\u{0456}', "i"),
+    ('\u{0457}', "yi"),
+    ('\u{0458}', "j"),
+    ('\u{0459}', "lj"),
+    ('\u{045a}', "nj"),
+    ('\u{045b}', "c"),
+    ('\u{045c}', "kj"),
+    ('\u{045d}', "i"),
+    ('\u{045e}', "u"),
+    ('\u{045f}', "dz"),
+    ('\u{0490}', "G"),
+    ('\u{0491}', "g"),
+    ('\u{1e00}', "A"),
+    ('\u{1e01}', "a"),
+    ('\u{1e02}', "B"),
+    ('\u{1e03}', "b"),
+    ('\u{1e04}', "B"),
+    ('\u{1e05}', "b"),
+    ('\u{1e06}', "B"),
+    ('\u{1e07}', "b"),
+    ('\u{1e08}', "C"),
+    ('\u{1e09}', "c"),
+    ('\u{1e0a}', "D"),
+    ('\u{1e0b}', "d"),
+    ('\u{1e0c}', "D"),
+    ('\u{1e0d}', "d"),
+    ('\u{1e0e}', "D"),
+    ('\u{1e0f}', "d"),
+    ('\u{1e10}', "D"),
+    ('\u{1e11}', "d"),
+    ('\u{1e12}', "D"),
+    ('\u{1e13}', "d"),
+    ('\u{1e14}', "E"),
+    ('\u{1e15}', "e"),
+    ('\u{1e16}', "E"),
+    ('\u{1e17}', "e"),
+    ('\u{1e18}', "E"),
+    ('\u{1e19}', "e"),
+    ('\u{1e1a}', "E"),
+    ('\u{1e1b}', "e"),
+    ('\u{1e1c}', "E"),
+    ('\u{1e1d}', "e"),
+    ('\u{1e1e}', "F"),
+    ('\u{1e1f}', "f"),
+    ('\u{1e20}', "G"),
+    ('\u{1e21}', "g"),
+    ('\u{1e22}', "H"),
+    ('\u{1e23}', "h"),
+    ('\u{1e24}', "H"),
+    ('\u{1e25}', "h"),
+    ('\u{1e26}', "H"),
+    ('\u{1e27}', "h"),
+    ('\u{1e28}', "H"),
+    ('\u{1e29}', "h"),
+    ('\u{1e2a}', "H"),
+    ('\u{1e2b}', "h"),
+    ('\u{1e2c}', "I"),
+    ('\u{1e2d}', "i"),
+    ('\u{1e2e}', "I"),
+    ('\u{1e2f}', "i"),
+    ('\u{1e30}', "K"),
+    ('\u{1e31}', "k"),
+    ('\u{1e32}', "K"),
+    ('\u{1e33}', "k"),
+    ('\u{1e34}', "K"),
+    ('\u{1e35}', "k"),
+    ('\u{1e36}', "L"),
+    ('\u{1e37}', "l"),
+    ('\u{1e38}', "L"),
+    ('\u{1e39}', "l"),
+    ('\u{1e3a}', "L"),
+    ('\u{1e3b}', "l"),
+    ('\u{1e3c}', "L"),
+    ('\u{1e3d}', "l"),
+    ('\u{1e3e}', "M"),
+    ('\u{1e3f}', "m"),
+    ('\u{1e40}', "M"),
+    ('\u{1e41}', "m"),
+    ('\u{1e42}', "M"),
+    ('\u{1e43}', "m"),
+    ('\u{1e44}', "N"),
+    ('\u{1e45}', "n"),
+    ('\u{1e46}', "N"),
+    ('\u{1e47}', "n"),
+    ('\u{1e48}', "N"),
+    ('\u{1e49}', "n"),
+    ('\u{1e4a}', "N"),
+    ('\u{1e4b}', "n"),
+    ('\u{1e4c}', "O"),
+    ('\u{1e4d}', "o"),
+    ('\u{1e4e}', "O"),
+    ('\u{1e4f}', "o"),
+    ('\u{1e50}', "O"),
+    ('\u{1e51}', "o"),
+    ('\u{1e52}', "O"),
+    ('\u{1e53}', "o"),
+    ('\u{1e54}', "P"),
+    ('\u{1e55}', "p"),
+    ('\u{1e56}', "P"),
+    ('\u{1e57}', "p"),
+    ('\u{1e58}', "R"),
+    ('\u{1e59}', "r"),
+    ('\u{1e5a}', "R"),
+    ('\u{1e5b}', "r"),
+    ('\u{1e5c}', "R"),
+    ('\u{1e5d}', "r"),
+    ('\u{1e5e}', "R"),
+    ('\u{1e5f}', "r"),
+    ('\u{1e60}', "S"),
+    ('\u{1e61}', "s"),
+    ('\u{1e62}', "S"),
+    ('\u{1e63}', "s"),
+    ('\u{1e64}', "S"),
+    ('\u{1e65}', "s"),
+    ('\u{1e66}', "S"),
+    ('\u{1e67}', "s"),
+    ('\u{1e68}', "S"),
+    ('\u{1e69}', "s"),
+    ('\u{1e6a}', "T"),
+    ('\u{1e6b}', "t"),
+    ('\u{1e6c}', "T"),
+    ('\u{1e6d}', "t"),
+    ('\u{1e6e}', "T"),
+    ('\u{1e6f}', "t"),
+    ('\u{1e70}', "T"),
+    ('\u{1e71}', "t"),
+    ('\u{1e72}', "U"),
+    ('\u{1e73}', "u"),
+    ('\u{1e74}', "U"),
+    ('\u{1e75}', "u"),
+    ('\u{1e76}', "U"),
+    ('\u{1e77}', "u"),
+    ('\u{1e78}', "U"),
+    ('\u{1e79}', "u"),
+    ('\u{1e7a}', "U"),
+    ('\u{1e7b}', "u"),
+    ('\u{1e7c}', "V"),
+    ('\u{1e7d}', "v"),
+    ('\u{1e7e}', "V"),
+    ('\u{1e7f}', "v"),
+    ('\u{1e80}', "W"),
+    ('\u{1e81}', "w"),
+    ('\u{1e82}', "W"),
+    ('\u{1e83}', "w"),
+    ('\u{1e84}', "W"),
+    ('\u{1e85}', "w"),
+    ('\u{1e86}', "W"),
+    ('\u{1e87}', "w"),
+    ('\u{1e88}', "W"),
+    ('\u{1e89}', "w"),
+    ('\u{1e8a}', "X"),
+    ('\u{1e8b}', "x"),
+    ('\u{1e8c}', "X"),
+    ('\u{1e8d}', "x"),
+    ('\u{1e8e}', "Y"),
+    ('\u{1e8f}', "y"),
+    ('\u{1e90}', "Z"),
+    ('\u{1e91}', "z"),
+    ('\u{1e92}', "Z"),
+    ('\u{1e93}', "z"),
+    ('\u{1e94}', "Z"),
+    ('\u{1e95}', "z"),
+    ('\u{1e96}', "h"),
+    ('\u{1e97}', "t"),
+    ('\u{1e98}', "w"),
+    ('\u{1e99}', "y"),
+    ('\u{1e9a}', "a"),
+    ('\u{1e9b}', "s"),
+    ('\u{1e9e}', "SS"),
+    ('\u{1ea0}', "A"),
+    ('\u{1ea1}', "a"),
+    ('\u{1ea2}', "A"),
+    ('\u{1ea3}', "a"),
+    ('\u{1ea4}', "A"),
+    ('\u{1ea5}', "a"),
+    ('\u{1ea6}', "A"),
+    ('\u{1ea7}', "a"),
+    ('\u{1ea8}', "A"),
+    ('\u{1ea9}', "a"),
+    ('\u{1eaa}', "A"),
+    ('\u{1eab}', "a"),
+    ('\u{1eac}', "A"),
+    ('\u{1ead}', "a"),
+    ('\u{1eae}', "A"),
+    ('\u{1eaf}', "a"),
+    ('\u{1eb0}', "A"),
+    ('\u{1eb1}', "a"),
+    ('\u{1eb2}', "A"),
+    ('\u{1eb3}', "a"),
+    ('\u{1eb4}', "A"),
+    ('\u{1eb5}', "a"),
+    ('\u{1eb6}', "A"),
+    ('\u{1eb7}', "a"),
+    ('\u{1eb8}', "E"),
+    ('\u{1eb9}', "e"),
+    ('\u{1eba}', "E"),
+    ('\u{1ebb}', "e"),
+    ('\u{1ebc}', "E"),
+    ('\u{1ebd}', "e"),
+    ('\u{1ebe}', "E"),
+    ('\u{1ebf}', "e"),
+    ('\u{1ec0}', "E"),
+    ('\u{1ec1}', "e"),
+    ('\u{1ec2}', "E"),
+    ('\u{1ec3}', "e"),
+    ('\u{1ec4}', "E"),
+    ('\u{1ec5}', "e"),
+    ('\u{1ec6}', "E"),
+    ('\u{1ec7}', "e"),
+    ('\u{1ec8}', "I"),
+    ('\u{1ec9}', "i"),
+    ('\u{1eca}', "I"),
+    ('\u{1ecb}', "i"),
+    ('\u{1ecc}', "O"),
+    ('\u{1ecd}', "o"),
+    ('\u{1ece}', "O"),
+    ('\u{1ecf}', "o"),
+    ('\u{1ed0}', "O"),
+    ('\u{1ed1}', "o"),
+    ('\u{1ed2}', "O"),
+    ('\u{1ed3}', "o"),
+    ('\u{1ed4}', "O"),
+    ('\u{1ed5}', "o"),
+    ('\u{1ed6}', "O"),
+    ('\u{1ed7}', "o"),
+    ('\u{1ed8}', "O"),
+    ('\u{1ed9}', "o"),
+    ('\u{1eda}', "O"),
+    ('\u{1edb}', "o"),
+    ('\u{1edc}', "O"),
+    ('\u{1edd}', "o"),
+    ('\u{1ede}', "O"),
+    ('\u{1edf}', "o"),
+    ('\u{1ee0}', "O"),
+    ('\u{1ee1}', "o"),
+    ('\u{1ee2}', "O"),
+    ('\u{1ee3}', "o"),
+    ('\u{1ee4}', "U"),
+    ('\u{1ee5}', "u"),
+    ('\u{1ee6}', "U"),
+    ('\u{1ee7}', "u"),
+    ('\u{1ee8}', "U"),
+    ('\u{1ee9}', "u"),
+    ('\u{1eea}', "U"),
+    ('\u{1eeb}', "u"),
+    ('\u{1eec}', "U"),
+    ('\u{1eed}', "u"),
+    ('\u{1eee}', "U"),
+    ('\u{1eef}', "u"),
+    ('\u{1ef0}', "U"),
+    ('\u{1ef1}', "u"),
+    ('\u{1ef2}', "Y"),
+    ('\u{1ef3}', "y"),
+    ('\u{1ef4}', "Y"),
+    ('\u{1ef5}', "y"),
+    ('\u{1ef6}', "Y"),
+    ('\u{1ef7}', "y"),
+    ('\u{1ef8}', "Y"),
+    ('\u{1ef9}', "y"),
+    ('\u{1efe}', "Y"),
+    ('\u{1eff}', "y"),
+    ('\u{2000}', " "),
+    ('\u{2001}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2004}', " "),
+    ('\u{2005}', " "),
+    ('\u{2006}', " "),
+    ('\u{2007}', " "),
+    ('\u{2008}', " "),
+    ('\u{2009}', " "),
+    ('\u{200a}', " "),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+    ('\u{2015}', "-"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201a}', "'"),
+    ('\u{201b}', "'"),
+    ('\u{201c}', "\""),
+    ('\u{201d}', "\""),
+    ('\u{201e}', "\""),
+    ('\u{201f}', "\""),
+    ('\u{2026}', "..."),
+    ('\u{202f}', " "),
+    ('\u{2032}', "'"),
+    ('\u{2033}', "\""),
+    ('\u{2039}', "<"),
+    ('\u{203a}', ">"),
+    ('\u{205f}', " "),
+];
+
+/// Returns the replacement of `c` in `table`, which is sorted by character.
+pub(crate) fn lookup(table: &[(char, &'static str)], c: char) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&c, |&(key, _)| key)
+        .ok()
+        .map(|index| table[index].1)
+}
@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+/// Removes the characters in `chars` from the start and end of `value`, like the lodash `_.trim`
+/// function. Unlike [str::trim], which removes whitespace, any of the characters of `chars` are
+/// removed, in any order. Nothing is removed if `chars` is empty.
+///
+/// * `value` - The string to trim.
+/// * `chars` - The characters to remove.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::trim;
+///
+/// assert_eq!(trim("-_-abc-_-", "_-"), "abc");
+/// assert_eq!(trim("  abc  ", " "), "abc");
+/// ```
+pub fn trim<'a>(value: &'a str, chars: &str) -> Cow<'a, str> {
+    Cow::Borrowed(value.trim_matches(|c| chars.contains(c)))
+}
+
+/// A trait that implements the [Trim::trim_chars] method on strings.
+pub trait Trim<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Removes the characters in `chars` from the start and end of this string. This method is not
+    /// named `trim` so as not to be shadowed by [str::trim].
+    ///
+    /// * `chars` - The characters to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Trim;
+    ///
+    /// assert_eq!("__init__".trim_chars("_"), "init");
+    /// ```
+    fn trim_chars(&self, chars: &str) -> Cow<'_, str>;
+}
+
+impl Trim<String> for String {
+    fn trim_chars(&self, chars: &str) -> Cow<'_, str> {
+        trim(self, chars)
+    }
+}
+
+impl Trim<str> for str {
+    fn trim_chars(&self, chars: &str) -> Cow<'_, str> {
+        trim(self, chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_trim_chars() {
+        assert_eq!(trim("", "ab"), "");
+        assert_eq!(trim("abba", "ab"), "");
+        assert_eq!(trim("  abc  ", ""), "  abc  ");
+        assert_eq!(trim("¡¿Qué?!", "¡¿?!"), "Qué");
+        assert_eq!("🦀rust🦀".to_string().trim_chars("🦀"), "rust");
+        assert_eq!("\t x \n".trim_chars(" \t\n"), "x");
+    }
+}
@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+
+/// Removes the characters in `chars` from the end of `value`, like the lodash `_.trimEnd`
+/// function. Unlike [str::trim_end], which removes whitespace, any of the characters of `chars` are
+/// removed, in any order. Nothing is removed if `chars` is empty.
+///
+/// * `value` - The string to trim.
+/// * `chars` - The characters to remove.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::trim_end;
+///
+/// assert_eq!(trim_end("-_-abc-_-", "_-"), "-_-abc");
+/// assert_eq!(trim_end("  abc  ", " "), "  abc");
+/// ```
+pub fn trim_end<'a>(value: &'a str, chars: &str) -> Cow<'a, str> {
+    Cow::Borrowed(value.trim_end_matches(|c| chars.contains(c)))
+}
+
+/// A trait that implements the [TrimEnd::trim_end_chars] method on strings.
+pub trait TrimEnd<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Removes the characters in `chars` from the end of this string. This method is not named
+    /// `trim_end` so as not to be shadowed by [str::trim_end].
+    ///
+    /// * `chars` - The characters to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::TrimEnd;
+    ///
+    /// assert_eq!("__init__".trim_end_chars("_"), "__init");
+    /// ```
+    fn trim_end_chars(&self, chars: &str) -> Cow<'_, str>;
+}
+
+impl TrimEnd<String> for String {
+    fn trim_end_chars(&self, chars: &str) -> Cow<'_, str> {
+        trim_end(self, chars)
+    }
+}
+
+impl TrimEnd<str> for str {
+    fn trim_end_chars(&self, chars: &str) -> Cow<'_, str> {
+        trim_end(self, chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_trim_end_chars() {
+        assert_eq!(trim_end("", "ab"), "");
+        assert_eq!(trim_end("abba", "ab"), "");
+        assert_eq!(trim_end("  abc  ", ""), "  abc  ");
+        assert_eq!(trim_end("¡¿Qué?!", "¡¿?!"), "¡¿Qué");
+        assert_eq!("/srv/www//".to_string().trim_end_chars("/"), "/srv/www");
+    }
+}
@@ -0,0 +1,100 @@
+use std::borrow::Cow;
+
+/// Splits `line` into its content and its "\n" or "\r\n" line break.
+fn split_line_break(line: &str) -> (&str, &str) {
+    let content = line
+        .strip_suffix("\r\n")
+        .or_else(|| line.strip_suffix('\n'))
+        .unwrap_or(line);
+
+    line.split_at(content.len())
+}
+
+/// Removes the leading and trailing whitespace of every line of `value`, keeping its line
+/// breaks. Returns `value` as is, without allocating, when none of its lines has leading or
+/// trailing whitespace.
+///
+/// * `value` - The string to trim.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::trim_lines;
+///
+/// assert_eq!(
+///     trim_lines("  12 Main Street \r\n\tSpringfield\n"),
+///     "12 Main Street\r\nSpringfield\n"
+/// );
+/// ```
+pub fn trim_lines(value: &str) -> Cow<'_, str> {
+    let is_trimmed = value.split_inclusive('\n').all(|line| {
+        let (content, _) = split_line_break(line);
+
+        content.trim().len() == content.len()
+    });
+
+    if is_trimmed {
+        return Cow::Borrowed(value);
+    }
+
+    let mut trimmed = String::with_capacity(value.len());
+
+    for line in value.split_inclusive('\n') {
+        let (content, line_break) = split_line_break(line);
+
+        trimmed.push_str(content.trim());
+        trimmed.push_str(line_break);
+    }
+
+    Cow::Owned(trimmed)
+}
+
+/// A trait that implements the [TrimLines::trim_lines] method on strings.
+pub trait TrimLines<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Removes the leading and trailing whitespace of every line of this string, keeping its
+    /// line breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::TrimLines;
+    ///
+    /// assert_eq!(" fred \n barney ".trim_lines(), "fred\nbarney");
+    /// ```
+    fn trim_lines(&self) -> Cow<'_, str>;
+}
+
+impl TrimLines<String> for String {
+    fn trim_lines(&self) -> Cow<'_, str> {
+        trim_lines(self)
+    }
+}
+
+impl TrimLines<str> for str {
+    fn trim_lines(&self) -> Cow<'_, str> {
+        trim_lines(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_trim_lines() {
+        assert_eq!(trim_lines(""), "");
+        assert_eq!(trim_lines(" \n\t\r\n "), "\n\r\n");
+        assert_eq!(trim_lines("a \r\n\u{a0}b\u{3000}"), "a\r\nb");
+        assert_eq!("  one  \n\n  two".to_string().trim_lines(), "one\n\ntwo");
+    }
+
+    #[test]
+    fn can_borrow_trimmed_lines() {
+        assert!(matches!(trim_lines("a\r\nb\n\nc\n"), Cow::Borrowed(_)));
+        assert!(matches!(trim_lines("a\r\r\nb"), Cow::Owned(_)));
+        assert!(matches!("a \nb".trim_lines(), Cow::Owned(_)));
+    }
+}
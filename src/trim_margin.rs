@@ -0,0 +1,141 @@
+/// Removes the leading whitespace and the "|" margin from the lines of `text`, as in Kotlin, so
+/// that multiline string literals can be indented with the surrounding code. The first and last
+/// lines are removed if they are blank, and lines without the margin are left as they are.
+///
+/// * `text` - The text to trim.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::trim_margin;
+///
+/// let text = "
+///     |Usage: rodash [options]
+///     |
+///     |  -h, --help  Print help
+///     ";
+/// assert_eq!(
+///     trim_margin(text),
+///     "Usage: rodash [options]\n\n  -h, --help  Print help".to_string()
+/// );
+/// ```
+pub fn trim_margin<S>(text: S) -> String
+where
+    S: AsRef<str>,
+{
+    trim_margin_with(text, "|")
+}
+
+/// This method is like [trim_margin] except that the lines start with `margin` instead of "|".
+/// With an empty `margin`, only the leading whitespace is removed.
+///
+/// * `text` - The text to trim.
+/// * `margin` - The string after the leading whitespace of the lines.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::trim_margin_with;
+///
+/// assert_eq!(
+///     trim_margin_with("  #> one\n  #> two", "#> "),
+///     "one\ntwo".to_string()
+/// );
+/// ```
+pub fn trim_margin_with<S>(text: S, margin: &str) -> String
+where
+    S: AsRef<str>,
+{
+    let mut lines: Vec<&str> = text.as_ref().lines().collect();
+
+    if lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    if lines.first().is_some_and(|line| line.trim().is_empty()) {
+        lines.remove(0);
+    }
+
+    lines
+        .into_iter()
+        .map(|line| line.trim_start().strip_prefix(margin).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A trait that implements the [TrimMargin::trim_margin] method on strings.
+pub trait TrimMargin<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Removes the leading whitespace and the "|" margin from the lines of this string, and its
+    /// first and last lines if they are blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::TrimMargin;
+    ///
+    /// assert_eq!("\n  |a\n  |  b\n".trim_margin(), "a\n  b".to_string());
+    /// ```
+    fn trim_margin(&self) -> String;
+
+    /// This method is like [TrimMargin::trim_margin] except that the lines start with `margin`
+    /// instead of "|".
+    ///
+    /// * `margin` - The string after the leading whitespace of the lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::TrimMargin;
+    ///
+    /// assert_eq!("  > a\n  > b".trim_margin_with("> "), "a\nb".to_string());
+    /// ```
+    fn trim_margin_with(&self, margin: &str) -> String;
+}
+
+impl TrimMargin<String> for String {
+    fn trim_margin(&self) -> String {
+        trim_margin(self)
+    }
+
+    fn trim_margin_with(&self, margin: &str) -> String {
+        trim_margin_with(self, margin)
+    }
+}
+
+impl TrimMargin<str> for str {
+    fn trim_margin(&self) -> String {
+        trim_margin(self)
+    }
+
+    fn trim_margin_with(&self, margin: &str) -> String {
+        trim_margin_with(self, margin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_trim_margin() {
+        assert_eq!(trim_margin(""), "".to_string());
+        assert_eq!(trim_margin("\n  \n"), "".to_string());
+        assert_eq!(
+            "  |a\r\n  no margin\r\n\t|  b|c".trim_margin(),
+            "a\n  no margin\n  b|c".to_string()
+        );
+        assert_eq!(
+            "\n  |a\n\n  |b\n  ".to_string().trim_margin(),
+            "a\n\nb".to_string()
+        );
+    }
+
+    #[test]
+    fn can_trim_other_margins() {
+        assert_eq!(trim_margin_with("  a\n    b", ""), "a\nb".to_string());
+        assert_eq!("  ::a\n  :b".trim_margin_with("::"), "a\n  :b".to_string());
+    }
+}
@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+
+/// Removes the characters in `chars` from the start of `value`, like the lodash `_.trimStart`
+/// function. Unlike [str::trim_start], which removes whitespace, any of the characters of `chars`
+/// are removed, in any order. Nothing is removed if `chars` is empty.
+///
+/// * `value` - The string to trim.
+/// * `chars` - The characters to remove.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::trim_start;
+///
+/// assert_eq!(trim_start("-_-abc-_-", "_-"), "abc-_-");
+/// assert_eq!(trim_start("  abc  ", " "), "abc  ");
+/// ```
+pub fn trim_start<'a>(value: &'a str, chars: &str) -> Cow<'a, str> {
+    Cow::Borrowed(value.trim_start_matches(|c| chars.contains(c)))
+}
+
+/// A trait that implements the [TrimStart::trim_start_chars] method on strings.
+pub trait TrimStart<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Removes the characters in `chars` from the start of this string. This method is not named
+    /// `trim_start` so as not to be shadowed by [str::trim_start].
+    ///
+    /// * `chars` - The characters to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::TrimStart;
+    ///
+    /// assert_eq!("__init__".trim_start_chars("_"), "init__");
+    /// ```
+    fn trim_start_chars(&self, chars: &str) -> Cow<'_, str>;
+}
+
+impl TrimStart<String> for String {
+    fn trim_start_chars(&self, chars: &str) -> Cow<'_, str> {
+        trim_start(self, chars)
+    }
+}
+
+impl TrimStart<str> for str {
+    fn trim_start_chars(&self, chars: &str) -> Cow<'_, str> {
+        trim_start(self, chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_trim_start_chars() {
+        assert_eq!(trim_start("", "ab"), "");
+        assert_eq!(trim_start("abba", "ab"), "");
+        assert_eq!(trim_start("  abc  ", ""), "  abc  ");
+        assert_eq!(trim_start("¡¿Qué?!", "¡¿?!"), "Qué?!");
+        assert_eq!("00042".to_string().trim_start_chars("0"), "42");
+    }
+}
@@ -0,0 +1,429 @@
+use regex::Regex;
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The unit in which the length of a string is measured.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    /// Extended grapheme clusters, which are the characters a reader perceives, such as "é"
+    /// written as "e" followed by a combining acute accent, or a family emoji.
+    #[default]
+    Graphemes,
+    /// Unicode scalar values, as counted by [str::chars].
+    Chars,
+    /// UTF-8 bytes, as counted by [str::len].
+    Bytes,
+    /// Columns of a monospace terminal, in which East Asian wide characters and most emoji take
+    /// two columns and combining marks take none.
+    Width,
+}
+
+impl LengthUnit {
+    /// Returns the length of `value` in this unit.
+    pub(crate) fn measure(self, value: &str) -> usize {
+        match self {
+            Self::Graphemes => value.graphemes(true).count(),
+            Self::Chars => value.chars().count(),
+            Self::Bytes => value.len(),
+            Self::Width => value.width(),
+        }
+    }
+}
+
+/// The separator that a truncated string is cut at, used by [truncate].
+#[derive(Debug, Clone, Copy)]
+pub enum TruncateSeparator<'a> {
+    /// A literal separator, such as " ".
+    Text(&'a str),
+    /// A pattern that matches separators, such as `,? +`.
+    Pattern(&'a Regex),
+}
+
+/// The options used by [truncate].
+#[derive(Debug, Clone, Copy)]
+pub struct TruncateOptions<'a> {
+    /// The maximum length of the truncated string, including the omission. Defaults to 30.
+    pub length: usize,
+    /// The string appended to a truncated string to indicate that text is omitted. Defaults to
+    /// "...".
+    pub omission: &'a str,
+    /// The separator that a truncated string is cut at, so that it ends at the last separator
+    /// before the omission instead of within a word. Defaults to `None`.
+    pub separator: Option<TruncateSeparator<'a>>,
+    /// The unit of `length`. Defaults to [LengthUnit::Graphemes].
+    pub unit: LengthUnit,
+}
+
+impl Default for TruncateOptions<'_> {
+    fn default() -> Self {
+        Self {
+            length: 30,
+            omission: "...",
+            separator: None,
+            unit: LengthUnit::Graphemes,
+        }
+    }
+}
+
+/// Truncates `value` if it is longer than the given maximum length, like the lodash
+/// `_.truncate` function. The last characters of the truncated string are replaced with the
+/// omission string. Grapheme clusters are never split, whatever the unit of the length.
+///
+/// * `value` - The string to truncate.
+/// * `options` - The maximum length, omission, separator and length unit.
+///
+/// # Examples
+///
+/// ```
+/// use regex::Regex;
+/// use rodash::{
+///     truncate,
+///     TruncateOptions,
+///     TruncateSeparator,
+/// };
+///
+/// let value = "hi-diddly-ho there, neighborino";
+/// assert_eq!(
+///     truncate(value, TruncateOptions::default()),
+///     "hi-diddly-ho there, neighbo...".to_string()
+/// );
+///
+/// let options = TruncateOptions {
+///     length: 24,
+///     separator: Some(TruncateSeparator::Text(" ")),
+///     ..TruncateOptions::default()
+/// };
+/// assert_eq!(
+///     truncate(value, options),
+///     "hi-diddly-ho there,...".to_string()
+/// );
+///
+/// let separator = Regex::new(",? +").unwrap();
+/// let options = TruncateOptions {
+///     length: 24,
+///     separator: Some(TruncateSeparator::Pattern(&separator)),
+///     ..TruncateOptions::default()
+/// };
+/// assert_eq!(
+///     truncate(value, options),
+///     "hi-diddly-ho there...".to_string()
+/// );
+///
+/// let options = TruncateOptions {
+///     omission: " [...]",
+///     ..TruncateOptions::default()
+/// };
+/// assert_eq!(
+///     truncate(value, options),
+///     "hi-diddly-ho there, neig [...]".to_string()
+/// );
+/// ```
+pub fn truncate<S>(value: S, options: TruncateOptions<'_>) -> String
+where
+    S: AsRef<str>,
+{
+    truncate_cow(value.as_ref(), options).into_owned()
+}
+
+/// This method is like [truncate] except that it returns `value` as is, without allocating, when
+/// it is not longer than the maximum length.
+///
+/// * `value` - The string to truncate.
+/// * `options` - The maximum length, omission, separator and length unit.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     truncate_cow,
+///     LengthUnit,
+///     TruncateOptions,
+/// };
+/// use std::borrow::Cow;
+///
+/// let options = TruncateOptions {
+///     length: 6,
+///     omission: "…",
+///     unit: LengthUnit::Width,
+///     ..TruncateOptions::default()
+/// };
+/// assert_eq!(truncate_cow("日本語です", options), "日本…");
+/// assert!(matches!(truncate_cow("日本語", options), Cow::Borrowed(_)));
+/// ```
+pub fn truncate_cow<'a>(value: &'a str, options: TruncateOptions<'_>) -> Cow<'a, str> {
+    let unit = options.unit;
+
+    if unit.measure(value) <= options.length {
+        return Cow::Borrowed(value);
+    }
+
+    let budget = options
+        .length
+        .saturating_sub(unit.measure(options.omission));
+
+    if budget == 0 {
+        return Cow::Owned(options.omission.to_owned());
+    }
+
+    let mut end = 0;
+    let mut length = 0;
+
+    for (index, grapheme) in value.grapheme_indices(true) {
+        length += unit.measure(grapheme);
+
+        if length > budget {
+            break;
+        }
+
+        end = index + grapheme.len();
+    }
+
+    let mut truncated = &value[..end];
+
+    match options.separator {
+        Some(TruncateSeparator::Text(separator))
+            if !separator.is_empty() && !value[end..].starts_with(separator) =>
+        {
+            if let Some(index) = truncated.rfind(separator) {
+                truncated = &truncated[..index];
+            }
+        }
+        Some(TruncateSeparator::Pattern(separator))
+            if separator
+                .find_at(value, end)
+                .is_none_or(|found| found.start() != end) =>
+        {
+            if let Some(found) = separator.find_iter(truncated).last() {
+                truncated = &truncated[..found.start()];
+            }
+        }
+        _ => {}
+    }
+
+    Cow::Owned([truncated, options.omission].concat())
+}
+
+/// A trait that implements the [Truncate::truncated] method on strings.
+pub trait Truncate<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Truncates this string if it is longer than the given maximum length, like the lodash
+    /// `_.truncate` function. This method is not named `truncate` so as not to be shadowed by
+    /// [String::truncate].
+    ///
+    /// * `options` - The maximum length, omission, separator and length unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     Truncate,
+    ///     TruncateOptions,
+    /// };
+    ///
+    /// let options = TruncateOptions {
+    ///     length: 10,
+    ///     ..TruncateOptions::default()
+    /// };
+    /// assert_eq!("fred, barney".truncated(options), "fred, b...".to_string());
+    /// ```
+    fn truncated(&self, options: TruncateOptions<'_>) -> String;
+
+    /// This method is like [Truncate::truncated] except that it returns this string as is,
+    /// without allocating, when it is not longer than the maximum length.
+    ///
+    /// * `options` - The maximum length, omission, separator and length unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     Truncate,
+    ///     TruncateOptions,
+    /// };
+    ///
+    /// let options = TruncateOptions {
+    ///     length: 4,
+    ///     omission: "~",
+    ///     ..TruncateOptions::default()
+    /// };
+    /// assert_eq!("fred".truncated_cow(options), "fred");
+    /// assert_eq!("barney".truncated_cow(options), "bar~");
+    /// ```
+    fn truncated_cow(&self, options: TruncateOptions<'_>) -> Cow<'_, str>;
+}
+
+impl Truncate<String> for String {
+    fn truncated(&self, options: TruncateOptions<'_>) -> String {
+        truncate(self, options)
+    }
+
+    fn truncated_cow(&self, options: TruncateOptions<'_>) -> Cow<'_, str> {
+        truncate_cow(self, options)
+    }
+}
+
+impl Truncate<str> for str {
+    fn truncated(&self, options: TruncateOptions<'_>) -> String {
+        truncate(self, options)
+    }
+
+    fn truncated_cow(&self, options: TruncateOptions<'_>) -> Cow<'_, str> {
+        truncate_cow(self, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the default options with the given `length` and `unit`.
+    fn options(length: usize, unit: LengthUnit) -> TruncateOptions<'static> {
+        TruncateOptions {
+            length,
+            unit,
+            ..TruncateOptions::default()
+        }
+    }
+
+    #[test]
+    fn can_keep_short_strings() {
+        assert_eq!(truncate("", TruncateOptions::default()), "".to_string());
+        assert_eq!(
+            truncate("fred", options(4, LengthUnit::Graphemes)),
+            "fred".to_string()
+        );
+        assert!(matches!(
+            truncate_cow("fred", options(4, LengthUnit::Bytes)),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn can_truncate_with_omissions() {
+        assert_eq!(
+            truncate("fred barney", options(7, LengthUnit::Graphemes)),
+            "fred...".to_string()
+        );
+        assert_eq!(
+            "fred barney".truncated(options(3, LengthUnit::Graphemes)),
+            "...".to_string()
+        );
+        assert_eq!(
+            "fred barney"
+                .to_string()
+                .truncated(options(2, LengthUnit::Graphemes)),
+            "...".to_string()
+        );
+
+        let options = TruncateOptions {
+            length: 5,
+            omission: "",
+            ..TruncateOptions::default()
+        };
+        assert_eq!(truncate("fred barney", options), "fred ".to_string());
+    }
+
+    #[test]
+    fn can_truncate_in_every_unit() {
+        // "é" is written with a combining acute accent.
+        let value = "ae\u{301}日本👨‍👩‍👧x";
+
+        assert_eq!(
+            truncate(value, options(6, LengthUnit::Graphemes)),
+            value.to_string()
+        );
+        assert_eq!(
+            truncate(value, options(5, LengthUnit::Graphemes)),
+            "ae\u{301}...".to_string()
+        );
+        assert_eq!(
+            truncate(value, options(7, LengthUnit::Chars)),
+            "ae\u{301}日...".to_string()
+        );
+        assert_eq!(
+            truncate(value, options(6, LengthUnit::Chars)),
+            "ae\u{301}...".to_string()
+        );
+        assert_eq!(
+            truncate(value, options(9, LengthUnit::Bytes)),
+            "ae\u{301}...".to_string()
+        );
+        assert_eq!(
+            truncate(value, options(6, LengthUnit::Bytes)),
+            "a...".to_string()
+        );
+        assert_eq!(
+            truncate(value, options(9, LengthUnit::Width)),
+            value.to_string()
+        );
+        assert_eq!(
+            truncate(value, options(8, LengthUnit::Width)),
+            "ae\u{301}日...".to_string()
+        );
+    }
+
+    #[test]
+    fn can_keep_grapheme_clusters_whole() {
+        let family = "👨‍👩‍👧";
+        let families = [family, family, family].concat();
+
+        assert_eq!(
+            truncate(&families, options(3, LengthUnit::Graphemes)),
+            families
+        );
+        assert_eq!(
+            truncate(&families, options(13, LengthUnit::Chars)),
+            [family, family, "..."].concat()
+        );
+        assert_eq!(
+            truncate(&families, options(12, LengthUnit::Chars)),
+            [family, "..."].concat()
+        );
+    }
+
+    #[test]
+    fn can_truncate_at_separators() {
+        let value = "hi-diddly-ho there, neighborino";
+        let separator = Regex::new(",? +").unwrap();
+        let with_separator = |length, separator| TruncateOptions {
+            length,
+            separator: Some(separator),
+            ..TruncateOptions::default()
+        };
+
+        assert_eq!(
+            truncate(value, with_separator(24, TruncateSeparator::Text(" "))),
+            "hi-diddly-ho there,...".to_string()
+        );
+        assert_eq!(
+            truncate(
+                value,
+                with_separator(24, TruncateSeparator::Pattern(&separator))
+            ),
+            "hi-diddly-ho there...".to_string()
+        );
+        assert_eq!(
+            truncate(value, with_separator(23, TruncateSeparator::Text(" "))),
+            "hi-diddly-ho there,...".to_string()
+        );
+        assert_eq!(
+            truncate(value, with_separator(22, TruncateSeparator::Text(" "))),
+            "hi-diddly-ho there,...".to_string()
+        );
+        assert_eq!(
+            truncate(value, with_separator(15, TruncateSeparator::Text(" "))),
+            "hi-diddly-ho...".to_string()
+        );
+        assert_eq!(
+            truncate(value, with_separator(10, TruncateSeparator::Text("-"))),
+            "hi...".to_string()
+        );
+        assert_eq!(
+            truncate(value, with_separator(8, TruncateSeparator::Text(" "))),
+            "hi-di...".to_string()
+        );
+    }
+}
@@ -1,13 +1,47 @@
-use once_cell::sync::Lazy;
-use regex::{
-    Captures,
-    Regex,
+use crate::{
+    codec::{
+        self,
+        Decoded,
+        Decoder,
+    },
+    entities::{
+        C1_REPLACEMENTS,
+        ENTITIES,
+        MAX_ENTITY_LENGTH,
+    },
 };
+use std::{
+    borrow::Cow,
+    fmt,
+    io,
+};
+
+/// The set of character references understood by [unescape_with].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnescapeMode {
+    /// Only the entities `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&#39;` are unescaped, matching
+    /// the behaviour of lodash and [unescape].
+    #[default]
+    Lodash,
+    /// All named character references defined by the [WHATWG HTML standard], decimal and
+    /// hexadecimal numeric references, and the legacy named references that may omit the trailing
+    /// semicolon (such as `&amp` and `&copy`) are unescaped, the same way a browser decodes text
+    /// content.
+    ///
+    /// [WHATWG HTML standard]: https://html.spec.whatwg.org/multipage/named-characters.html
+    Html5,
+}
 
-/// Used to match HTML entities and HTML characters.
-static ESCAPED_HTML: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"&(?:amp|lt|gt|quot|#(0+)?39);"#).expect("unable to build the pattern")
-});
+impl Decoder for UnescapeMode {
+    const MARKER: u8 = b'&';
+
+    fn decode(&self, value: &str) -> Option<(Decoded, usize)> {
+        match self {
+            Self::Lodash => decode_lodash_reference(value),
+            Self::Html5 => decode_html5_reference(value),
+        }
+    }
+}
 
 /// The inverse of [escape]. This method converts the HTML entities `&amp;`, `&lt;`, `&gt;`,
 /// `&quot;` and `&#39;` in `value` to their corresponding characters.
@@ -34,26 +68,229 @@ pub fn unescape<S>(value: S) -> String
 where
     S: AsRef<str>,
 {
-    let value = value.as_ref();
-
-    if ESCAPED_HTML.is_match(value) {
-        ESCAPED_HTML
-            .replace_all(value, |cap: &Captures| {
-                let item = &cap[0];
-
-                match item {
-                    "&amp;" => "&",
-                    "&lt;" => "<",
-                    "&gt;" => ">",
-                    "&quot;" => r#"""#,
-                    _ => "'",
-                }
-                .to_owned()
-            })
-            .to_string()
-    } else {
-        value.to_string()
+    unescape_cow(value.as_ref(), UnescapeMode::Lodash).into_owned()
+}
+
+/// This method is like [unescape] except that it accepts the set of character references to
+/// unescape.
+///
+/// In [UnescapeMode::Html5] mode, numeric references to the null character, surrogates or code
+/// points outside the Unicode range are replaced with U+FFFD, and references to C1 control
+/// characters are mapped to their windows-1252 counterparts. References that cannot be resolved
+/// are left untouched.
+///
+/// * `value` - The string to unescape.
+/// * `mode` - The set of character references to unescape.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     unescape_with,
+///     UnescapeMode,
+/// };
+///
+/// assert_eq!(
+///     unescape_with("&copy; fred &amp barney &#x2F; &#96;", UnescapeMode::Html5),
+///     "© fred & barney / `".to_string()
+/// );
+/// ```
+pub fn unescape_with<S>(value: S, mode: UnescapeMode) -> String
+where
+    S: AsRef<str>,
+{
+    unescape_cow(value.as_ref(), mode).into_owned()
+}
+
+/// This method is like [unescape_with] except that it returns `value` as is, without allocating,
+/// when it has nothing to unescape.
+///
+/// * `value` - The string to unescape.
+/// * `mode` - The set of character references to unescape.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     unescape_cow,
+///     UnescapeMode,
+/// };
+/// use std::borrow::Cow;
+///
+/// assert_eq!(
+///     unescape_cow("fred &amp; barney", UnescapeMode::Lodash),
+///     "fred & barney"
+/// );
+/// assert!(matches!(
+///     unescape_cow("fred, barney", UnescapeMode::Html5),
+///     Cow::Borrowed(_)
+/// ));
+/// ```
+pub fn unescape_cow(value: &str, mode: UnescapeMode) -> Cow<'_, str> {
+    codec::decode_cow(&mode, value)
+}
+
+/// This method is like [unescape_with] except that it writes the unescaped value into `out`,
+/// without allocating.
+///
+/// * `value` - The string to unescape.
+/// * `mode` - The set of character references to unescape.
+/// * `out` - The writer to write the unescaped value into.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     unescape_to,
+///     UnescapeMode,
+/// };
+///
+/// let mut text = String::new();
+/// unescape_to("fred &amp; barney", UnescapeMode::Lodash, &mut text).unwrap();
+/// assert_eq!(text, "fred & barney");
+/// ```
+pub fn unescape_to<W>(value: &str, mode: UnescapeMode, out: &mut W) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    codec::decode_to(&mode, value, out)
+}
+
+/// This method is like [unescape_with] except that it writes the unescaped value into the I/O
+/// writer `out`, without allocating.
+///
+/// * `value` - The string to unescape.
+/// * `mode` - The set of character references to unescape.
+/// * `out` - The writer to write the unescaped value into.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     unescape_to_io,
+///     UnescapeMode,
+/// };
+///
+/// let mut text = Vec::new();
+/// unescape_to_io("fred &amp; barney", UnescapeMode::Lodash, &mut text).unwrap();
+/// assert_eq!(text, b"fred & barney");
+/// ```
+pub fn unescape_to_io<W>(value: &str, mode: UnescapeMode, out: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    codec::write_io(out, |out| codec::decode_to(&mode, value, out))
+}
+
+/// Decodes one of the entities `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&#39;` (with optional
+/// leading zeros) at the start of `value`.
+fn decode_lodash_reference(value: &str) -> Option<(Decoded, usize)> {
+    if let Some(digits) = value.strip_prefix("&#") {
+        let zeros = digits.bytes().take_while(|byte| *byte == b'0').count();
+
+        return digits[zeros..]
+            .starts_with("39;")
+            .then_some((Decoded::Char('\''), "&#39;".len() + zeros));
+    }
+
+    [
+        ("&amp;", '&'),
+        ("&lt;", '<'),
+        ("&gt;", '>'),
+        ("&quot;", '"'),
+    ]
+    .into_iter()
+    .find(|(entity, _)| value.starts_with(entity))
+    .map(|(entity, c)| (Decoded::Char(c), entity.len()))
+}
+
+/// Decodes the HTML5 named, decimal or hexadecimal character reference at the start of `value`.
+fn decode_html5_reference(value: &str) -> Option<(Decoded, usize)> {
+    let bytes = value.as_bytes();
+
+    match bytes.get(1)? {
+        b'#' => {
+            let (radix, start) = match bytes.get(2) {
+                Some(b'x' | b'X') => (16, 3),
+                _ => (10, 2),
+            };
+            let end = start
+                + bytes[start..]
+                    .iter()
+                    .take_while(|byte| char::from(**byte).is_digit(radix))
+                    .count();
+
+            if end == start {
+                return None;
+            }
+
+            let length = if bytes.get(end) == Some(&b';') {
+                end + 1
+            } else {
+                end
+            };
+
+            Some((
+                Decoded::Char(decode_numeric_reference(&value[start..end], radix)),
+                length,
+            ))
+        }
+        byte if byte.is_ascii_alphabetic() => decode_named_reference(&value[1..])
+            .map(|(characters, length)| (Decoded::Str(characters), length + 1)),
+        _ => None,
+    }
+}
+
+/// Resolves the code point of a numeric character reference.
+///
+/// * `digits` - The digits of the reference, without the "&#" prefix or the "x" marker.
+/// * `radix` - The radix of `digits`.
+fn decode_numeric_reference(digits: &str, radix: u32) -> char {
+    let code_point = digits.chars().fold(0_u32, |code_point, digit| {
+        code_point
+            .saturating_mul(radix)
+            .saturating_add(digit.to_digit(radix).unwrap_or_default())
+    });
+
+    match code_point {
+        0x80..=0x9f => {
+            C1_REPLACEMENTS[(code_point - 0x80) as usize].or_else(|| char::from_u32(code_point))
+        }
+        0 => None,
+        _ => char::from_u32(code_point),
+    }
+    .unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// Resolves the named character reference at the start of `name`, returning the characters it
+/// expands to and its length, or `None` if no entity matches.
+///
+/// As in browsers, the longest entity that prefixes `name` is used when the name is not
+/// terminated by a semicolon, so that `&notit;` unescapes to `¬it;`.
+///
+/// * `name` - The text following the "&" of the reference.
+fn decode_named_reference(name: &str) -> Option<(&'static str, usize)> {
+    let find = |name: &str| {
+        ENTITIES
+            .binary_search_by(|(entity, _)| (*entity).cmp(name))
+            .ok()
+            .map(|index| ENTITIES[index].1)
+    };
+    let length = name
+        .bytes()
+        .take(MAX_ENTITY_LENGTH)
+        .take_while(u8::is_ascii_alphanumeric)
+        .count();
+
+    if name.as_bytes().get(length) == Some(&b';') {
+        if let Some(characters) = find(&name[..=length]) {
+            return Some((characters, length + 1));
+        }
     }
+
+    (2..=length)
+        .rev()
+        .find_map(|length| find(&name[..length]).map(|characters| (characters, length)))
 }
 
 /// A trait that implements the [Unescape::unescape] method on strings.
@@ -81,18 +318,74 @@ where
     ///
     /// [escape]: crate::Escape::escape
     fn unescape(&self) -> String;
+
+    /// This method is like [Unescape::unescape] except that it accepts the set of character
+    /// references to unescape.
+    ///
+    /// * `mode` - The set of character references to unescape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     Unescape,
+    ///     UnescapeMode,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "&copy; fred &amp barney &#x2F; &#96;".unescape_with(UnescapeMode::Html5),
+    ///     "© fred & barney / `".to_string()
+    /// );
+    /// ```
+    fn unescape_with(&self, mode: UnescapeMode) -> String;
+
+    /// This method is like [Unescape::unescape_with] except that it returns this string as is,
+    /// without allocating, when it has nothing to unescape.
+    ///
+    /// * `mode` - The set of character references to unescape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     Unescape,
+    ///     UnescapeMode,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "fred &amp; barney".unescape_cow(UnescapeMode::Lodash),
+    ///     "fred & barney"
+    /// );
+    /// ```
+    fn unescape_cow(&self, mode: UnescapeMode) -> Cow<'_, str>;
 }
 
 impl Unescape<String> for String {
     fn unescape(&self) -> String {
         unescape(self)
     }
+
+    fn unescape_with(&self, mode: UnescapeMode) -> String {
+        unescape_with(self, mode)
+    }
+
+    fn unescape_cow(&self, mode: UnescapeMode) -> Cow<'_, str> {
+        unescape_cow(self, mode)
+    }
 }
 
 impl Unescape<str> for str {
     fn unescape(&self) -> String {
         unescape(self)
     }
+
+    fn unescape_with(&self, mode: UnescapeMode) -> String {
+        unescape_with(self, mode)
+    }
+
+    fn unescape_cow(&self, mode: UnescapeMode) -> Cow<'_, str> {
+        unescape_cow(self, mode)
+    }
 }
 
 #[cfg(test)]
@@ -150,4 +443,120 @@ mod tests {
         assert_eq!("&#039;".unescape(), "'".to_string());
         assert_eq!("&#000039;".unescape(), "'".to_string());
     }
+
+    #[test]
+    fn can_unescape_named_references_in_html5_mode() {
+        assert_eq!(
+            unescape_with("&nbsp;&copy;&hellip;&Aacute;&amp;", UnescapeMode::Html5),
+            "\u{a0}©…Á&".to_string()
+        );
+        assert_eq!(
+            unescape_with("&NotSquareSupersetEqual;&acE;", UnescapeMode::Html5),
+            "⋣∾\u{333}".to_string()
+        );
+        assert_eq!(
+            "&nbsp;&copy;&hellip;".unescape_with(UnescapeMode::Html5),
+            "\u{a0}©…".to_string()
+        );
+    }
+
+    #[test]
+    fn can_unescape_numeric_references_in_html5_mode() {
+        assert_eq!(
+            unescape_with("&#96;&#x2F;&#X2f;&#0039;&#x1F600;", UnescapeMode::Html5),
+            "`//'😀".to_string()
+        );
+        assert_eq!(
+            unescape_with("&#96&#x2F", UnescapeMode::Html5),
+            "`/".to_string()
+        );
+        assert_eq!(
+            "&#96;&#x2F;".unescape_with(UnescapeMode::Html5),
+            "`/".to_string()
+        );
+    }
+
+    #[test]
+    fn can_replace_invalid_numeric_references_in_html5_mode() {
+        assert_eq!(
+            unescape_with(
+                "&#0;&#xD800;&#x110000;&#99999999999999;",
+                UnescapeMode::Html5
+            ),
+            "\u{fffd}\u{fffd}\u{fffd}\u{fffd}".to_string()
+        );
+        assert_eq!(
+            unescape_with("&#x80;&#x81;&#150;", UnescapeMode::Html5),
+            "€\u{81}–".to_string()
+        );
+    }
+
+    #[test]
+    fn can_unescape_legacy_references_without_semicolons_in_html5_mode() {
+        assert_eq!(
+            unescape_with("fred &amp barney &copy2024 &lt;3", UnescapeMode::Html5),
+            "fred & barney ©2024 <3".to_string()
+        );
+        assert_eq!(
+            unescape_with("&notit;", UnescapeMode::Html5),
+            "¬it;".to_string()
+        );
+        assert_eq!(
+            unescape_with("&notin;", UnescapeMode::Html5),
+            "∉".to_string()
+        );
+    }
+
+    #[test]
+    fn can_preserve_unknown_references_in_html5_mode() {
+        assert_eq!(
+            unescape_with("&foo; &#; &#x; & &;", UnescapeMode::Html5),
+            "&foo; &#; &#x; & &;".to_string()
+        );
+        assert_eq!(unescape_with("abc", UnescapeMode::Html5), "abc".to_string());
+    }
+
+    #[test]
+    fn can_unescape_in_lodash_mode() {
+        assert_eq!(
+            unescape_with("&amp;&#96;&nbsp;", UnescapeMode::Lodash),
+            "&&#96;&nbsp;".to_string()
+        );
+        assert_eq!(
+            "&amp;&#96;&nbsp;".unescape_with(UnescapeMode::default()),
+            "&&#96;&nbsp;".to_string()
+        );
+    }
+
+    #[test]
+    fn can_borrow_strings_with_nothing_to_unescape() {
+        assert!(matches!(
+            unescape_cow("a & b &#96;", UnescapeMode::Lodash),
+            Cow::Borrowed("a & b &#96;")
+        ));
+        assert!(matches!(
+            unescape_cow("a & b &foo;", UnescapeMode::Html5),
+            Cow::Borrowed("a & b &foo;")
+        ));
+        assert!(matches!(
+            "abc".to_string().unescape_cow(UnescapeMode::Html5),
+            Cow::Borrowed("abc")
+        ));
+        assert!(matches!(
+            unescape_cow("a &amp; b", UnescapeMode::Lodash),
+            Cow::Owned(_)
+        ));
+    }
+
+    #[test]
+    fn can_unescape_values_into_writers() {
+        let mut out = String::new();
+        unescape_to("&amp;&#039;&nbsp;", UnescapeMode::Lodash, &mut out).unwrap();
+        unescape_to("&nbsp;&#x41", UnescapeMode::Html5, &mut out).unwrap();
+        assert_eq!(out, "&'&nbsp;\u{a0}A");
+
+        let mut out = Vec::new();
+        unescape_to_io("&lt;p&gt;", UnescapeMode::Html5, &mut out).unwrap();
+        assert_eq!(out, b"<p>");
+    }
 }
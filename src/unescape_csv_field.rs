@@ -0,0 +1,380 @@
+use crate::escape_csv_field::is_formula;
+use memchr::memchr;
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt,
+};
+
+/// The error returned when unquoting a malformed field of a comma-separated file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CsvFieldError {
+    /// A field that starts with a double quote but does not end with one.
+    UnterminatedQuote,
+    /// A double quote that is neither doubled inside a quoted field, nor the closing quote of the
+    /// field.
+    UnescapedQuote {
+        /// The byte index of the double quote in the value.
+        index: usize,
+    },
+}
+
+impl fmt::Display for CsvFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnterminatedQuote => f.write_str("unterminated quoted field"),
+            Self::UnescapedQuote { index } => write!(f, "unescaped quote at index {index}"),
+        }
+    }
+}
+
+impl Error for CsvFieldError {}
+
+/// The inverse of [escape_csv_field]. This method removes the enclosing double quotes of a quoted
+/// field of a comma-separated file, and the doubling of its double quotes. Fields that are not
+/// quoted are returned as they are.
+///
+/// * `value` - The field to unquote.
+///
+/// # Errors
+///
+/// Returns a [CsvFieldError] if `value` is a quoted field without a closing quote, or contains a
+/// double quote that is not doubled.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     unescape_csv_field,
+///     CsvFieldError,
+/// };
+///
+/// assert_eq!(
+///     unescape_csv_field(r#""fred, ""barney""""#),
+///     Ok("fred, \"barney\"".to_string())
+/// );
+/// assert_eq!(unescape_csv_field("fred"), Ok("fred".to_string()));
+/// assert_eq!(
+///     unescape_csv_field(r#""fred"s""#),
+///     Err(CsvFieldError::UnescapedQuote { index: 5 })
+/// );
+/// ```
+///
+/// [escape_csv_field]: crate::escape_csv_field
+pub fn unescape_csv_field<S>(value: S) -> Result<String, CsvFieldError>
+where
+    S: AsRef<str>,
+{
+    unescape_csv_field_cow(value.as_ref(), false).map(Cow::into_owned)
+}
+
+/// The inverse of [escape_csv_field_with]. This method is like [unescape_csv_field] except that,
+/// if `formula_safe` is `true`, the single quote that prefixes a quoted field neutralized as a
+/// formula is also removed.
+///
+/// * `value` - The field to unquote.
+/// * `formula_safe` - Whether fields were neutralized as formulas.
+///
+/// # Errors
+///
+/// Returns a [CsvFieldError] if `value` is a quoted field without a closing quote, or contains a
+/// double quote that is not doubled.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::unescape_csv_field_with;
+///
+/// assert_eq!(
+///     unescape_csv_field_with(r#""'=SUM(A1:A9)""#, true),
+///     Ok("=SUM(A1:A9)".to_string())
+/// );
+/// assert_eq!(
+///     unescape_csv_field_with("'=SUM(A1:A9)", true),
+///     Ok("'=SUM(A1:A9)".to_string())
+/// );
+/// ```
+///
+/// [escape_csv_field_with]: crate::escape_csv_field_with
+pub fn unescape_csv_field_with<S>(value: S, formula_safe: bool) -> Result<String, CsvFieldError>
+where
+    S: AsRef<str>,
+{
+    unescape_csv_field_cow(value.as_ref(), formula_safe).map(Cow::into_owned)
+}
+
+/// This method is like [unescape_csv_field_with] except that it borrows the unquoted field from
+/// `value`, without allocating, when it contains no doubled double quotes.
+///
+/// * `value` - The field to unquote.
+/// * `formula_safe` - Whether fields were neutralized as formulas.
+///
+/// # Errors
+///
+/// Returns a [CsvFieldError] if `value` is a quoted field without a closing quote, or contains a
+/// double quote that is not doubled.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::unescape_csv_field_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(
+///     unescape_csv_field_cow(r#""a ""b""""#, false),
+///     Ok(Cow::from("a \"b\""))
+/// );
+/// assert!(matches!(
+///     unescape_csv_field_cow("\"fred, barney\"", false),
+///     Ok(Cow::Borrowed("fred, barney"))
+/// ));
+/// ```
+pub fn unescape_csv_field_cow(
+    value: &str,
+    formula_safe: bool,
+) -> Result<Cow<'_, str>, CsvFieldError> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        return match memchr(b'"', value.as_bytes()) {
+            Some(index) => Err(CsvFieldError::UnescapedQuote { index }),
+            None => Ok(Cow::Borrowed(value)),
+        };
+    };
+    let contents = quoted
+        .strip_suffix('"')
+        .ok_or(CsvFieldError::UnterminatedQuote)?;
+    let contents = match contents.strip_prefix('\'') {
+        Some(formula) if formula_safe && is_formula(formula) => formula,
+        _ => contents,
+    };
+    // The byte index of `contents` in `value`, to report the index of unescaped quotes.
+    let offset = value.len() - 1 - contents.len();
+    let bytes = contents.as_bytes();
+    let Some(mut index) = memchr(b'"', bytes) else {
+        return Ok(Cow::Borrowed(contents));
+    };
+    let mut unquoted = String::with_capacity(contents.len());
+    let mut start = 0;
+
+    loop {
+        if bytes.get(index + 1) != Some(&b'"') {
+            return Err(CsvFieldError::UnescapedQuote {
+                index: offset + index,
+            });
+        }
+
+        unquoted.push_str(&contents[start..=index]);
+        start = index + 2;
+
+        match memchr(b'"', &bytes[start..]) {
+            Some(next) => index = start + next,
+            None => break,
+        }
+    }
+
+    unquoted.push_str(&contents[start..]);
+
+    Ok(Cow::Owned(unquoted))
+}
+
+/// A trait that implements the [UnescapeCsvField::unescape_csv_field] method on strings.
+pub trait UnescapeCsvField<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// The inverse of [escape_csv_field]. This method removes the enclosing double quotes of this
+    /// string, a quoted field of a comma-separated file, and the doubling of its double quotes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [CsvFieldError] if this string is a quoted field without a closing quote, or
+    /// contains a double quote that is not doubled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnescapeCsvField;
+    ///
+    /// assert_eq!(
+    ///     r#""fred, ""barney""""#.unescape_csv_field(),
+    ///     Ok("fred, \"barney\"".to_string())
+    /// );
+    /// ```
+    ///
+    /// [escape_csv_field]: crate::EscapeCsvField::escape_csv_field
+    fn unescape_csv_field(&self) -> Result<String, CsvFieldError>;
+
+    /// The inverse of [escape_csv_field_with]. This method is like
+    /// [UnescapeCsvField::unescape_csv_field] except that, if `formula_safe` is `true`, the single
+    /// quote that prefixes a quoted field neutralized as a formula is also removed.
+    ///
+    /// * `formula_safe` - Whether fields were neutralized as formulas.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [CsvFieldError] if this string is a quoted field without a closing quote, or
+    /// contains a double quote that is not doubled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnescapeCsvField;
+    ///
+    /// assert_eq!(
+    ///     r#""'@SUM(A1:A9)""#.unescape_csv_field_with(true),
+    ///     Ok("@SUM(A1:A9)".to_string())
+    /// );
+    /// ```
+    ///
+    /// [escape_csv_field_with]: crate::EscapeCsvField::escape_csv_field_with
+    fn unescape_csv_field_with(&self, formula_safe: bool) -> Result<String, CsvFieldError>;
+
+    /// This method is like [UnescapeCsvField::unescape_csv_field_with] except that it borrows the
+    /// unquoted field from this string, without allocating, when it contains no doubled double
+    /// quotes.
+    ///
+    /// * `formula_safe` - Whether fields were neutralized as formulas.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [CsvFieldError] if this string is a quoted field without a closing quote, or
+    /// contains a double quote that is not doubled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnescapeCsvField;
+    ///
+    /// assert_eq!("\"a,b\"".unescape_csv_field_cow(false), Ok("a,b".into()));
+    /// ```
+    fn unescape_csv_field_cow(&self, formula_safe: bool) -> Result<Cow<'_, str>, CsvFieldError>;
+}
+
+impl UnescapeCsvField<String> for String {
+    fn unescape_csv_field(&self) -> Result<String, CsvFieldError> {
+        unescape_csv_field(self)
+    }
+
+    fn unescape_csv_field_with(&self, formula_safe: bool) -> Result<String, CsvFieldError> {
+        unescape_csv_field_with(self, formula_safe)
+    }
+
+    fn unescape_csv_field_cow(&self, formula_safe: bool) -> Result<Cow<'_, str>, CsvFieldError> {
+        unescape_csv_field_cow(self, formula_safe)
+    }
+}
+
+impl UnescapeCsvField<str> for str {
+    fn unescape_csv_field(&self) -> Result<String, CsvFieldError> {
+        unescape_csv_field(self)
+    }
+
+    fn unescape_csv_field_with(&self, formula_safe: bool) -> Result<String, CsvFieldError> {
+        unescape_csv_field_with(self, formula_safe)
+    }
+
+    fn unescape_csv_field_cow(&self, formula_safe: bool) -> Result<Cow<'_, str>, CsvFieldError> {
+        unescape_csv_field_cow(self, formula_safe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        escape_csv_field_with,
+        EscapeCsvField,
+    };
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_unescape_fields() {
+        assert_eq!(unescape_csv_field(""), Ok("".to_string()));
+        assert_eq!(unescape_csv_field(r#""""#), Ok("".to_string()));
+        assert_eq!(unescape_csv_field(r#""""""#), Ok("\"".to_string()));
+        assert_eq!(
+            unescape_csv_field("\"a,\r\nb é\""),
+            Ok("a,\r\nb é".to_string())
+        );
+        assert_eq!(
+            r#""""a""""b""""#.unescape_csv_field(),
+            Ok("\"a\"\"b\"".to_string())
+        );
+        assert_eq!(
+            " fred, barney ".to_string().unescape_csv_field(),
+            Ok(" fred, barney ".to_string())
+        );
+    }
+
+    #[test]
+    fn can_unescape_formulas() {
+        assert_eq!(
+            unescape_csv_field_with(r#""'=1,""2""""#, true),
+            Ok("=1,\"2\"".to_string())
+        );
+        assert_eq!(
+            unescape_csv_field_with(r#""'=1""#, false),
+            Ok("'=1".to_string())
+        );
+        assert_eq!(
+            "\"'fred\"".unescape_csv_field_with(true),
+            Ok("'fred".to_string())
+        );
+        assert_eq!("'-1".unescape_csv_field_with(true), Ok("'-1".to_string()));
+        assert_eq!(
+            unescape_csv_field_with(r#""'''-1,""#, true),
+            Ok("''-1,".to_string())
+        );
+    }
+
+    #[test]
+    fn can_report_malformed_fields() {
+        assert_eq!(
+            unescape_csv_field("\""),
+            Err(CsvFieldError::UnterminatedQuote)
+        );
+        assert_eq!(
+            unescape_csv_field("\"fred"),
+            Err(CsvFieldError::UnterminatedQuote)
+        );
+        assert_eq!(
+            unescape_csv_field("fred\"s"),
+            Err(CsvFieldError::UnescapedQuote { index: 4 })
+        );
+        assert_eq!(
+            unescape_csv_field(r#""a""b"c""#),
+            Err(CsvFieldError::UnescapedQuote { index: 5 })
+        );
+        assert_eq!(
+            unescape_csv_field_with(r#""'=a"""#, true),
+            Err(CsvFieldError::UnescapedQuote { index: 4 })
+        );
+        assert_eq!(
+            CsvFieldError::UnescapedQuote { index: 4 }.to_string(),
+            "unescaped quote at index 4"
+        );
+    }
+
+    #[test]
+    fn can_borrow_fields_without_doubled_quotes() {
+        assert!(matches!(
+            unescape_csv_field_cow("fred", false),
+            Ok(Cow::Borrowed("fred"))
+        ));
+        assert!(matches!(
+            "\"'=1\"".unescape_csv_field_cow(true),
+            Ok(Cow::Borrowed("=1"))
+        ));
+        assert!(matches!(
+            "\"\"\"\"".unescape_csv_field_cow(false),
+            Ok(Cow::Owned(_))
+        ));
+    }
+
+    quickcheck! {
+        fn escaped_fields_round_trip(value: String, formula_safe: bool) -> bool {
+            unescape_csv_field_with(escape_csv_field_with(&value, formula_safe), formula_safe)
+                == Ok(value.clone())
+                && value.escape_csv_field().unescape_csv_field() == Ok(value)
+        }
+    }
+}
@@ -0,0 +1,535 @@
+use memchr::memchr;
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt,
+};
+
+/// The error returned when unescaping a malformed string literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnescapeStringError {
+    /// A backslash that does not start a valid escape sequence.
+    InvalidEscape {
+        /// The byte index of the backslash in the value.
+        index: usize,
+    },
+    /// A "\u" escape sequence of a UTF-16 surrogate that is not part of a surrogate pair.
+    LoneSurrogate {
+        /// The byte index of the backslash that starts the escape sequence in the value.
+        index: usize,
+    },
+}
+
+impl fmt::Display for UnescapeStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEscape { index } => write!(f, "invalid escape sequence at index {index}"),
+            Self::LoneSurrogate { index } => write!(f, "lone surrogate escape at index {index}"),
+        }
+    }
+}
+
+impl Error for UnescapeStringError {}
+
+/// Parses `digits` as a hexadecimal number, which must be non-empty and at most U+10FFFF.
+fn parse_hex(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    digits.iter().try_fold(0, |number: u32, digit| {
+        let number = number * 16 + char::from(*digit).to_digit(16)?;
+
+        (number <= 0x10ffff).then_some(number)
+    })
+}
+
+/// Parses the "\u" escape sequence at `index` of `value`, returning its code unit or code point
+/// and its length. JavaScript also allows code points of any length between braces.
+fn parse_unicode_escape(
+    value: &str,
+    index: usize,
+    is_json: bool,
+) -> Result<(u32, usize), UnescapeStringError> {
+    let bytes = &value.as_bytes()[index + 2..];
+    let invalid = UnescapeStringError::InvalidEscape { index };
+
+    if !is_json && bytes.first() == Some(&b'{') {
+        let end = memchr(b'}', bytes).ok_or(invalid)?;
+
+        return Ok((parse_hex(&bytes[1..end]).ok_or(invalid)?, end + 3));
+    }
+
+    let code_unit = bytes.get(..4).and_then(parse_hex).ok_or(invalid)?;
+
+    Ok((code_unit, 6))
+}
+
+/// Unescapes the "\u" escape sequence at `index` of `value`, combining surrogate pairs, and
+/// returns its character and length.
+fn unescape_unicode(
+    value: &str,
+    index: usize,
+    is_json: bool,
+) -> Result<(char, usize), UnescapeStringError> {
+    let (code_point, length) = parse_unicode_escape(value, index, is_json)?;
+
+    let (code_point, length) = match code_point {
+        0xd800..=0xdbff => {
+            let low_index = index + length;
+
+            match value.as_bytes().get(low_index..low_index + 2) {
+                Some(b"\\u") => match parse_unicode_escape(value, low_index, is_json) {
+                    Ok((low @ 0xdc00..=0xdfff, low_length)) => (
+                        0x10000 + ((code_point - 0xd800) << 10) + (low - 0xdc00),
+                        length + low_length,
+                    ),
+                    _ => return Err(UnescapeStringError::LoneSurrogate { index }),
+                },
+                _ => return Err(UnescapeStringError::LoneSurrogate { index }),
+            }
+        }
+        0xdc00..=0xdfff => return Err(UnescapeStringError::LoneSurrogate { index }),
+        code_point => (code_point, length),
+    };
+
+    char::from_u32(code_point)
+        .map(|c| (c, length))
+        .ok_or(UnescapeStringError::InvalidEscape { index })
+}
+
+/// Unescapes the escape sequence at `index` of `value` into `out`, and returns its length.
+fn unescape_sequence(
+    value: &str,
+    index: usize,
+    is_json: bool,
+    out: &mut String,
+) -> Result<usize, UnescapeStringError> {
+    let invalid = UnescapeStringError::InvalidEscape { index };
+    let bytes = value.as_bytes();
+    let c = value[index + 1..].chars().next().ok_or(invalid)?;
+
+    let unescaped = match c {
+        '"' | '\\' | '/' => c,
+        'b' => '\u{8}',
+        'f' => '\u{c}',
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        'u' => {
+            let (c, length) = unescape_unicode(value, index, is_json)?;
+            out.push(c);
+
+            return Ok(length);
+        }
+        _ if is_json => return Err(invalid),
+        'v' => '\u{b}',
+        '0' if !bytes.get(index + 2).is_some_and(u8::is_ascii_digit) => '\u{0}',
+        '0'..='9' => return Err(invalid),
+        'x' => {
+            let code_point = bytes
+                .get(index + 2..index + 4)
+                .and_then(parse_hex)
+                .ok_or(invalid)?;
+            out.push(char::from_u32(code_point).ok_or(invalid)?);
+
+            return Ok(4);
+        }
+        '\r' if bytes.get(index + 2) == Some(&b'\n') => return Ok(3),
+        '\r' | '\n' | '\u{2028}' | '\u{2029}' => return Ok(1 + c.len_utf8()),
+        c => c,
+    };
+
+    out.push(unescaped);
+
+    Ok(1 + c.len_utf8())
+}
+
+/// Unescapes the escape sequences of `value`, borrowing `value` if it has none.
+fn unescape_string(value: &str, is_json: bool) -> Result<Cow<'_, str>, UnescapeStringError> {
+    let bytes = value.as_bytes();
+    let Some(mut index) = memchr(b'\\', bytes) else {
+        return Ok(Cow::Borrowed(value));
+    };
+    let mut unescaped = String::with_capacity(value.len());
+    let mut start = 0;
+
+    loop {
+        unescaped.push_str(&value[start..index]);
+        start = index + unescape_sequence(value, index, is_json, &mut unescaped)?;
+
+        match memchr(b'\\', &bytes[start..]) {
+            Some(next) => index = start + next,
+            None => break,
+        }
+    }
+
+    unescaped.push_str(&value[start..]);
+
+    Ok(Cow::Owned(unescaped))
+}
+
+/// The inverse of [escape_js_string]. This method converts the escape sequences of the contents
+/// of a JavaScript string literal, without its quotes, to their corresponding characters.
+///
+/// Escape sequences follow the rules of strict mode code: the single character escapes such as
+/// "\n", "\x" and "\u" sequences, including "\u{...}" code points and surrogate pairs, line
+/// continuations, and "\0" when it is not followed by a digit. Any other character preceded by a
+/// backslash stands for itself.
+///
+/// * `value` - The string to unescape.
+///
+/// # Errors
+///
+/// Returns an [UnescapeStringError] if `value` contains a malformed escape sequence, a legacy
+/// octal escape sequence, or the escape sequence of a lone UTF-16 surrogate.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     unescape_js_string,
+///     UnescapeStringError,
+/// };
+///
+/// assert_eq!(
+///     unescape_js_string(r"fred\'s \x3C/script\x3E \u{1F600} \uD83D\uDE00"),
+///     Ok("fred's </script> 😀 😀".to_string())
+/// );
+/// assert_eq!(
+///     unescape_js_string(r"\xZZ"),
+///     Err(UnescapeStringError::InvalidEscape { index: 0 })
+/// );
+/// ```
+///
+/// [escape_js_string]: crate::escape_js_string
+pub fn unescape_js_string<S>(value: S) -> Result<String, UnescapeStringError>
+where
+    S: AsRef<str>,
+{
+    unescape_js_string_cow(value.as_ref()).map(Cow::into_owned)
+}
+
+/// This method is like [unescape_js_string] except that it returns `value` as is, without
+/// allocating, when it has nothing to unescape.
+///
+/// * `value` - The string to unescape.
+///
+/// # Errors
+///
+/// Returns an [UnescapeStringError] if `value` contains a malformed escape sequence, a legacy
+/// octal escape sequence, or the escape sequence of a lone UTF-16 surrogate.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::unescape_js_string_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(unescape_js_string_cow(r"a\tb"), Ok(Cow::from("a\tb")));
+/// assert!(matches!(
+///     unescape_js_string_cow("fred, barney"),
+///     Ok(Cow::Borrowed(_))
+/// ));
+/// ```
+pub fn unescape_js_string_cow(value: &str) -> Result<Cow<'_, str>, UnescapeStringError> {
+    unescape_string(value, false)
+}
+
+/// The inverse of [escape_json_string]. This method converts the escape sequences of the contents
+/// of a JSON string, without its quotes, to their corresponding characters, as defined by
+/// [RFC 8259].
+///
+/// * `value` - The string to unescape.
+///
+/// # Errors
+///
+/// Returns an [UnescapeStringError] if `value` contains an escape sequence that is not allowed in
+/// JSON, or the escape sequence of a lone UTF-16 surrogate.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     unescape_json_string,
+///     UnescapeStringError,
+/// };
+///
+/// assert_eq!(
+///     unescape_json_string(r#"\"fred\" \/ \u00E9 \uD83D\uDE00"#),
+///     Ok(r#""fred" / é 😀"#.to_string())
+/// );
+/// assert_eq!(
+///     unescape_json_string(r"\'"),
+///     Err(UnescapeStringError::InvalidEscape { index: 0 })
+/// );
+/// ```
+///
+/// [escape_json_string]: crate::escape_json_string
+/// [RFC 8259]: https://www.rfc-editor.org/rfc/rfc8259
+pub fn unescape_json_string<S>(value: S) -> Result<String, UnescapeStringError>
+where
+    S: AsRef<str>,
+{
+    unescape_json_string_cow(value.as_ref()).map(Cow::into_owned)
+}
+
+/// This method is like [unescape_json_string] except that it returns `value` as is, without
+/// allocating, when it has nothing to unescape.
+///
+/// * `value` - The string to unescape.
+///
+/// # Errors
+///
+/// Returns an [UnescapeStringError] if `value` contains an escape sequence that is not allowed in
+/// JSON, or the escape sequence of a lone UTF-16 surrogate.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::unescape_json_string_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(unescape_json_string_cow(r"a\tb"), Ok(Cow::from("a\tb")));
+/// assert!(matches!(
+///     unescape_json_string_cow("fred, barney"),
+///     Ok(Cow::Borrowed(_))
+/// ));
+/// ```
+pub fn unescape_json_string_cow(value: &str) -> Result<Cow<'_, str>, UnescapeStringError> {
+    unescape_string(value, true)
+}
+
+/// A trait that implements the [UnescapeJsString::unescape_js_string] method on strings.
+pub trait UnescapeJsString<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// The inverse of [escape_js_string]. This method converts the escape sequences of this
+    /// string, the contents of a JavaScript string literal, to their corresponding characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [UnescapeStringError] if this string contains a malformed escape sequence, a
+    /// legacy octal escape sequence, or the escape sequence of a lone UTF-16 surrogate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnescapeJsString;
+    ///
+    /// assert_eq!(
+    ///     r"fred\'s \u{1F600}".unescape_js_string(),
+    ///     Ok("fred's 😀".to_string())
+    /// );
+    /// ```
+    ///
+    /// [escape_js_string]: crate::EscapeJsString::escape_js_string
+    fn unescape_js_string(&self) -> Result<String, UnescapeStringError>;
+
+    /// This method is like [UnescapeJsString::unescape_js_string] except that it returns this
+    /// string as is, without allocating, when it has nothing to unescape.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [UnescapeStringError] if this string contains a malformed escape sequence, a
+    /// legacy octal escape sequence, or the escape sequence of a lone UTF-16 surrogate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnescapeJsString;
+    ///
+    /// assert_eq!(r"a\tb".unescape_js_string_cow(), Ok("a\tb".into()));
+    /// ```
+    fn unescape_js_string_cow(&self) -> Result<Cow<'_, str>, UnescapeStringError>;
+
+    /// The inverse of [escape_json_string]. This method converts the escape sequences of this
+    /// string, the contents of a JSON string, to their corresponding characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [UnescapeStringError] if this string contains an escape sequence that is not
+    /// allowed in JSON, or the escape sequence of a lone UTF-16 surrogate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnescapeJsString;
+    ///
+    /// assert_eq!(
+    ///     r#"\"fred\" \/"#.unescape_json_string(),
+    ///     Ok(r#""fred" /"#.to_string())
+    /// );
+    /// ```
+    ///
+    /// [escape_json_string]: crate::EscapeJsString::escape_json_string
+    fn unescape_json_string(&self) -> Result<String, UnescapeStringError>;
+
+    /// This method is like [UnescapeJsString::unescape_json_string] except that it returns this
+    /// string as is, without allocating, when it has nothing to unescape.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [UnescapeStringError] if this string contains an escape sequence that is not
+    /// allowed in JSON, or the escape sequence of a lone UTF-16 surrogate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnescapeJsString;
+    ///
+    /// assert_eq!(r"a\tb".unescape_json_string_cow(), Ok("a\tb".into()));
+    /// ```
+    fn unescape_json_string_cow(&self) -> Result<Cow<'_, str>, UnescapeStringError>;
+}
+
+impl UnescapeJsString<String> for String {
+    fn unescape_js_string(&self) -> Result<String, UnescapeStringError> {
+        unescape_js_string(self)
+    }
+
+    fn unescape_js_string_cow(&self) -> Result<Cow<'_, str>, UnescapeStringError> {
+        unescape_js_string_cow(self)
+    }
+
+    fn unescape_json_string(&self) -> Result<String, UnescapeStringError> {
+        unescape_json_string(self)
+    }
+
+    fn unescape_json_string_cow(&self) -> Result<Cow<'_, str>, UnescapeStringError> {
+        unescape_json_string_cow(self)
+    }
+}
+
+impl UnescapeJsString<str> for str {
+    fn unescape_js_string(&self) -> Result<String, UnescapeStringError> {
+        unescape_js_string(self)
+    }
+
+    fn unescape_js_string_cow(&self) -> Result<Cow<'_, str>, UnescapeStringError> {
+        unescape_js_string_cow(self)
+    }
+
+    fn unescape_json_string(&self) -> Result<String, UnescapeStringError> {
+        unescape_json_string(self)
+    }
+
+    fn unescape_json_string_cow(&self) -> Result<Cow<'_, str>, UnescapeStringError> {
+        unescape_json_string_cow(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        escape_js_string_with,
+        escape_json_string_with,
+    };
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_unescape_js_strings() {
+        assert_eq!(
+            unescape_js_string(r#"\"\'\\\/\b\f\n\r\t\v\0\a\é"#),
+            Ok("\"'\\/\u{8}\u{c}\n\r\t\u{b}\u{0}aé".to_string())
+        );
+        assert_eq!(
+            unescape_js_string(r"\x41\x7e\xE9\u0041\u{41}\u{00000041}\u{10FFFF}"),
+            Ok("A~éAAA\u{10ffff}".to_string())
+        );
+        assert_eq!(
+            r"\uD83D\uDE00\uD83D\u{DE00}".unescape_js_string(),
+            Ok("😀😀".to_string())
+        );
+        assert_eq!(
+            "a\\\nb\\\r\nc\\\rd\\\u{2028}e\\\u{2029}f"
+                .to_string()
+                .unescape_js_string(),
+            Ok("abcdef".to_string())
+        );
+    }
+
+    #[test]
+    fn can_report_malformed_js_escapes() {
+        let invalid = |index| Err(UnescapeStringError::InvalidEscape { index });
+
+        assert_eq!(unescape_js_string(r"ab\"), invalid(2));
+        assert_eq!(unescape_js_string(r"a\x4"), invalid(1));
+        assert_eq!(unescape_js_string(r"a\x+4"), invalid(1));
+        assert_eq!(unescape_js_string(r"a\u004"), invalid(1));
+        assert_eq!(unescape_js_string(r"a\u{}"), invalid(1));
+        assert_eq!(unescape_js_string(r"a\u{41"), invalid(1));
+        assert_eq!(unescape_js_string(r"a\u{110000}"), invalid(1));
+        assert_eq!(unescape_js_string(r"a\01"), invalid(1));
+        assert_eq!(unescape_js_string(r"a\1"), invalid(1));
+        assert_eq!(unescape_js_string(r"a\8"), invalid(1));
+        assert_eq!(
+            UnescapeStringError::InvalidEscape { index: 1 }.to_string(),
+            "invalid escape sequence at index 1"
+        );
+    }
+
+    #[test]
+    fn can_report_lone_surrogates() {
+        let lone = |index| Err(UnescapeStringError::LoneSurrogate { index });
+
+        assert_eq!(unescape_js_string(r"a\uD83D"), lone(1));
+        assert_eq!(unescape_js_string(r"a\uD83Db"), lone(1));
+        assert_eq!(unescape_js_string(r"a\uD83DA"), lone(1));
+        assert_eq!(unescape_js_string(r"a\uDE00"), lone(1));
+        assert_eq!(
+            unescape_json_string(r"a\u{D83D}"),
+            Err(UnescapeStringError::InvalidEscape { index: 1 })
+        );
+        assert_eq!(unescape_json_string(r"a\uD83D\u{DE00}"), lone(1));
+        assert_eq!(
+            UnescapeStringError::LoneSurrogate { index: 1 }.to_string(),
+            "lone surrogate escape at index 1"
+        );
+    }
+
+    #[test]
+    fn can_unescape_json_strings() {
+        assert_eq!(
+            unescape_json_string(r#"\"\\\/\b\f\n\r\t\u00e9\uD83D\uDE00"#),
+            Ok("\"\\/\u{8}\u{c}\n\r\té😀".to_string())
+        );
+        assert_eq!(
+            r"\v".unescape_json_string(),
+            Err(UnescapeStringError::InvalidEscape { index: 0 })
+        );
+        assert_eq!(
+            unescape_json_string(r"\x41"),
+            Err(UnescapeStringError::InvalidEscape { index: 0 })
+        );
+        assert_eq!(
+            unescape_json_string("\\\n"),
+            Err(UnescapeStringError::InvalidEscape { index: 0 })
+        );
+    }
+
+    #[test]
+    fn can_borrow_strings_with_nothing_to_unescape() {
+        assert!(matches!(
+            unescape_js_string_cow("fred 'barney' é"),
+            Ok(Cow::Borrowed(_))
+        ));
+        assert!(matches!(
+            "fred".unescape_json_string_cow(),
+            Ok(Cow::Borrowed(_))
+        ));
+        assert_eq!("".to_string().unescape_js_string_cow(), Ok(Cow::from("")));
+    }
+
+    quickcheck! {
+        fn escaped_js_strings_round_trip(value: String, ascii_only: bool) -> bool {
+            unescape_js_string(escape_js_string_with(&value, ascii_only)) == Ok(value)
+        }
+
+        fn escaped_json_strings_round_trip(value: String, ascii_only: bool) -> bool {
+            unescape_json_string(escape_json_string_with(&value, ascii_only)) == Ok(value)
+        }
+    }
+}
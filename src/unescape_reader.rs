@@ -0,0 +1,292 @@
+use crate::{
+    unescape_to,
+    UnescapeMode,
+};
+use std::{
+    io,
+    io::Read,
+    str,
+};
+
+/// The size of the chunks read from the underlying reader.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// The maximum length of an unterminated reference that is held back until more text is read.
+/// Longer references, which can only be numeric references with many leading zeros, are decoded
+/// as if they ended at the chunk boundary.
+const MAX_PENDING_REFERENCE: usize = 256;
+
+/// A reader that unescapes the UTF-8 text read from the `inner` reader, like [unescape_with] does
+/// for whole strings.
+///
+/// References and characters split across reads of the underlying reader are buffered until they
+/// are complete, so they are decoded exactly as if the whole text had been unescaped at once.
+/// Reading bytes that are not valid UTF-8 fails with [io::ErrorKind::InvalidData].
+///
+/// # Examples
+///
+/// ```
+/// use rodash::UnescapeReader;
+/// use std::io::Read;
+///
+/// let mut reader = UnescapeReader::new("fred, barney, &amp; pebbles".as_bytes());
+/// let mut text = String::new();
+/// reader.read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "fred, barney, & pebbles");
+/// ```
+///
+/// [unescape_with]: crate::unescape_with
+#[derive(Debug)]
+pub struct UnescapeReader<R> {
+    inner: R,
+    mode: UnescapeMode,
+    input: Vec<u8>,
+    output: String,
+    position: usize,
+    is_eof: bool,
+}
+
+impl<R> UnescapeReader<R>
+where
+    R: Read,
+{
+    /// Creates a reader that unescapes the entities understood by [unescape].
+    ///
+    /// * `inner` - The reader to read the escaped text from.
+    ///
+    /// [unescape]: crate::unescape
+    pub fn new(inner: R) -> Self {
+        Self::with_mode(inner, UnescapeMode::Lodash)
+    }
+
+    /// Creates a reader that unescapes the character references of the given `mode`.
+    ///
+    /// * `inner` - The reader to read the escaped text from.
+    /// * `mode` - The set of character references to unescape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     UnescapeMode,
+    ///     UnescapeReader,
+    /// };
+    /// use std::io::Read;
+    ///
+    /// let mut reader = UnescapeReader::with_mode("&copy; fred".as_bytes(), UnescapeMode::Html5);
+    /// let mut text = String::new();
+    /// reader.read_to_string(&mut text).unwrap();
+    /// assert_eq!(text, "© fred");
+    /// ```
+    pub fn with_mode(inner: R, mode: UnescapeMode) -> Self {
+        Self {
+            inner,
+            mode,
+            input: Vec::new(),
+            output: String::new(),
+            position: 0,
+            is_eof: false,
+        }
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader. Any text read from it but not yet returned is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads the next chunk from the underlying reader and unescapes as much of the buffered text
+    /// as possible into the output buffer.
+    fn fill(&mut self) -> io::Result<()> {
+        if !self.is_eof {
+            let mut chunk = [0; CHUNK_SIZE];
+            let length = loop {
+                match self.inner.read(&mut chunk) {
+                    Ok(length) => break length,
+                    Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(error) => return Err(error),
+                }
+            };
+
+            self.is_eof = length == 0;
+            self.input.extend_from_slice(&chunk[..length]);
+        }
+
+        // The text is decoded in full when nothing can follow it, either at the end of the input or
+        // before bytes that are not valid UTF-8, so that an unfinished reference is not held back
+        // forever.
+        let (valid, is_final) = match str::from_utf8(&self.input) {
+            Ok(text) => (text.len(), self.is_eof),
+            Err(error) if error.error_len().is_none() && !self.is_eof => {
+                (error.valid_up_to(), false)
+            }
+            Err(error) if error.valid_up_to() > 0 => (error.valid_up_to(), true),
+            Err(_) => return Err(invalid_utf8()),
+        };
+        let text = str::from_utf8(&self.input[..valid]).map_err(|_| invalid_utf8())?;
+        let end = if is_final {
+            valid
+        } else {
+            decodable_length(text)
+        };
+
+        if end == 0 && self.is_eof && !self.input.is_empty() {
+            // No more text will be read, so nothing would ever be decoded.
+            return Err(invalid_utf8());
+        }
+
+        self.output.clear();
+        self.position = 0;
+        unescape_to(&text[..end], self.mode, &mut self.output)
+            .map_err(|_| io::Error::other("unable to unescape the text"))?;
+        self.input.drain(..end);
+
+        Ok(())
+    }
+}
+
+impl<R> Read for UnescapeReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.output.len() {
+            if self.is_eof && self.input.is_empty() {
+                return Ok(0);
+            }
+
+            self.fill()?;
+        }
+
+        let output = &self.output.as_bytes()[self.position..];
+        let length = output.len().min(buf.len());
+
+        buf[..length].copy_from_slice(&output[..length]);
+        self.position += length;
+
+        Ok(length)
+    }
+}
+
+/// Returns the length of the leading part of `text` that can be unescaped without knowing the
+/// text that follows it, which excludes a trailing reference that may be incomplete.
+fn decodable_length(text: &str) -> usize {
+    match text.rfind('&') {
+        Some(index)
+            if text.len() - index <= MAX_PENDING_REFERENCE
+                && text.as_bytes()[index + 1..]
+                    .iter()
+                    .all(|byte| byte.is_ascii_alphanumeric() || *byte == b'#') =>
+        {
+            index
+        }
+        _ => text.len(),
+    }
+}
+
+/// Creates the error returned for text that is not valid UTF-8.
+fn invalid_utf8() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "stream did not contain valid UTF-8",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unescape_with;
+
+    /// A reader that returns at most `chunk_size` bytes per read.
+    struct ChunkedReader<'a> {
+        bytes: &'a [u8],
+        chunk_size: usize,
+    }
+
+    impl Read for ChunkedReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let length = self.bytes.len().min(buf.len()).min(self.chunk_size);
+            buf[..length].copy_from_slice(&self.bytes[..length]);
+            self.bytes = &self.bytes[length..];
+
+            Ok(length)
+        }
+    }
+
+    fn read_in_chunks(text: &str, chunk_size: usize, mode: UnescapeMode) -> io::Result<String> {
+        let mut reader = UnescapeReader::with_mode(
+            ChunkedReader {
+                bytes: text.as_bytes(),
+                chunk_size,
+            },
+            mode,
+        );
+        let mut output = String::new();
+        reader.read_to_string(&mut output)?;
+
+        Ok(output)
+    }
+
+    #[test]
+    fn can_unescape_read_text() {
+        let mut reader = UnescapeReader::new(r#"&amp;&lt;&gt;&quot;&#39;/"#.as_bytes());
+        let mut output = String::new();
+        reader.read_to_string(&mut output).unwrap();
+        assert_eq!(output, r#"&<>"'/"#);
+    }
+
+    #[test]
+    fn can_unescape_references_split_across_reads() {
+        let text = "fred &amp; barney &copy &notit; &#x1F600; &#0039; € é &am &";
+
+        for mode in [UnescapeMode::Lodash, UnescapeMode::Html5] {
+            for chunk_size in 1..8 {
+                assert_eq!(
+                    read_in_chunks(text, chunk_size, mode).unwrap(),
+                    unescape_with(text, mode)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn can_reject_invalid_utf8() {
+        let mut reader = UnescapeReader::new(&b"a&amp;\xffb"[..]);
+        let mut output = Vec::new();
+        assert_eq!(
+            reader.read_to_end(&mut output).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(output, b"a&");
+
+        let mut reader = UnescapeReader::new(&b"a\xe2\x82"[..]);
+        assert_eq!(
+            reader.read_to_end(&mut Vec::new()).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn can_reject_invalid_utf8_after_unfinished_references() {
+        for (bytes, expected) in [(&b"a&am\xff"[..], &b"a&am"[..]), (b"&\xff", b"&")] {
+            for chunk_size in [1, 2, CHUNK_SIZE] {
+                let mut reader = UnescapeReader::new(ChunkedReader { bytes, chunk_size });
+                let mut output = Vec::new();
+                assert_eq!(
+                    reader.read_to_end(&mut output).unwrap_err().kind(),
+                    io::ErrorKind::InvalidData
+                );
+                assert_eq!(output, expected);
+            }
+        }
+    }
+}
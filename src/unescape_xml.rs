@@ -0,0 +1,174 @@
+use crate::codec::{
+    self,
+    Decoded,
+    Decoder,
+};
+
+/// The rules applied by [unescape_xml].
+struct XmlDecoder;
+
+impl Decoder for XmlDecoder {
+    const MARKER: u8 = b'&';
+
+    fn decode(&self, value: &str) -> Option<(Decoded, usize)> {
+        let name_length = value[1..]
+            .bytes()
+            .position(|byte| !(byte.is_ascii_alphanumeric() || byte == b'#'))?;
+
+        if value.as_bytes()[name_length + 1] != b';' {
+            return None;
+        }
+
+        let c = match &value[1..name_length + 1] {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            reference => {
+                let (digits, radix) = match reference.strip_prefix('#')? {
+                    hex if hex.starts_with('x') => (&hex[1..], 16),
+                    decimal => (decimal, 10),
+                };
+
+                if digits.is_empty() || !digits.chars().all(|digit| digit.is_digit(radix)) {
+                    return None;
+                }
+
+                u32::from_str_radix(digits, radix)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .filter(|c| *c != '\u{0}')?
+            }
+        };
+
+        Some((Decoded::Char(c), name_length + 2))
+    }
+}
+
+/// The inverse of [escape_xml]. This method converts the XML entities `&amp;`, `&lt;`, `&gt;`,
+/// `&quot;` and `&apos;`, and the decimal (`&#N;`) and hexadecimal (`&#xH;`) character
+/// references in `value` to their corresponding characters.
+///
+/// # Note
+///
+/// References that are not terminated by a semicolon, and numeric references to the null
+/// character or to code points that are not Unicode scalar values, are left untouched.
+///
+/// * `value` - The string to unescape.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::unescape_xml;
+///
+/// assert_eq!(
+///     unescape_xml("fred &amp; &apos;barney&apos; &#169; &#x1F600;"),
+///     "fred & 'barney' © 😀".to_string()
+/// );
+/// ```
+///
+/// [escape_xml]: crate::escape_xml
+pub fn unescape_xml<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    codec::decode_cow(&XmlDecoder, value.as_ref()).into_owned()
+}
+
+/// A trait that implements the [UnescapeXml::unescape_xml] method on strings.
+pub trait UnescapeXml<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// The inverse of [escape_xml]. This method converts the XML entities `&amp;`, `&lt;`,
+    /// `&gt;`, `&quot;` and `&apos;`, and the decimal (`&#N;`) and hexadecimal (`&#xH;`)
+    /// character references in this string to their corresponding characters.
+    ///
+    /// # Note
+    ///
+    /// References that are not terminated by a semicolon, and numeric references to the null
+    /// character or to code points that are not Unicode scalar values, are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnescapeXml;
+    ///
+    /// assert_eq!(
+    ///     "fred &amp; &apos;barney&apos; &#169; &#x1F600;".unescape_xml(),
+    ///     "fred & 'barney' © 😀".to_string()
+    /// );
+    /// ```
+    ///
+    /// [escape_xml]: crate::EscapeXml::escape_xml
+    fn unescape_xml(&self) -> String;
+}
+
+impl UnescapeXml<String> for String {
+    fn unescape_xml(&self) -> String {
+        unescape_xml(self)
+    }
+}
+
+impl UnescapeXml<str> for str {
+    fn unescape_xml(&self) -> String {
+        unescape_xml(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        escape_xml,
+        EscapeXml,
+    };
+
+    #[test]
+    fn can_unescape_values() {
+        assert_eq!(
+            unescape_xml("&amp;&lt;&gt;&quot;&apos;/&amp;lt;"),
+            r#"&<>"'/&lt;"#.to_string()
+        );
+        assert_eq!(
+            unescape_xml(escape_xml(r#"&<>"'/"#)),
+            r#"&<>"'/"#.to_string()
+        );
+        assert_eq!(
+            "&amp;&lt;&gt;&quot;&apos;/".unescape_xml(),
+            r#"&<>"'/"#.to_string()
+        );
+        assert_eq!(
+            r#"&<>"'/"#.escape_xml().unescape_xml(),
+            r#"&<>"'/"#.to_string()
+        );
+    }
+
+    #[test]
+    fn can_unescape_numeric_references() {
+        assert_eq!(
+            unescape_xml("&#39;&#039;&#x27;&#x1F600;&#9;&#x1;"),
+            "'''😀\t\u{1}".to_string()
+        );
+        assert_eq!("&#x2F;&#47;".unescape_xml(), "//".to_string());
+    }
+
+    #[test]
+    fn can_preserve_invalid_references() {
+        assert_eq!(
+            unescape_xml("&#0; &#xD800; &#x110000; &#X41; &#x; &#; &amp &nbsp; & &;"),
+            "&#0; &#xD800; &#x110000; &#X41; &#x; &#; &amp &nbsp; & &;".to_string()
+        );
+        assert_eq!(
+            unescape_xml("&#-1; &#+1; &#x+1;"),
+            "&#-1; &#+1; &#x+1;".to_string()
+        );
+    }
+
+    #[test]
+    fn can_handle_strings_with_nothing_to_unescape() {
+        assert_eq!(unescape_xml("abc"), "abc".to_string());
+        assert_eq!("abc".unescape_xml(), "abc".to_string());
+    }
+}
@@ -0,0 +1,259 @@
+use crate::occurrences::{
+    occurrences_by,
+    occurrences_with,
+};
+use std::hash::Hash;
+
+/// Creates an array of unique values that are included in either of the `array` and `other`
+/// arrays, like the lodash `_.union` function. The order of result values are determined by the
+/// order in which they first occur in `array`, followed by `other`.
+///
+/// * `array` - The first array to inspect.
+/// * `other` - The second array to inspect.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::union;
+///
+/// assert_eq!(union(&[2, 1, 2], &[1, 3]), [2, 1, 3]);
+/// ```
+pub fn union<A>(array: &[A], other: &[A]) -> Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    occurrences_by([array, other], |item| item)
+        .into_iter()
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// This method is like [union] except that it accepts `iteratee` which is invoked for each value
+/// of `array` and `other` to generate the key by which they are compared, like the lodash
+/// `_.unionBy` function. Only the first value with each key is kept. The keys are looked up in a
+/// hash map, so the values themselves only need to be cloneable.
+///
+/// * `array` - The first array to inspect.
+/// * `other` - The second array to inspect.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::union_by;
+///
+/// let result = union_by(&[2.1, 2.5], &[1.2, 2.3], |n: &f64| n.floor() as i64);
+/// assert_eq!(result, [2.1, 1.2]);
+/// ```
+pub fn union_by<A, K, F>(array: &[A], other: &[A], iteratee: F) -> Vec<A>
+where
+    A: Clone,
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    occurrences_by([array, other], iteratee)
+        .into_iter()
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// This method is like [union] except that it accepts `comparator` which is invoked to tell
+/// whether two values are equal, like the lodash `_.unionWith` function. It is invoked with a
+/// value and the first occurrences of the values found before it, in `O(n²)` time, so
+/// [union_by] should be preferred when values can be compared by a key.
+///
+/// * `array` - The first array to inspect.
+/// * `other` - The second array to inspect.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::union_with;
+///
+/// let result = union_with(&["Fred"], &["FRED", "barney"], |a, b| {
+///     a.eq_ignore_ascii_case(b)
+/// });
+/// assert_eq!(result, ["Fred", "barney"]);
+/// ```
+pub fn union_with<A, F>(array: &[A], other: &[A], comparator: F) -> Vec<A>
+where
+    A: Clone,
+    F: FnMut(&A, &A) -> bool,
+{
+    occurrences_with([array, other], comparator)
+        .into_iter()
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// A trait that implements the [Union::union] method on arrays.
+pub trait Union<A, I>
+where
+    A: IntoIterator,
+    I: Clone,
+{
+    /// Creates an array of unique values that are included in either this array or the `other`
+    /// array. The order of result values are determined by the order in which they first occur in
+    /// this array, followed by `other`.
+    ///
+    /// * `other` - The array to inspect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Union;
+    ///
+    /// assert_eq!([2, 1].union(&[1, 3]), [2, 1, 3]);
+    /// ```
+    fn union(&self, other: &[I]) -> Vec<I>
+    where
+        I: Hash + Eq;
+
+    /// This method is like [Union::union] except that it accepts `iteratee` which is invoked
+    /// for each value of this array and `other` to generate the key by which they are compared.
+    ///
+    /// * `other` - The array to inspect.
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Union;
+    ///
+    /// assert_eq!(
+    ///     [2.1].union_by(&[1.2, 2.3], |n: &f64| n.floor() as i64),
+    ///     [2.1, 1.2]
+    /// );
+    /// ```
+    fn union_by<K, F>(&self, other: &[I], iteratee: F) -> Vec<I>
+    where
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [Union::union] except that it accepts `comparator` which is invoked
+    /// to tell whether two values are equal.
+    ///
+    /// * `other` - The array to inspect.
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Union;
+    ///
+    /// assert_eq!(
+    ///     ["a"].union_with(&["A", "b"], |a, b| a.eq_ignore_ascii_case(b)),
+    ///     ["a", "b"]
+    /// );
+    /// ```
+    fn union_with<F>(&self, other: &[I], comparator: F) -> Vec<I>
+    where
+        F: FnMut(&I, &I) -> bool;
+}
+
+impl<A> Union<Vec<A>, A> for Vec<A>
+where
+    A: Clone,
+{
+    fn union(&self, other: &[A]) -> Vec<A>
+    where
+        A: Hash + Eq,
+    {
+        union(self, other)
+    }
+
+    fn union_by<K, F>(&self, other: &[A], iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        union_by(self, other, iteratee)
+    }
+
+    fn union_with<F>(&self, other: &[A], comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        union_with(self, other, comparator)
+    }
+}
+
+impl<A, const N: usize> Union<[A; N], A> for [A; N]
+where
+    A: Clone,
+{
+    fn union(&self, other: &[A]) -> Vec<A>
+    where
+        A: Hash + Eq,
+    {
+        union(self, other)
+    }
+
+    fn union_by<K, F>(&self, other: &[A], iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        union_by(self, other, iteratee)
+    }
+
+    fn union_with<F>(&self, other: &[A], comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        union_with(self, other, comparator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::user;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_return_union_of_two_arrays() {
+        let result = union(&[2, 1, 2], &[1, 3, 3]);
+        assert_eq!(result, [2, 1, 3]);
+
+        assert_eq!(vec![2, 1].union(&[]), [2, 1]);
+
+        assert_eq!([0; 0].union(&[3, 1, 3]), [3, 1]);
+    }
+
+    #[test]
+    fn can_keep_first_occurrence_of_each_key_or_match_across_arrays() {
+        let users = [user(1, "fred"), user(2, "Fred")];
+        let others = [user(2, "barney"), user(3, "FRED"), user(4, "pebbles")];
+
+        assert_eq!(
+            union_by(&users, &others, |user| user.id),
+            [
+                user(1, "fred"),
+                user(2, "Fred"),
+                user(3, "FRED"),
+                user(4, "pebbles")
+            ]
+        );
+        assert_eq!(
+            users.union_with(&others, |a, b| a.name.eq_ignore_ascii_case(b.name)),
+            [user(1, "fred"), user(2, "barney"), user(4, "pebbles")]
+        );
+    }
+
+    quickcheck! {
+        fn contains_each_value_once_in_order(array: Vec<u8>, other: Vec<u8>) -> bool {
+            let mut expected = Vec::new();
+
+            for item in array.iter().chain(&other) {
+                if !expected.contains(item) {
+                    expected.push(*item);
+                }
+            }
+
+            union(&array, &other) == expected
+                && union_with(&array, &other, |a, b| a == b) == expected
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use crate::occurrences::{
+    occurrences_by,
+    occurrences_with,
+};
+use std::{
+    hash::Hash,
+    iter,
+};
+
+/// Creates an array of unique values that are included in any of the `array` and `others`
+/// arrays, like the lodash `_.union` function. The order of result values are determined by the
+/// order in which they first occur in `array`, followed by `others`.
+///
+/// * `array` - The first array to inspect.
+/// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::union_all;
+///
+/// assert_eq!(union_all(&[2], vec![&[1, 2], &[3, 1]]), [2, 1, 3]);
+/// ```
+pub fn union_all<A, O>(array: &[A], others: O) -> Vec<A>
+where
+    A: Hash + Eq + Clone,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
+{
+    let others = others.into_iter().collect::<Vec<_>>();
+    let arrays = iter::once(array).chain(others.iter().map(AsRef::as_ref));
+
+    occurrences_by(arrays, |item| item)
+        .into_iter()
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// This method is like [union_all] except that it accepts `iteratee` which is invoked for each
+/// value of `array` and `others` to generate the key by which they are compared. Only the first
+/// value with each key is kept. The keys are looked up in a hash map, so the values themselves only
+/// need to be cloneable.
+///
+/// * `array` - The first array to inspect.
+/// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::union_all_by;
+///
+/// let result = union_all_by(&[2.1], [[1.2], [2.3]], |n: &f64| n.floor() as i64);
+/// assert_eq!(result, [2.1, 1.2]);
+/// ```
+pub fn union_all_by<A, O, K, F>(array: &[A], others: O, iteratee: F) -> Vec<A>
+where
+    A: Clone,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    let others = others.into_iter().collect::<Vec<_>>();
+    let arrays = iter::once(array).chain(others.iter().map(AsRef::as_ref));
+
+    occurrences_by(arrays, iteratee)
+        .into_iter()
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// This method is like [union_all] except that it accepts `comparator` which is invoked to tell
+/// whether two values are equal. It is invoked with a value and the first occurrences of the
+/// values found before it, in `O(n²)` time, so [union_all_by] should be preferred when values can
+/// be compared by a key.
+///
+/// * `array` - The first array to inspect.
+/// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::union_all_with;
+///
+/// let result = union_all_with(&["a"], [["A", "b"], ["B", "c"]], |a, b| {
+///     a.eq_ignore_ascii_case(b)
+/// });
+/// assert_eq!(result, ["a", "b", "c"]);
+/// ```
+pub fn union_all_with<A, O, F>(array: &[A], others: O, comparator: F) -> Vec<A>
+where
+    A: Clone,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
+    F: FnMut(&A, &A) -> bool,
+{
+    let others = others.into_iter().collect::<Vec<_>>();
+    let arrays = iter::once(array).chain(others.iter().map(AsRef::as_ref));
+
+    occurrences_with(arrays, comparator)
+        .into_iter()
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// A trait that implements the [UnionAll::union_all] method on arrays.
+pub trait UnionAll<A, I>
+where
+    A: IntoIterator,
+    I: Clone,
+{
+    /// Creates an array of unique values that are included in any of this array and the `others`
+    /// arrays. The order of result values are determined by the order in which they first occur in
+    /// this array, followed by `others`.
+    ///
+    /// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnionAll;
+    ///
+    /// assert_eq!([2].union_all(vec![&[1, 2], &[3, 1]]), [2, 1, 3]);
+    /// ```
+    fn union_all<O>(&self, others: O) -> Vec<I>
+    where
+        I: Hash + Eq,
+        O: IntoIterator,
+        O::Item: AsRef<[I]>;
+
+    /// This method is like [UnionAll::union_all] except that it accepts `iteratee` which is invoked
+    /// for each value of this array and `others` to generate the key by which they are compared.
+    ///
+    /// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnionAll;
+    ///
+    /// assert_eq!(
+    ///     [2.1].union_all_by([[1.2], [2.3]], |n: &f64| n.floor() as i64),
+    ///     [2.1, 1.2]
+    /// );
+    /// ```
+    fn union_all_by<O, K, F>(&self, others: O, iteratee: F) -> Vec<I>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[I]>,
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [UnionAll::union_all] except that it accepts `comparator` which is
+    /// invoked to tell whether two values are equal.
+    ///
+    /// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UnionAll;
+    ///
+    /// assert_eq!(
+    ///     ["a"].union_all_with([["A"], ["b"]], |a, b| a.eq_ignore_ascii_case(b)),
+    ///     ["a", "b"]
+    /// );
+    /// ```
+    fn union_all_with<O, F>(&self, others: O, comparator: F) -> Vec<I>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[I]>,
+        F: FnMut(&I, &I) -> bool;
+}
+
+impl<A> UnionAll<Vec<A>, A> for Vec<A>
+where
+    A: Clone,
+{
+    fn union_all<O>(&self, others: O) -> Vec<A>
+    where
+        A: Hash + Eq,
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+    {
+        union_all(self, others)
+    }
+
+    fn union_all_by<O, K, F>(&self, others: O, iteratee: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        union_all_by(self, others, iteratee)
+    }
+
+    fn union_all_with<O, F>(&self, others: O, comparator: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        F: FnMut(&A, &A) -> bool,
+    {
+        union_all_with(self, others, comparator)
+    }
+}
+
+impl<A, const N: usize> UnionAll<[A; N], A> for [A; N]
+where
+    A: Clone,
+{
+    fn union_all<O>(&self, others: O) -> Vec<A>
+    where
+        A: Hash + Eq,
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+    {
+        union_all(self, others)
+    }
+
+    fn union_all_by<O, K, F>(&self, others: O, iteratee: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        union_all_by(self, others, iteratee)
+    }
+
+    fn union_all_with<O, F>(&self, others: O, comparator: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        F: FnMut(&A, &A) -> bool,
+    {
+        union_all_with(self, others, comparator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_helpers::user,
+        union,
+    };
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_return_union_of_multiple_arrays() {
+        let result = union_all(&[2, 2], vec![&[1, 2], &[3, 1]]);
+        assert_eq!(result, [2, 1, 3]);
+
+        assert_eq!(vec![2, 1, 2].union_all(Vec::<Vec<i32>>::new()), [2, 1]);
+
+        let values = [4, 3, 3, 2, 2, 1];
+        assert_eq!([4].union_all(values.chunks(2)), [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn can_keep_first_occurrence_of_each_key_or_match_across_nested_arrays() {
+        let users = [user(1, "fred")];
+        let others = [
+            vec![user(1, "barney"), user(2, "FRED")],
+            vec![user(3, "wilma")],
+        ];
+
+        assert_eq!(
+            union_all_by(&users, &others, |user| user.id),
+            [user(1, "fred"), user(2, "FRED"), user(3, "wilma")]
+        );
+        assert_eq!(
+            users.union_all_with(&others, |a, b| a.name.eq_ignore_ascii_case(b.name)),
+            [user(1, "fred"), user(1, "barney"), user(3, "wilma")]
+        );
+    }
+
+    quickcheck! {
+        fn matches_repeated_union(array: Vec<u8>, others: Vec<Vec<u8>>) -> bool {
+            let expected = others
+                .iter()
+                .fold(union(&array, &[]), |result, other| union(&result, other));
+
+            union_all(&array, &others) == expected
+        }
+    }
+}
@@ -26,11 +26,78 @@ where
     vec
 }
 
+/// This method is like [uniq] except that it accepts `iteratee` which is invoked for each value
+/// of `array` to generate the key by which uniqueness is computed, like the lodash `_.uniqBy`
+/// function. The keys are looked up in a hash set, so the values themselves only need to be
+/// cloneable.
+///
+/// * `array` - The array to inspect.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::uniq_by;
+///
+/// assert_eq!(
+///     uniq_by(&[2.1, 1.2, 2.3], |n: &f64| n.floor() as i64),
+///     [2.1, 1.2]
+/// );
+/// ```
+pub fn uniq_by<A, K, F>(array: &[A], mut iteratee: F) -> Vec<A>
+where
+    A: Clone,
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    let mut keys = HashSet::new();
+
+    array
+        .iter()
+        .filter(|item| keys.insert(iteratee(item)))
+        .cloned()
+        .collect()
+}
+
+/// This method is like [uniq] except that it accepts `comparator` which is invoked with a value
+/// of `array` and a value already in the result to tell whether they are equal, like the lodash
+/// `_.uniqWith` function. Every value is compared with every kept value, in `O(n²)` time, so
+/// [uniq_by] should be preferred when values can be compared by a key.
+///
+/// * `array` - The array to inspect.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::uniq_with;
+///
+/// let result = uniq_with(&["Fred", "barney", "FRED"], |a, b| {
+///     a.eq_ignore_ascii_case(b)
+/// });
+/// assert_eq!(result, ["Fred", "barney"]);
+/// ```
+pub fn uniq_with<A, F>(array: &[A], mut comparator: F) -> Vec<A>
+where
+    A: Clone,
+    F: FnMut(&A, &A) -> bool,
+{
+    let mut result: Vec<A> = Vec::new();
+
+    for item in array {
+        if !result.iter().any(|value| comparator(item, value)) {
+            result.push(item.clone());
+        }
+    }
+
+    result
+}
+
 /// A trait that implements the [Uniq::uniq] method on arrays.
 pub trait Uniq<A, I>
 where
     A: IntoIterator,
-    I: Hash + Eq + Clone,
+    I: Clone,
 {
     /// Creates a duplicate-free version of this array, in which only the first occurrence of each
     /// element is kept. The order of result values is determined by the order they occur in the
@@ -43,30 +110,108 @@ where
     ///
     /// assert_eq!([2, 1, 2].uniq(), [2, 1]);
     /// ```
-    fn uniq(&self) -> Vec<I>;
+    fn uniq(&self) -> Vec<I>
+    where
+        I: Hash + Eq;
+
+    /// This method is like [Uniq::uniq] except that it accepts `iteratee` which is invoked for each
+    /// value of this array to generate the key by which uniqueness is computed.
+    ///
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Uniq;
+    ///
+    /// assert_eq!(
+    ///     [2.1, 1.2, 2.3].uniq_by(|n: &f64| n.floor() as i64),
+    ///     [2.1, 1.2]
+    /// );
+    /// ```
+    fn uniq_by<K, F>(&self, iteratee: F) -> Vec<I>
+    where
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [Uniq::uniq] except that it accepts `comparator` which is invoked with a
+    /// value of this array and a value already in the result to tell whether they are equal.
+    ///
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Uniq;
+    ///
+    /// assert_eq!(
+    ///     ["a", "A", "b"].uniq_with(|a, b| a.eq_ignore_ascii_case(b)),
+    ///     ["a", "b"]
+    /// );
+    /// ```
+    fn uniq_with<F>(&self, comparator: F) -> Vec<I>
+    where
+        F: FnMut(&I, &I) -> bool;
 }
 
 impl<A> Uniq<Vec<A>, A> for Vec<A>
 where
-    A: Hash + Eq + Clone,
+    A: Clone,
 {
-    fn uniq(&self) -> Vec<A> {
+    fn uniq(&self) -> Vec<A>
+    where
+        A: Hash + Eq,
+    {
         uniq(self)
     }
+
+    fn uniq_by<K, F>(&self, iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        uniq_by(self, iteratee)
+    }
+
+    fn uniq_with<F>(&self, comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        uniq_with(self, comparator)
+    }
 }
 
 impl<A, const N: usize> Uniq<[A; N], A> for [A; N]
 where
-    A: Hash + Eq + Clone,
+    A: Clone,
 {
-    fn uniq(&self) -> Vec<A> {
+    fn uniq(&self) -> Vec<A>
+    where
+        A: Hash + Eq,
+    {
         uniq(self)
     }
+
+    fn uniq_by<K, F>(&self, iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        uniq_by(self, iteratee)
+    }
+
+    fn uniq_with<F>(&self, comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        uniq_with(self, comparator)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::user;
 
     #[test]
     fn can_return_unique_values_of_an_unsorted_array() {
@@ -81,4 +226,19 @@ mod tests {
         assert_eq!(vec![1, 2, 2].uniq(), [1, 2]);
         assert_eq!([1, 2, 2].uniq(), [1, 2]);
     }
+
+    #[test]
+    fn can_keep_first_value_of_each_key_or_match() {
+        let users = [user(1, "fred"), user(2, "Fred"), user(1, "barney")];
+
+        assert_eq!(
+            uniq_by(&users, |user| user.id),
+            [user(1, "fred"), user(2, "Fred")]
+        );
+        assert_eq!(
+            users.uniq_with(|a, b| a.name.eq_ignore_ascii_case(b.name)),
+            [user(1, "fred"), user(1, "barney")]
+        );
+        assert_eq!(Vec::<i32>::new().uniq_by(|n| *n), [0; 0]);
+    }
 }
@@ -0,0 +1,89 @@
+use crate::{
+    case::{
+        self,
+        WordCase,
+    },
+    AcronymMode,
+};
+
+/// Converts `value` to upper case, like the lodash `_.upperCase` function. The [words] of `value`
+/// are uppercased and joined with spaces.
+///
+/// * `value` - The string to convert.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::upper_case;
+///
+/// assert_eq!(upper_case("--foo-bar"), "FOO BAR".to_string());
+/// assert_eq!(upper_case("fooBar"), "FOO BAR".to_string());
+/// assert_eq!(upper_case("__foo_bar__"), "FOO BAR".to_string());
+/// ```
+///
+/// [words]: crate::words
+pub fn upper_case<S>(value: S) -> String
+where
+    S: AsRef<str>,
+{
+    case::convert(
+        value.as_ref(),
+        WordCase::Upper,
+        WordCase::Upper,
+        " ",
+        AcronymMode::Normalize,
+    )
+}
+
+/// A trait that implements the [UpperCase::upper_case] method on strings.
+pub trait UpperCase<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Converts this string to upper case, like the lodash `_.upperCase` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::UpperCase;
+    ///
+    /// assert_eq!("fooBar".upper_case(), "FOO BAR".to_string());
+    /// ```
+    fn upper_case(&self) -> String;
+}
+
+impl UpperCase<String> for String {
+    fn upper_case(&self) -> String {
+        upper_case(self)
+    }
+}
+
+impl UpperCase<str> for str {
+    fn upper_case(&self) -> String {
+        upper_case(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_to_upper_case() {
+        assert_eq!(upper_case("fooBar"), "FOO BAR".to_string());
+        assert_eq!(
+            "XMLHttpRequest".upper_case(),
+            "XML HTTP REQUEST".to_string()
+        );
+        assert_eq!(
+            "version2Update".to_string().upper_case(),
+            "VERSION 2 UPDATE".to_string()
+        );
+        assert_eq!(upper_case("don't stop"), "DONT STOP".to_string());
+        assert_eq!("  ".upper_case(), "".to_string());
+        assert_eq!(
+            "élan vital".to_string().upper_case(),
+            "ÉLAN VITAL".to_string()
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The kind of a character, which determines where words start and end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharKind {
+    /// An uppercase letter.
+    Upper,
+    /// A lowercase letter.
+    Lower,
+    /// A letter without case, such as a CJK ideograph.
+    Caseless,
+    /// A numeric character.
+    Digit,
+    /// A combining mark or joiner, which belongs to the word of the preceding character.
+    Mark,
+    /// An apostrophe, which is part of a word when it is between two letters, as in "don't".
+    Apostrophe,
+    /// Any other character, which separates words.
+    Separator,
+}
+
+impl CharKind {
+    /// Returns the kind of `c`.
+    fn of(c: char) -> Self {
+        if c.is_uppercase() {
+            Self::Upper
+        } else if c.is_lowercase() {
+            Self::Lower
+        } else if c.is_numeric() {
+            Self::Digit
+        } else if c.is_alphabetic() {
+            Self::Caseless
+        } else if matches!(c, '\'' | '\u{2019}') {
+            Self::Apostrophe
+        } else if is_mark(c) {
+            Self::Mark
+        } else {
+            Self::Separator
+        }
+    }
+
+    /// Returns `true` if this kind is a letter.
+    fn is_letter(self) -> bool {
+        matches!(self, Self::Upper | Self::Lower | Self::Caseless)
+    }
+}
+
+/// Returns `true` if `c` is a combining mark, a variation selector or a zero width joiner.
+fn is_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36f}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{200d}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{fe20}'..='\u{fe2f}'
+    )
+}
+
+/// Returns `true` if `c` is an emoji, a dingbat or a regional indicator, which make up words of
+/// their own.
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{2600}'..='\u{27bf}' | '\u{2b00}'..='\u{2bff}' | '\u{1f000}'..='\u{1faff}'
+    )
+}
+
+/// Splits `segment`, a word of the Unicode word boundaries, into words at the humps of camel
+/// case, the end of acronyms, the boundaries of numbers and the separators, such as "_".
+fn push_words<'a>(segment: &'a str, words: &mut Vec<&'a str>) {
+    let chars = segment.char_indices().collect::<Vec<_>>();
+    let mut start = None;
+    let mut previous = CharKind::Separator;
+    // The number of consecutive uppercase letters before the current character, and the index of
+    // the last one, where an acronym followed by a capitalized word is split.
+    let mut uppercase_count = 0;
+    let mut last_uppercase = 0;
+
+    for (position, &(index, c)) in chars.iter().enumerate() {
+        let kind = match CharKind::of(c) {
+            CharKind::Apostrophe
+                if previous.is_letter()
+                    && chars
+                        .get(position + 1)
+                        .is_some_and(|(_, next)| CharKind::of(*next).is_letter()) =>
+            {
+                CharKind::Mark
+            }
+            CharKind::Apostrophe => CharKind::Separator,
+            kind => kind,
+        };
+
+        match (kind, start) {
+            (CharKind::Separator, _) => {
+                if let Some(start) = start.take() {
+                    words.push(&segment[start..index]);
+                }
+
+                previous = CharKind::Separator;
+                uppercase_count = 0;
+                continue;
+            }
+            (CharKind::Mark, _) => continue,
+            (_, None) => start = Some(index),
+            (_, Some(word_start)) => {
+                let split = match (previous, kind) {
+                    (CharKind::Lower | CharKind::Caseless, CharKind::Upper) => Some(index),
+                    (CharKind::Upper, CharKind::Lower) if uppercase_count > 1 => {
+                        Some(last_uppercase)
+                    }
+                    (CharKind::Digit, CharKind::Upper | CharKind::Lower | CharKind::Caseless)
+                    | (CharKind::Upper | CharKind::Lower | CharKind::Caseless, CharKind::Digit) => {
+                        Some(index)
+                    }
+                    _ => None,
+                };
+
+                if let Some(split) = split {
+                    words.push(&segment[word_start..split]);
+                    start = Some(split);
+                }
+            }
+        }
+
+        if kind == CharKind::Upper {
+            uppercase_count += 1;
+            last_uppercase = index;
+        } else {
+            uppercase_count = 0;
+        }
+
+        previous = kind;
+    }
+
+    if let Some(start) = start {
+        words.push(&segment[start..]);
+    }
+}
+
+/// Splits `value` into an array of its words, like the lodash `_.words` function. Words are
+/// found at the Unicode word boundaries and are further split at the humps of camel case, at the
+/// end of acronyms, at the boundaries of numbers and at separators such as "_" and "-". Emoji
+/// are words of their own.
+///
+/// * `value` - The string to inspect.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::words;
+///
+/// assert_eq!(
+///     words("fred, barney, & pebbles"),
+///     vec!["fred", "barney", "pebbles"]
+/// );
+/// assert_eq!(
+///     words("XMLHttpRequest_v2 loves 🦀"),
+///     vec!["XML", "Http", "Request", "v", "2", "loves", "🦀"]
+/// );
+/// ```
+pub fn words(value: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+
+    for segment in value.split_word_bounds() {
+        if segment.starts_with(is_emoji) {
+            words.push(segment);
+        } else {
+            push_words(segment, &mut words);
+        }
+    }
+
+    words
+}
+
+/// This method is like [words] except that the words are the matches of `pattern` in `value`.
+///
+/// * `value` - The string to inspect.
+/// * `pattern` - The pattern to match words.
+///
+/// # Examples
+///
+/// ```
+/// use regex::Regex;
+/// use rodash::words_with;
+///
+/// let pattern = Regex::new(r"[^, ]+").unwrap();
+/// assert_eq!(
+///     words_with("fred, barney, & pebbles", &pattern),
+///     vec!["fred", "barney", "&", "pebbles"]
+/// );
+/// ```
+pub fn words_with<'a>(value: &'a str, pattern: &Regex) -> Vec<&'a str> {
+    pattern
+        .find_iter(value)
+        .map(|found| found.as_str())
+        .collect()
+}
+
+/// A trait that implements the [Words::words] method on strings.
+pub trait Words<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Splits this string into an array of its words, like the lodash `_.words` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Words;
+    ///
+    /// assert_eq!("fooBar_baz-2".words(), vec!["foo", "Bar", "baz", "2"]);
+    /// ```
+    fn words(&self) -> Vec<&str>;
+
+    /// This method is like [Words::words] except that the words are the matches of `pattern` in
+    /// this string.
+    ///
+    /// * `pattern` - The pattern to match words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use regex::Regex;
+    /// use rodash::Words;
+    ///
+    /// let pattern = Regex::new(r"\S+").unwrap();
+    /// assert_eq!(
+    ///     "fred & barney".words_with(&pattern),
+    ///     vec!["fred", "&", "barney"]
+    /// );
+    /// ```
+    fn words_with(&self, pattern: &Regex) -> Vec<&str>;
+}
+
+impl Words<String> for String {
+    fn words(&self) -> Vec<&str> {
+        words(self)
+    }
+
+    fn words_with(&self, pattern: &Regex) -> Vec<&str> {
+        words_with(self, pattern)
+    }
+}
+
+impl Words<str> for str {
+    fn words(&self) -> Vec<&str> {
+        words(self)
+    }
+
+    fn words_with(&self, pattern: &Regex) -> Vec<&str> {
+        words_with(self, pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_split_words() {
+        assert_eq!(
+            words("fred, barney, & pebbles"),
+            vec!["fred", "barney", "pebbles"]
+        );
+        assert_eq!(
+            words(" foo_bar-baz  qux.quux\t"),
+            vec!["foo", "bar", "baz", "qux", "quux"]
+        );
+        assert_eq!(words(""), Vec::<&str>::new());
+        assert_eq!(words(" _-&! "), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn can_split_camel_case_and_acronyms() {
+        assert_eq!(words("fooBarBaz"), vec!["foo", "Bar", "Baz"]);
+        assert_eq!(words("XMLHttpRequest"), vec!["XML", "Http", "Request"]);
+        assert_eq!(words("getHTTPResponse"), vec!["get", "HTTP", "Response"]);
+        assert_eq!(words("IPv6Address"), vec!["I", "Pv", "6", "Address"]);
+        assert_eq!(words("ABC"), vec!["ABC"]);
+        assert_eq!("AbcDEF".words(), vec!["Abc", "DEF"]);
+    }
+
+    #[test]
+    fn can_split_numbers() {
+        assert_eq!(words("abc123def"), vec!["abc", "123", "def"]);
+        assert_eq!(words("v1.2.3"), vec!["v", "1", "2", "3"]);
+        assert_eq!(words("42"), vec!["42"]);
+    }
+
+    #[test]
+    fn can_split_unicode_words() {
+        assert_eq!(
+            words("Ünïcödé ΣΊΣΥΦΟΣ straße"),
+            vec!["Ünïcödé", "ΣΊΣΥΦΟΣ", "straße"]
+        );
+        assert_eq!(
+            words("he\u{301}llo Wo\u{308}rld"),
+            vec!["he\u{301}llo", "Wo\u{308}rld"]
+        );
+        assert_eq!(words("日本語"), vec!["日", "本", "語"]);
+        assert_eq!(
+            words("don't stop ’til it's 'done'"),
+            vec!["don't", "stop", "til", "it's", "done"]
+        );
+        assert_eq!("Добрый деньМир".words(), vec!["Добрый", "день", "Мир"]);
+    }
+
+    #[test]
+    fn can_keep_emoji() {
+        assert_eq!(
+            words("I ❤️ Rust🦀 👨‍👩‍👧 🇺🇸 👍🏽!"),
+            vec!["I", "❤️", "Rust", "🦀", "👨‍👩‍👧", "🇺🇸", "👍🏽"]
+        );
+    }
+
+    #[test]
+    fn can_split_words_with_patterns() {
+        let pattern = Regex::new(r"[^, ]+").unwrap();
+        assert_eq!(
+            words_with("fred, barney, & pebbles", &pattern),
+            vec!["fred", "barney", "&", "pebbles"]
+        );
+        assert_eq!(
+            "a1b22".to_string().words_with(&Regex::new(r"\d+").unwrap()),
+            vec!["1", "22"]
+        );
+    }
+}
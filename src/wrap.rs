@@ -0,0 +1,320 @@
+use std::mem;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// How [wrap_with] breaks words that do not fit on a line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordBreak {
+    /// Words are never broken, so that words wider than a line overflow it on a line of their own.
+    #[default]
+    Normal,
+    /// Words wider than a line are broken between grapheme clusters, and other words are moved
+    /// to the next line whole.
+    BreakLongWords,
+    /// Words are broken between any grapheme clusters to fill every line, as for CJK text or
+    /// hashes.
+    BreakAll,
+}
+
+/// Returns `true` if `c` is a space that lines can be broken at, which excludes the no-break
+/// spaces.
+fn is_break_space(c: char) -> bool {
+    c.is_whitespace() && !matches!(c, '\u{a0}' | '\u{2007}' | '\u{202f}')
+}
+
+/// Splits `word` after the grapheme clusters that fit in `width` columns. With `at_least_one`,
+/// the first cluster is taken even if it is wider than `width`.
+fn split_at_width(word: &str, width: usize, at_least_one: bool) -> (&str, &str) {
+    let mut end = 0;
+    let mut end_width = 0;
+
+    for grapheme in word.graphemes(true) {
+        end_width += grapheme.width();
+
+        if end_width > width && (end > 0 || !at_least_one) {
+            break;
+        }
+
+        end += grapheme.len();
+    }
+
+    word.split_at(end)
+}
+
+/// Wraps `line`, which has no line break, into lines of at most `width` columns, which are
+/// indented like `line`.
+fn wrap_line(line: &str, width: usize, word_break: WordBreak, lines: &mut Vec<String>) {
+    let content = line.trim_start_matches(is_break_space);
+
+    if content.trim_end_matches(is_break_space).is_empty() {
+        lines.push(String::new());
+        return;
+    }
+
+    let indent = &line[..line.len() - content.len()];
+    let available = width.saturating_sub(indent.width()).max(1);
+    let mut current = indent.to_owned();
+    let mut current_width = 0;
+
+    for mut word in content
+        .split(is_break_space)
+        .filter(|word| !word.is_empty())
+    {
+        let mut word_width = word.width();
+
+        if current.len() > indent.len() {
+            if current_width + 1 + word_width <= available {
+                current.push(' ');
+                current.push_str(word);
+                current_width += 1 + word_width;
+                continue;
+            }
+
+            if word_break == WordBreak::BreakAll && current_width + 1 < available {
+                let (head, tail) = split_at_width(word, available - current_width - 1, false);
+
+                if !head.is_empty() {
+                    current.push(' ');
+                    current.push_str(head);
+                    word = tail;
+                    word_width = tail.width();
+                }
+            }
+
+            lines.push(mem::replace(&mut current, indent.to_owned()));
+        }
+
+        if word_break != WordBreak::Normal {
+            while word_width > available {
+                let (head, tail) = split_at_width(word, available, true);
+
+                if tail.is_empty() {
+                    break;
+                }
+
+                lines.push([indent, head].concat());
+                word = tail;
+                word_width = tail.width();
+            }
+        }
+
+        current.push_str(word);
+        current_width = word_width;
+    }
+
+    lines.push(current);
+}
+
+/// Wraps `text` into lines of at most `width` columns, breaking lines at spaces. Widths are
+/// measured in columns of a monospace terminal, in which East Asian wide characters take two
+/// columns. Existing line breaks, including the blank lines between paragraphs, are kept, and
+/// the lines wrapped from an indented line keep its indentation. Spaces between words are
+/// collapsed, and words wider than `width` overflow on lines of their own.
+///
+/// * `text` - The text to wrap.
+/// * `width` - The maximum width of a line.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::wrap;
+///
+/// assert_eq!(
+///     wrap("The quick brown fox jumps over the lazy dog.", 15),
+///     "The quick brown\nfox jumps over\nthe lazy dog.".to_string()
+/// );
+/// assert_eq!(
+///     wrap("Usage:\n\n  rodash [options] <input>", 18),
+///     "Usage:\n\n  rodash [options]\n  <input>".to_string()
+/// );
+/// ```
+pub fn wrap<S>(text: S, width: usize) -> String
+where
+    S: AsRef<str>,
+{
+    wrap_with(text, width, WordBreak::Normal)
+}
+
+/// This method is like [wrap] except that words that do not fit on a line are broken according
+/// to `word_break`. Words are only ever broken between grapheme clusters.
+///
+/// * `text` - The text to wrap.
+/// * `width` - The maximum width of a line.
+/// * `word_break` - How words that do not fit on a line are broken.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::{
+///     wrap_with,
+///     WordBreak,
+/// };
+///
+/// assert_eq!(
+///     wrap_with("commit 0123456789abcdef", 10, WordBreak::BreakLongWords),
+///     "commit\n0123456789\nabcdef".to_string()
+/// );
+/// assert_eq!(
+///     wrap_with("commit 0123456789abcdef", 10, WordBreak::BreakAll),
+///     "commit 012\n3456789abc\ndef".to_string()
+/// );
+/// assert_eq!(
+///     wrap_with("日本語のテキスト", 6, WordBreak::BreakAll),
+///     "日本語\nのテキ\nスト".to_string()
+/// );
+/// ```
+pub fn wrap_with<S>(text: S, width: usize, word_break: WordBreak) -> String
+where
+    S: AsRef<str>,
+{
+    let mut lines = Vec::new();
+
+    for line in text.as_ref().split('\n') {
+        wrap_line(line, width, word_break, &mut lines);
+    }
+
+    lines.join("\n")
+}
+
+/// A trait that implements the [Wrap::wrap] method on strings.
+pub trait Wrap<S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    /// Wraps this string into lines of at most `width` columns, breaking lines at spaces and
+    /// keeping existing line breaks.
+    ///
+    /// * `width` - The maximum width of a line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Wrap;
+    ///
+    /// assert_eq!(
+    ///     "fred barney pebbles".wrap(11),
+    ///     "fred barney\npebbles".to_string()
+    /// );
+    /// ```
+    fn wrap(&self, width: usize) -> String;
+
+    /// This method is like [Wrap::wrap] except that words that do not fit on a line are broken
+    /// according to `word_break`.
+    ///
+    /// * `width` - The maximum width of a line.
+    /// * `word_break` - How words that do not fit on a line are broken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::{
+    ///     WordBreak,
+    ///     Wrap,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "abcdefgh".wrap_with(3, WordBreak::BreakLongWords),
+    ///     "abc\ndef\ngh".to_string()
+    /// );
+    /// ```
+    fn wrap_with(&self, width: usize, word_break: WordBreak) -> String;
+}
+
+impl Wrap<String> for String {
+    fn wrap(&self, width: usize) -> String {
+        wrap(self, width)
+    }
+
+    fn wrap_with(&self, width: usize, word_break: WordBreak) -> String {
+        wrap_with(self, width, word_break)
+    }
+}
+
+impl Wrap<str> for str {
+    fn wrap(&self, width: usize) -> String {
+        wrap(self, width)
+    }
+
+    fn wrap_with(&self, width: usize, word_break: WordBreak) -> String {
+        wrap_with(self, width, word_break)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_wrap_words() {
+        assert_eq!(wrap("", 10), "".to_string());
+        assert_eq!(wrap("a b c", 1), "a\nb\nc".to_string());
+        assert_eq!(wrap("a  b\tc   ", 10), "a b c".to_string());
+        assert_eq!(wrap("\u{300} a", 10), "\u{300} a".to_string());
+        assert_eq!(
+            "incomprehensibilities is long".wrap(10),
+            "incomprehensibilities\nis long".to_string()
+        );
+        assert_eq!(
+            "100\u{a0}km away".to_string().wrap(6),
+            "100\u{a0}km\naway".to_string()
+        );
+    }
+
+    #[test]
+    fn can_keep_line_breaks_and_indentation() {
+        assert_eq!(
+            wrap("one two three\n\n  four five six\n   \nseven\n", 9),
+            "one two\nthree\n\n  four\n  five\n  six\n\nseven\n".to_string()
+        );
+        assert_eq!(wrap("a\r\nb", 5), "a\nb".to_string());
+    }
+
+    #[test]
+    fn can_wrap_wide_characters() {
+        assert_eq!(
+            wrap("日本 語の テキスト", 8),
+            "日本\n語の\nテキスト".to_string()
+        );
+        assert_eq!(
+            wrap_with("日本語", 1, WordBreak::BreakLongWords),
+            "日\n本\n語".to_string()
+        );
+        assert_eq!(
+            wrap_with("ab e\u{301}e\u{301}e\u{301}", 4, WordBreak::BreakAll),
+            "ab e\u{301}\ne\u{301}e\u{301}".to_string()
+        );
+    }
+
+    #[test]
+    fn can_break_words() {
+        assert_eq!(
+            wrap_with("a bcdefg", 3, WordBreak::BreakLongWords),
+            "a\nbcd\nefg".to_string()
+        );
+        assert_eq!(
+            wrap_with("a bcdefg", 3, WordBreak::BreakAll),
+            "a b\ncde\nfg".to_string()
+        );
+        assert_eq!(
+            wrap_with("  abcdef", 4, WordBreak::BreakLongWords),
+            "  ab\n  cd\n  ef".to_string()
+        );
+    }
+
+    quickcheck! {
+        fn broken_lines_are_never_too_wide(words: Vec<String>, width: u8) -> bool {
+            let width = usize::from(width % 20) + 2;
+            let text = words
+                .iter()
+                .map(|word| word.replace(char::is_whitespace, ""))
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            wrap_with(&text, width, WordBreak::BreakAll)
+                .split('\n')
+                .all(|line| line.width() <= width || line.graphemes(true).count() == 1)
+        }
+    }
+}
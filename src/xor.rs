@@ -0,0 +1,255 @@
+use crate::occurrences::{
+    occurrences_by,
+    occurrences_with,
+};
+use std::hash::Hash;
+
+/// Creates an array of unique values that are included in only one of the `array` and `other`
+/// arrays, which is their symmetric difference, like the lodash `_.xor` function. The order of
+/// result values are determined by the order in which they first occur in `array`, followed by
+/// `other`.
+///
+/// * `array` - The first array to inspect.
+/// * `other` - The second array to inspect.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::xor;
+///
+/// assert_eq!(xor(&[2, 1, 2], &[2, 3]), [1, 3]);
+/// ```
+pub fn xor<A>(array: &[A], other: &[A]) -> Vec<A>
+where
+    A: Hash + Eq + Clone,
+{
+    occurrences_by([array, other], |item| item)
+        .into_iter()
+        .filter(|(_, count)| *count == 1)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// This method is like [xor] except that it accepts `iteratee` which is invoked for each value
+/// of `array` and `other` to generate the key by which they are compared, like the lodash
+/// `_.xorBy` function. Only the first value with each key is kept. The keys are looked up in a hash
+/// map, so the values themselves only need to be cloneable.
+///
+/// * `array` - The first array to inspect.
+/// * `other` - The second array to inspect.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::xor_by;
+///
+/// let result = xor_by(&[2.1, 1.2], &[2.3, 3.4], |n: &f64| n.floor() as i64);
+/// assert_eq!(result, [1.2, 3.4]);
+/// ```
+pub fn xor_by<A, K, F>(array: &[A], other: &[A], iteratee: F) -> Vec<A>
+where
+    A: Clone,
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    occurrences_by([array, other], iteratee)
+        .into_iter()
+        .filter(|(_, count)| *count == 1)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// This method is like [xor] except that it accepts `comparator` which is invoked to tell
+/// whether two values are equal, like the lodash `_.xorWith` function. It is invoked with a
+/// value and the first occurrences of the values found before it, in `O(n²)` time, so
+/// [xor_by] should be preferred when values can be compared by a key.
+///
+/// * `array` - The first array to inspect.
+/// * `other` - The second array to inspect.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::xor_with;
+///
+/// let result = xor_with(&["Fred", "wilma"], &["FRED", "barney"], |a, b| {
+///     a.eq_ignore_ascii_case(b)
+/// });
+/// assert_eq!(result, ["wilma", "barney"]);
+/// ```
+pub fn xor_with<A, F>(array: &[A], other: &[A], comparator: F) -> Vec<A>
+where
+    A: Clone,
+    F: FnMut(&A, &A) -> bool,
+{
+    occurrences_with([array, other], comparator)
+        .into_iter()
+        .filter(|(_, count)| *count == 1)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// A trait that implements the [Xor::xor] method on arrays.
+pub trait Xor<A, I>
+where
+    A: IntoIterator,
+    I: Clone,
+{
+    /// Creates an array of unique values that are included in only one of this array and the
+    /// `other` array. The order of result values are determined by the order in which they first
+    /// occur in this array, followed by `other`.
+    ///
+    /// * `other` - The array to inspect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Xor;
+    ///
+    /// assert_eq!([2, 1].xor(&[2, 3]), [1, 3]);
+    /// ```
+    fn xor(&self, other: &[I]) -> Vec<I>
+    where
+        I: Hash + Eq;
+
+    /// This method is like [Xor::xor] except that it accepts `iteratee` which is invoked
+    /// for each value of this array and `other` to generate the key by which they are compared.
+    ///
+    /// * `other` - The array to inspect.
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Xor;
+    ///
+    /// assert_eq!([2.1, 1.2].xor_by(&[2.3], |n: &f64| n.floor() as i64), [1.2]);
+    /// ```
+    fn xor_by<K, F>(&self, other: &[I], iteratee: F) -> Vec<I>
+    where
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [Xor::xor] except that it accepts `comparator` which is invoked
+    /// to tell whether two values are equal.
+    ///
+    /// * `other` - The array to inspect.
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::Xor;
+    ///
+    /// assert_eq!(
+    ///     ["a", "b"].xor_with(&["A", "c"], |a, b| a.eq_ignore_ascii_case(b)),
+    ///     ["b", "c"]
+    /// );
+    /// ```
+    fn xor_with<F>(&self, other: &[I], comparator: F) -> Vec<I>
+    where
+        F: FnMut(&I, &I) -> bool;
+}
+
+impl<A> Xor<Vec<A>, A> for Vec<A>
+where
+    A: Clone,
+{
+    fn xor(&self, other: &[A]) -> Vec<A>
+    where
+        A: Hash + Eq,
+    {
+        xor(self, other)
+    }
+
+    fn xor_by<K, F>(&self, other: &[A], iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        xor_by(self, other, iteratee)
+    }
+
+    fn xor_with<F>(&self, other: &[A], comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        xor_with(self, other, comparator)
+    }
+}
+
+impl<A, const N: usize> Xor<[A; N], A> for [A; N]
+where
+    A: Clone,
+{
+    fn xor(&self, other: &[A]) -> Vec<A>
+    where
+        A: Hash + Eq,
+    {
+        xor(self, other)
+    }
+
+    fn xor_by<K, F>(&self, other: &[A], iteratee: F) -> Vec<A>
+    where
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        xor_by(self, other, iteratee)
+    }
+
+    fn xor_with<F>(&self, other: &[A], comparator: F) -> Vec<A>
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        xor_with(self, other, comparator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        difference,
+        test_helpers::user,
+        union,
+    };
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_return_symmetric_difference_of_two_arrays() {
+        let result = xor(&[2, 1, 2], &[2, 3, 3]);
+        assert_eq!(result, [1, 3]);
+
+        assert_eq!(vec![2, 1, 1].xor(&[]), [2, 1]);
+
+        assert_eq!([1, 2].xor(&[2, 1]), [0; 0]);
+    }
+
+    #[test]
+    fn can_drop_keys_or_matches_found_in_both_arrays() {
+        let users = [user(1, "fred"), user(2, "barney")];
+        let others = [user(2, "Barney"), user(3, "FRED")];
+
+        assert_eq!(
+            xor_by(&users, &others, |user| user.id),
+            [user(1, "fred"), user(3, "FRED")]
+        );
+        assert_eq!(
+            users.xor_with(&others, |a, b| a.name.eq_ignore_ascii_case(b.name)),
+            []
+        );
+    }
+
+    quickcheck! {
+        fn is_the_union_of_both_differences(array: Vec<u8>, other: Vec<u8>) -> bool {
+            let expected = union(
+                &difference(&array, &other, None),
+                &difference(&other, &array, None),
+            );
+
+            xor(&array, &other) == expected && xor_with(&array, &other, |a, b| a == b) == expected
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use crate::occurrences::{
+    occurrences_by,
+    occurrences_with,
+};
+use std::{
+    hash::Hash,
+    iter,
+};
+
+/// Creates an array of unique values that are included in only one of the `array` and `others`
+/// arrays, like the lodash `_.xor` function. The order of result values are determined by the
+/// order in which they first occur in `array`, followed by `others`.
+///
+/// * `array` - The first array to inspect.
+/// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::xor_all;
+///
+/// assert_eq!(xor_all(&[2, 1], vec![&[2, 3], &[3, 4]]), [1, 4]);
+/// ```
+pub fn xor_all<A, O>(array: &[A], others: O) -> Vec<A>
+where
+    A: Hash + Eq + Clone,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
+{
+    let others = others.into_iter().collect::<Vec<_>>();
+    let arrays = iter::once(array).chain(others.iter().map(AsRef::as_ref));
+
+    occurrences_by(arrays, |item| item)
+        .into_iter()
+        .filter(|(_, count)| *count == 1)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// This method is like [xor_all] except that it accepts `iteratee` which is invoked for each
+/// value of `array` and `others` to generate the key by which they are compared. Only the first
+/// value with each key is kept. The keys are looked up in a hash map, so the values themselves only
+/// need to be cloneable.
+///
+/// * `array` - The first array to inspect.
+/// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+/// * `iteratee` - The function that returns the key of a value.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::xor_all_by;
+///
+/// let result = xor_all_by(&[2.1, 1.2], [[2.3], [3.4]], |n: &f64| n.floor() as i64);
+/// assert_eq!(result, [1.2, 3.4]);
+/// ```
+pub fn xor_all_by<A, O, K, F>(array: &[A], others: O, iteratee: F) -> Vec<A>
+where
+    A: Clone,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
+    K: Hash + Eq,
+    F: FnMut(&A) -> K,
+{
+    let others = others.into_iter().collect::<Vec<_>>();
+    let arrays = iter::once(array).chain(others.iter().map(AsRef::as_ref));
+
+    occurrences_by(arrays, iteratee)
+        .into_iter()
+        .filter(|(_, count)| *count == 1)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// This method is like [xor_all] except that it accepts `comparator` which is invoked to tell
+/// whether two values are equal. It is invoked with a value and the first occurrences of the
+/// values found before it, in `O(n²)` time, so [xor_all_by] should be preferred when values can be
+/// compared by a key.
+///
+/// * `array` - The first array to inspect.
+/// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+/// * `comparator` - The function that tells whether two values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use rodash::xor_all_with;
+///
+/// let result = xor_all_with(&["a", "b"], [["A"], ["c"]], |a, b| {
+///     a.eq_ignore_ascii_case(b)
+/// });
+/// assert_eq!(result, ["b", "c"]);
+/// ```
+pub fn xor_all_with<A, O, F>(array: &[A], others: O, comparator: F) -> Vec<A>
+where
+    A: Clone,
+    O: IntoIterator,
+    O::Item: AsRef<[A]>,
+    F: FnMut(&A, &A) -> bool,
+{
+    let others = others.into_iter().collect::<Vec<_>>();
+    let arrays = iter::once(array).chain(others.iter().map(AsRef::as_ref));
+
+    occurrences_with(arrays, comparator)
+        .into_iter()
+        .filter(|(_, count)| *count == 1)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// A trait that implements the [XorAll::xor_all] method on arrays.
+pub trait XorAll<A, I>
+where
+    A: IntoIterator,
+    I: Clone,
+{
+    /// Creates an array of unique values that are included in only one of this array and the
+    /// `others` arrays. The order of result values are determined by the order in which they first
+    /// occur in this array, followed by `others`.
+    ///
+    /// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::XorAll;
+    ///
+    /// assert_eq!([2, 1].xor_all(vec![&[2, 3], &[3, 4]]), [1, 4]);
+    /// ```
+    fn xor_all<O>(&self, others: O) -> Vec<I>
+    where
+        I: Hash + Eq,
+        O: IntoIterator,
+        O::Item: AsRef<[I]>;
+
+    /// This method is like [XorAll::xor_all] except that it accepts `iteratee` which is invoked
+    /// for each value of this array and `others` to generate the key by which they are compared.
+    ///
+    /// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+    /// * `iteratee` - The function that returns the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::XorAll;
+    ///
+    /// assert_eq!(
+    ///     [2.1].xor_all_by([[2.3], [3.4]], |n: &f64| n.floor() as i64),
+    ///     [3.4]
+    /// );
+    /// ```
+    fn xor_all_by<O, K, F>(&self, others: O, iteratee: F) -> Vec<I>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[I]>,
+        K: Hash + Eq,
+        F: FnMut(&I) -> K;
+
+    /// This method is like [XorAll::xor_all] except that it accepts `comparator` which is invoked
+    /// to tell whether two values are equal.
+    ///
+    /// * `others` - The other arrays to inspect, such as a vector of slices or of vectors.
+    /// * `comparator` - The function that tells whether two values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rodash::XorAll;
+    ///
+    /// assert_eq!(
+    ///     ["a", "b"].xor_all_with([["A"], ["c"]], |a, b| a.eq_ignore_ascii_case(b)),
+    ///     ["b", "c"]
+    /// );
+    /// ```
+    fn xor_all_with<O, F>(&self, others: O, comparator: F) -> Vec<I>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[I]>,
+        F: FnMut(&I, &I) -> bool;
+}
+
+impl<A> XorAll<Vec<A>, A> for Vec<A>
+where
+    A: Clone,
+{
+    fn xor_all<O>(&self, others: O) -> Vec<A>
+    where
+        A: Hash + Eq,
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+    {
+        xor_all(self, others)
+    }
+
+    fn xor_all_by<O, K, F>(&self, others: O, iteratee: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        xor_all_by(self, others, iteratee)
+    }
+
+    fn xor_all_with<O, F>(&self, others: O, comparator: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        F: FnMut(&A, &A) -> bool,
+    {
+        xor_all_with(self, others, comparator)
+    }
+}
+
+impl<A, const N: usize> XorAll<[A; N], A> for [A; N]
+where
+    A: Clone,
+{
+    fn xor_all<O>(&self, others: O) -> Vec<A>
+    where
+        A: Hash + Eq,
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+    {
+        xor_all(self, others)
+    }
+
+    fn xor_all_by<O, K, F>(&self, others: O, iteratee: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        K: Hash + Eq,
+        F: FnMut(&A) -> K,
+    {
+        xor_all_by(self, others, iteratee)
+    }
+
+    fn xor_all_with<O, F>(&self, others: O, comparator: F) -> Vec<A>
+    where
+        O: IntoIterator,
+        O::Item: AsRef<[A]>,
+        F: FnMut(&A, &A) -> bool,
+    {
+        xor_all_with(self, others, comparator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        intersect_quorum,
+        test_helpers::user,
+    };
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn can_return_symmetric_difference_of_multiple_arrays() {
+        let result = xor_all(&[2, 1, 1], vec![&[2, 3], &[3, 4]]);
+        assert_eq!(result, [1, 4]);
+
+        assert_eq!(vec![2, 1, 2].xor_all(Vec::<Vec<i32>>::new()), [2, 1]);
+
+        assert_eq!([1].xor_all([[1], [1]]), [0; 0]);
+    }
+
+    #[test]
+    fn can_keep_keys_or_matches_found_in_a_single_array() {
+        let users = [user(1, "fred"), user(2, "barney")];
+        let others = [vec![user(2, "FRED")], vec![user(3, "wilma")]];
+
+        assert_eq!(
+            xor_all_by(&users, &others, |user| user.id),
+            [user(1, "fred"), user(3, "wilma")]
+        );
+        assert_eq!(
+            users.xor_all_with(&others, |a, b| a.name.eq_ignore_ascii_case(b.name)),
+            [user(2, "barney"), user(3, "wilma")]
+        );
+    }
+
+    quickcheck! {
+        fn keeps_values_of_a_single_array(array: Vec<u8>, others: Vec<Vec<u8>>) -> bool {
+            let all = intersect_quorum(&array, &others, 1);
+            let shared = intersect_quorum(&array, &others, 2);
+            let expected = all
+                .into_iter()
+                .filter(|item| !shared.contains(item))
+                .collect::<Vec<_>>();
+
+            xor_all(&array, &others) == expected
+                && xor_all_with(&array, &others, |a, b| a == b) == expected
+        }
+    }
+}